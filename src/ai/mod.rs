@@ -0,0 +1,228 @@
+//! Computer-controlled species.
+//!
+//! Each AI species runs its own colony using the same turn economy as the
+//! player ([`apply_turn_production`]). AI turns are resolved as soon as the
+//! player's End Turn has been processed (see [`TurnEnded`]), so every AI
+//! species has finished before the player can act again.
+//!
+//! # Build Heuristic
+//!
+//! The AI keeps at most one project in its production queue. When the queue
+//! is empty it picks the building type it owns the fewest of, breaking ties
+//! by priority: industry first, then science, then housing
+//! (see [`BUILD_PRIORITY`]).
+//!
+//! When nothing is being researched it queues the cheapest technology whose
+//! prerequisites are met.
+
+use std::collections::BTreeMap;
+
+use bevy::prelude::*;
+use serde::{Deserialize, Serialize};
+
+use crate::data_types::{BuildableOn, GameData, GameRegistry, SpeciesId, TechnologyId};
+use crate::main_menu::GameState;
+use crate::planet_data::{BuildingType, TileColor, generate_planet};
+use crate::planet_view::logic::{apply_turn_production, update_connectivity};
+use crate::planet_view::types::{PlanetViewState, ProductionProject, ProjectType, TurnEnded};
+use crate::research::ResearchQueue;
+
+#[cfg(test)]
+mod tests;
+
+/// Number of AI species created for a new game.
+pub const AI_SPECIES_COUNT: u64 = 2;
+
+/// Base seed for AI home planets (offset per species).
+const AI_SEED_BASE: u64 = 54321;
+
+/// Building types the AI constructs, in tie-break priority order.
+pub const BUILD_PRIORITY: [BuildingType; 3] = [
+    BuildingType::Factory,
+    BuildingType::Laboratory,
+    BuildingType::Habitat,
+];
+
+/// Plugin that creates AI species and resolves their turns.
+pub struct AiPlugin;
+
+impl Plugin for AiPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<AiState>()
+            .add_systems(OnEnter(GameState::PlanetView), setup_ai_species)
+            .add_systems(
+                Update,
+                ai_turn_system.run_if(in_state(GameState::PlanetView)),
+            );
+    }
+}
+
+/// State of a single AI-controlled species.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct AiSpecies {
+    /// Display name of the species.
+    pub name: String,
+    /// The species' home colony.
+    pub planet: PlanetViewState,
+    /// The species' research progress.
+    #[serde(default)]
+    pub research: ResearchQueue,
+}
+
+impl AiSpecies {
+    /// Create a species with a freshly generated home planet.
+    pub fn new(
+        name: impl Into<String>,
+        seed: u64,
+        game_data: &GameData,
+        registry: &GameRegistry,
+    ) -> Self {
+        let mut surface = generate_planet(seed, false);
+        update_connectivity(&mut surface, game_data, registry);
+
+        Self {
+            name: name.into(),
+            planet: PlanetViewState {
+                surface: Some(surface),
+                turn: 1,
+                ..Default::default()
+            },
+            research: ResearchQueue::default(),
+        }
+    }
+
+    /// Play one turn: queue construction and research if idle, then
+    /// resolve production and research.
+    pub fn take_turn(&mut self, game_data: &GameData, registry: &GameRegistry) {
+        if self.planet.production_queue.is_empty() {
+            if let Some(project) = choose_project(&self.planet, game_data, registry) {
+                self.planet.production_queue.push_back(project);
+            }
+        }
+        if self.research.is_idle() {
+            if let Some(tech) = choose_research(&self.research, game_data) {
+                // Cannot fail: `choose_research` only returns queueable techs.
+                let _ = self.research.queue_tech(tech, game_data);
+            }
+        }
+        apply_turn_production(&mut self.planet, &mut self.research, game_data, registry);
+    }
+}
+
+/// All AI species in the current game, keyed by species id.
+///
+/// Saved alongside the player's planet by [`crate::save_load`].
+#[derive(Resource, Debug, Clone, Default, Serialize, Deserialize)]
+pub struct AiState {
+    /// AI species in deterministic (id) order.
+    pub species: BTreeMap<SpeciesId, AiSpecies>,
+}
+
+impl AiState {
+    /// Create the AI species for a new game.
+    pub fn new_game(game_data: &GameData, registry: &GameRegistry) -> Self {
+        let species = (1..=AI_SPECIES_COUNT)
+            .map(|n| {
+                let id = SpeciesId::from(format!("ai_{n}"));
+                let ai = AiSpecies::new(
+                    format!("AI Species {n}"),
+                    AI_SEED_BASE + n,
+                    game_data,
+                    registry,
+                );
+                (id, ai)
+            })
+            .collect();
+        Self { species }
+    }
+
+    /// Resolve one turn for every AI species.
+    pub fn take_turns(&mut self, game_data: &GameData, registry: &GameRegistry) {
+        for ai in self.species.values_mut() {
+            ai.take_turn(game_data, registry);
+        }
+    }
+}
+
+/// Pick the next building project for an AI colony.
+///
+/// Returns `None` when no buildable tile is left.
+pub fn choose_project(
+    planet: &PlanetViewState,
+    game_data: &GameData,
+    registry: &GameRegistry,
+) -> Option<ProductionProject> {
+    let surface = planet.surface.as_ref()?;
+
+    let target_tile_index = surface.tiles.iter().position(|tile| {
+        tile.connected && tile.building.is_none() && tile.color == TileColor::White
+    })?;
+
+    let count = |kind: BuildingType| {
+        surface
+            .tiles
+            .iter()
+            .filter(|tile| tile.building == Some(kind))
+            .count()
+    };
+
+    // `min_by_key` keeps the first minimum, so ties go to the higher priority.
+    let (building, def) = BUILD_PRIORITY
+        .iter()
+        .filter_map(|&kind| {
+            registry
+                .surface_building(game_data, kind.id())
+                .filter(|def| def.buildable_on_cell_type == BuildableOn::White)
+                .map(|def| (kind, def))
+        })
+        .min_by_key(|&(kind, _)| count(kind))?;
+
+    Some(ProductionProject {
+        project_type: ProjectType::Building(building),
+        total_cost: def.production_cost,
+        progress: 0,
+        target_tile_index,
+    })
+}
+
+/// Pick the cheapest technology the AI can research next.
+pub fn choose_research(research: &ResearchQueue, game_data: &GameData) -> Option<TechnologyId> {
+    game_data
+        .technologies()
+        .iter()
+        .map(|tech| (TechnologyId::from(tech.id.as_str()), tech.science_cost))
+        .filter(|(id, _)| {
+            !research.is_researched(id)
+                && !research.is_queued(id)
+                && research.prerequisites_met(id, game_data)
+        })
+        .min_by_key(|&(_, cost)| cost)
+        .map(|(id, _)| id)
+}
+
+/// Create fresh AI species when a new game starts.
+///
+/// Sessions already in progress (loaded saves, returning from another
+/// screen) keep their existing [`AiState`].
+pub fn setup_ai_species(
+    mut ai_state: ResMut<AiState>,
+    game_data: Res<GameData>,
+    registry: Res<GameRegistry>,
+) {
+    if ai_state.species.is_empty() {
+        *ai_state = AiState::new_game(&game_data, &registry);
+    }
+}
+
+/// Resolve all AI turns after the player ends their turn.
+fn ai_turn_system(
+    mut turn_events: MessageReader<TurnEnded>,
+    mut ai_state: ResMut<AiState>,
+    game_data: Res<GameData>,
+    registry: Res<GameRegistry>,
+) {
+    for event in turn_events.read() {
+        ai_state.take_turns(&game_data, &registry);
+        debug!("AI species resolved turn {}", event.turn);
+    }
+}
@@ -0,0 +1,81 @@
+use std::path::PathBuf;
+
+use super::{AI_SPECIES_COUNT, AiState, choose_project};
+use crate::data_types::load_game_data;
+use crate::planet_data::BuildingType;
+use crate::planet_view::types::ProjectType;
+
+#[test]
+fn first_project_prefers_industry() {
+    let (data, registry) = load_game_data(PathBuf::from("assets/data")).expect("data loads");
+    let state = AiState::new_game(&data, &registry);
+    let ai = state.species.values().next().expect("AI species exist");
+
+    let project = choose_project(&ai.planet, &data, &registry).expect("a tile is buildable");
+    assert_eq!(
+        project.project_type,
+        ProjectType::Building(BuildingType::Factory)
+    );
+}
+
+#[test]
+fn resources_grow_monotonically_over_twenty_turns() {
+    let (data, registry) = load_game_data(PathBuf::from("assets/data")).expect("data loads");
+    let mut state = AiState::new_game(&data, &registry);
+    assert_eq!(state.species.len() as u64, AI_SPECIES_COUNT);
+
+    let snapshot = |state: &AiState| {
+        state
+            .species
+            .values()
+            .map(|ai| {
+                let p = &ai.planet;
+                (p.food, p.housing, p.production, p.science)
+            })
+            .collect::<Vec<_>>()
+    };
+
+    let mut previous = snapshot(&state);
+    for _ in 0..20 {
+        state.take_turns(&data, &registry);
+        let current = snapshot(&state);
+        for (before, after) in previous.iter().zip(&current) {
+            assert!(
+                after.0 >= before.0,
+                "food decreased: {before:?} -> {after:?}"
+            );
+            assert!(
+                after.1 >= before.1,
+                "housing decreased: {before:?} -> {after:?}"
+            );
+            assert!(
+                after.2 > before.2,
+                "production stalled: {before:?} -> {after:?}"
+            );
+            assert!(
+                after.3 > before.3,
+                "science stalled: {before:?} -> {after:?}"
+            );
+        }
+        previous = current;
+    }
+
+    for ai in state.species.values() {
+        assert_eq!(ai.planet.turn, 21);
+        let built = ai
+            .planet
+            .surface
+            .as_ref()
+            .unwrap()
+            .tiles
+            .iter()
+            .filter(|tile| tile.building == Some(BuildingType::Factory))
+            .count();
+        assert!(built > 0, "{} never finished a factory", ai.name);
+        assert!(
+            ai.research.is_researched(&"tech_terraforming".into()),
+            "{} never finished researching",
+            ai.name
+        );
+    }
+}
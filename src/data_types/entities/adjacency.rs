@@ -0,0 +1,38 @@
+//! Adjacency bonuses between complementary buildings.
+
+use serde::Deserialize;
+
+use crate::planet_data::BuildingKind;
+
+/// Extra yield a building earns for each orthogonal neighbor of another kind.
+///
+/// Loaded from `adjacency_bonuses.ron`. Rules match on [`BuildingKind`], so
+/// they cover every upgrade level. Only connected tiles earn bonuses.
+///
+/// # RON Example
+/// ```ron
+/// (
+///     building_kind: Food,
+///     neighbor_kind: Housing,
+///     yields_food: 1,
+/// )
+/// ```
+#[derive(Debug, Clone, Deserialize, PartialEq, Eq)]
+pub struct AdjacencyBonus {
+    /// Kind of building that earns the bonus.
+    pub building_kind: BuildingKind,
+    /// Kind of neighboring building that grants it.
+    pub neighbor_kind: BuildingKind,
+    /// Extra food per turn.
+    #[serde(default)]
+    pub yields_food: i32,
+    /// Extra housing.
+    #[serde(default)]
+    pub yields_housing: i32,
+    /// Extra production per turn.
+    #[serde(default)]
+    pub yields_production: i32,
+    /// Extra science per turn.
+    #[serde(default)]
+    pub yields_science: i32,
+}
@@ -0,0 +1,54 @@
+//! Random events that can happen on the player's planet.
+
+use serde::Deserialize;
+
+use super::surface::ResourceKind;
+
+/// A random event rolled once per turn on the player's planet.
+///
+/// Loaded from `planet_events.ron`. Each event fires at most once per game.
+///
+/// # RON Example
+/// ```ron
+/// (
+///     id: "event_bountiful_harvest",
+///     name_en: "Bountiful Harvest",
+///     description_en: "Ideal weather fills the granaries.",
+///     trigger_chance: 0.05,
+///     min_turn: 3,
+///     effect: resource_bonus(kind: food, amount: 20),
+/// )
+/// ```
+#[derive(Debug, Clone, Deserialize, PartialEq)]
+pub struct PlanetEvent {
+    /// Unique identifier (e.g., "event_bountiful_harvest").
+    pub id: String,
+    /// English display name.
+    pub name_en: String,
+    /// English text shown in the notification.
+    pub description_en: String,
+    /// Chance (0.0 - 1.0) that the event fires on a given turn.
+    pub trigger_chance: f32,
+    /// First turn on which the event can fire.
+    #[serde(default)]
+    pub min_turn: u32,
+    /// What happens when the event fires.
+    pub effect: PlanetEventEffect,
+}
+
+/// Outcome of a [`PlanetEvent`].
+#[derive(Debug, Clone, Copy, Deserialize, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+pub enum PlanetEventEffect {
+    /// Add to (or, if negative, take from) a resource stockpile.
+    ResourceBonus {
+        /// Stockpile changed.
+        kind: ResourceKind,
+        /// Amount added; the stockpile stays between 0 and its cap.
+        amount: i32,
+    },
+    /// Add science points to the research stockpile.
+    ResearchBoost(i32),
+    /// Turn the empty White tile at `(x, y)` Black.
+    TileBlocked(usize, usize),
+}
@@ -3,17 +3,23 @@
 //! Each submodule defines the Rust types that correspond to RON data schemas.
 //!
 //! # Modules
+//! - [`adjacency`] - Yield bonuses between neighboring buildings
+//! - [`event`] - Random planet events
 //! - [`scenario`] - Game scenarios (starting conditions, galaxy settings)
 //! - [`surface`] - Planet surface types and buildings
 //! - [`tech`] - Technology/research tree entries
 //! - [`victory`] - Victory and defeat conditions
 
+mod adjacency;
+mod event;
 mod scenario;
 mod surface;
 mod tech;
 mod victory;
 
+pub use adjacency::AdjacencyBonus;
+pub use event::{PlanetEvent, PlanetEventEffect};
 pub use scenario::{GenerationMode, Scenario};
-pub use surface::{BuildableOn, SpecialBehavior, SurfaceBuilding, SurfaceCellType};
+pub use surface::{BuildableOn, ResourceKind, SpecialBehavior, SurfaceBuilding, SurfaceCellType};
 pub use tech::Technology;
 pub use victory::{VictoryCondition, VictoryType};
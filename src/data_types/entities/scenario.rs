@@ -46,4 +46,8 @@ pub struct Scenario {
     pub black_ratio: f32,
     /// ID of the victory condition to use.
     pub victory_condition_id: String,
+    /// Last turn that can be played before the game is lost; overrides
+    /// the global turn limit when set.
+    #[serde(default)]
+    pub max_turns: Option<u32>,
 }
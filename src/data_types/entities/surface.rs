@@ -5,6 +5,8 @@
 
 use serde::Deserialize;
 
+use crate::data_types::ids::SurfaceBuildingId;
+
 /// Definition of a surface cell type (e.g., "plains", "mountains").
 ///
 /// Loaded from `surface_cell_types.ron`.
@@ -28,6 +30,18 @@ pub enum BuildableOn {
     Black,
 }
 
+/// Stockpiled colony resources.
+#[derive(Debug, Clone, Copy, Deserialize, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+pub enum ResourceKind {
+    /// Food stockpile.
+    Food,
+    /// Production stockpile.
+    Production,
+    /// Science stockpile.
+    Science,
+}
+
 /// Special behaviors that buildings can have.
 #[derive(Debug, Clone, Deserialize, PartialEq, Eq)]
 #[serde(rename_all = "snake_case")]
@@ -36,6 +50,17 @@ pub enum SpecialBehavior {
     None,
     /// Converts adjacent black tiles to white.
     Terraformer,
+    /// Raises the stockpile cap of a resource.
+    ///
+    /// ```ron
+    /// special_behavior: storage_bonus(resource: food, amount: 100),
+    /// ```
+    StorageBonus {
+        /// Resource whose cap is raised.
+        resource: ResourceKind,
+        /// Amount added to the cap (must not be negative).
+        amount: i32,
+    },
 }
 
 /// Definition of a building that can be placed on the planet surface.
@@ -68,4 +93,7 @@ pub struct SurfaceBuilding {
     pub unlocked_by_tech_id: Option<String>,
     /// Special behavior when this building is placed.
     pub special_behavior: SpecialBehavior,
+    /// Building this one can be upgraded into (if any).
+    #[serde(default)]
+    pub upgrades_to: Option<SurfaceBuildingId>,
 }
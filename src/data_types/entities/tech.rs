@@ -13,6 +13,9 @@ use serde::Deserialize;
 ///     id: "tech_advanced_farming",
 ///     name_en: "Advanced Farming",
 ///     science_cost: 100,
+///     description_en: "Crop rotation and soil analysis.",
+///     prerequisites: ["tech_agriculture"],
+///     accident_chance: 0.05,
 /// )
 /// ```
 #[derive(Debug, Clone, Deserialize)]
@@ -23,4 +26,14 @@ pub struct Technology {
     pub name_en: String,
     /// Science points required to research.
     pub science_cost: i32,
+    /// English description shown in the tech tree.
+    #[serde(default)]
+    pub description_en: String,
+    /// IDs of technologies that must be researched first.
+    #[serde(default)]
+    pub prerequisites: Vec<String>,
+    /// Chance per turn of a research accident while this technology is
+    /// being researched (0.0 for safe technologies).
+    #[serde(default)]
+    pub accident_chance: f32,
 }
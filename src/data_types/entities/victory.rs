@@ -10,6 +10,14 @@ use serde::Deserialize;
 pub enum VictoryType {
     /// Win by covering all white tiles with buildings.
     CoverAllTiles,
+    /// Win by researching every technology.
+    ResearchAllTechnologies,
+    /// Win by covering, together with allied species, a share of the white
+    /// tiles on every planet.
+    Alliance,
+    /// Win by keeping the colony's prosperity above a threshold for several
+    /// turns in a row.
+    Economic,
 }
 
 /// A victory condition definition.
@@ -1,11 +1,16 @@
 //! Error types for data loading.
 //!
 //! Defines all error variants that can occur while reading,
-//! parsing, or validating TOML game data files.
+//! parsing, or validating RON game data files.
+//!
+//! Every variant carries an optional `suggestion` telling mod authors how to
+//! fix the problem. The constructors ([`DataLoadError::io`],
+//! [`DataLoadError::validation`], ...) fill in a generic one; checks that
+//! know better replace it with [`DataLoadError::with_suggestion`].
 
 use thiserror::Error;
 
-/// Errors that can occur while loading TOML game data.
+/// Errors that can occur while loading RON game data.
 #[derive(Debug, Error)]
 pub enum DataLoadError {
     /// File read failure.
@@ -15,6 +20,8 @@ pub enum DataLoadError {
         source: std::io::Error,
         /// Path that failed.
         path: String,
+        /// How to fix the problem.
+        suggestion: Option<&'static str>,
     },
     /// RON parse failure.
     #[error("Failed to parse {path}: {source}")]
@@ -23,6 +30,8 @@ pub enum DataLoadError {
         source: ron::error::SpannedError,
         /// Path that failed.
         path: String,
+        /// How to fix the problem.
+        suggestion: Option<&'static str>,
     },
     /// Schema version is newer than the loader understands.
     #[error("Unsupported schema version {found} in {path}; current version is {current}")]
@@ -33,6 +42,8 @@ pub enum DataLoadError {
         current: u32,
         /// File path that declared the version.
         path: String,
+        /// How to fix the problem.
+        suggestion: Option<&'static str>,
     },
     /// Duplicate identifier encountered.
     #[error("Duplicate {kind} id encountered: {id}")]
@@ -41,6 +52,8 @@ pub enum DataLoadError {
         kind: &'static str,
         /// Duplicated identifier.
         id: String,
+        /// How to fix the problem.
+        suggestion: Option<&'static str>,
     },
     /// Validation failure.
     #[error("Validation failed for {kind} '{id}': {message}")]
@@ -51,5 +64,91 @@ pub enum DataLoadError {
         id: String,
         /// Validation error details.
         message: String,
+        /// How to fix the problem.
+        suggestion: Option<&'static str>,
     },
 }
+
+impl DataLoadError {
+    /// File read failure for `path`.
+    pub fn io(source: std::io::Error, path: impl Into<String>) -> Self {
+        Self::Io {
+            source,
+            path: path.into(),
+            suggestion: Some("Check that the file exists and is readable."),
+        }
+    }
+
+    /// RON parse failure for `path`.
+    pub fn parse(source: ron::error::SpannedError, path: impl Into<String>) -> Self {
+        Self::Parse {
+            source,
+            path: path.into(),
+            suggestion: Some("Fix the RON syntax at the line and column shown."),
+        }
+    }
+
+    /// Manifest at `path` declares a schema version newer than `current`.
+    pub fn unsupported_schema_version(found: u32, current: u32, path: impl Into<String>) -> Self {
+        Self::UnsupportedSchemaVersion {
+            found,
+            current,
+            path: path.into(),
+            suggestion: Some("Update the game, or use a version of the mod made for this one."),
+        }
+    }
+
+    /// Two `kind` entries share the id `id`.
+    pub fn duplicate_id(kind: &'static str, id: impl Into<String>) -> Self {
+        Self::DuplicateId {
+            kind,
+            id: id.into(),
+            suggestion: Some("Remove or rename the duplicate entry in its RON file."),
+        }
+    }
+
+    /// The `kind` entry `id` has an invalid value.
+    pub fn validation(
+        kind: &'static str,
+        id: impl Into<String>,
+        message: impl Into<String>,
+    ) -> Self {
+        Self::Validation {
+            kind,
+            id: id.into(),
+            message: message.into(),
+            suggestion: Some("Correct the value in the entry's RON file."),
+        }
+    }
+
+    /// Replace the suggestion with a more specific one.
+    pub fn with_suggestion(mut self, text: &'static str) -> Self {
+        match &mut self {
+            Self::Io { suggestion, .. }
+            | Self::Parse { suggestion, .. }
+            | Self::UnsupportedSchemaVersion { suggestion, .. }
+            | Self::DuplicateId { suggestion, .. }
+            | Self::Validation { suggestion, .. } => *suggestion = Some(text),
+        }
+        self
+    }
+
+    /// How to fix the problem, if known.
+    pub fn suggestion(&self) -> Option<&'static str> {
+        match self {
+            Self::Io { suggestion, .. }
+            | Self::Parse { suggestion, .. }
+            | Self::UnsupportedSchemaVersion { suggestion, .. }
+            | Self::DuplicateId { suggestion, .. }
+            | Self::Validation { suggestion, .. } => *suggestion,
+        }
+    }
+
+    /// The error message followed by its suggestion, for logs.
+    pub fn report(&self) -> String {
+        match self.suggestion() {
+            Some(suggestion) => format!("{self} (suggestion: {suggestion})"),
+            None => self.to_string(),
+        }
+    }
+}
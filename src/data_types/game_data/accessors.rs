@@ -14,6 +14,23 @@ impl GameData {
         &self.technologies
     }
 
+    pub fn tech_prereqs(&self, tech_id: &str) -> &[String] {
+        self.technologies
+            .iter()
+            .find(|tech| tech.id == tech_id)
+            .map(|tech| tech.prerequisites.as_slice())
+            .unwrap_or_default()
+    }
+
+    pub fn tech_unlocks<'a>(
+        &'a self,
+        tech_id: &'a str,
+    ) -> impl Iterator<Item = &'a SurfaceBuilding> + 'a {
+        self.surface_buildings
+            .iter()
+            .filter(move |building| building.unlocked_by_tech_id.as_deref() == Some(tech_id))
+    }
+
     pub fn victory_conditions(&self) -> &[VictoryCondition] {
         &self.victory_conditions
     }
@@ -21,4 +38,12 @@ impl GameData {
     pub fn scenarios(&self) -> &[Scenario] {
         &self.scenarios
     }
+
+    pub fn adjacency_bonuses(&self) -> &[AdjacencyBonus] {
+        &self.adjacency_bonuses
+    }
+
+    pub fn planet_events(&self) -> &[PlanetEvent] {
+        &self.planet_events
+    }
 }
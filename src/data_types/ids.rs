@@ -17,7 +17,10 @@
 macro_rules! define_id_type {
     ($name:ident) => {
         /// Strongly-typed identifier for this entity type.
-        #[derive(Debug, Clone, PartialEq, Eq, Hash)]
+        #[derive(
+            Debug, Clone, PartialEq, Eq, Hash, PartialOrd, Ord, serde::Serialize, serde::Deserialize,
+        )]
+        #[serde(transparent)]
         pub struct $name(pub String);
 
         impl From<&str> for $name {
@@ -57,3 +60,5 @@ define_id_type!(TechnologyId);
 define_id_type!(VictoryConditionId);
 // ID type for game scenarios.
 define_id_type!(ScenarioId);
+// ID type for species (the player and AI empires).
+define_id_type!(SpeciesId);
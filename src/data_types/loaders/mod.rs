@@ -4,6 +4,7 @@
 //! - [`ron_loader`] - Low-level RON parsing helpers
 //! - [`wrappers`] - Intermediate deserialization types
 //! - [`root`] - Main `load_game_data()` entry point
+//! - [`mods`] - Mod manifests and merging mod data into the base data
 //!
 //! # Data Files
 //! Loads the following RON files from `assets/data/`:
@@ -12,9 +13,36 @@
 //! - `technologies.ron` - Research tree
 //! - `victory_conditions.ron` - Win/lose conditions
 //! - `scenarios.ron` - Game scenarios
+//! - `adjacency_bonuses.ron` - Yield bonuses between neighboring buildings
+//! - `planet_events.ron` - Random planet events
 
+mod mods;
 mod ron_loader;
-mod wrappers;
 mod root;
+mod wrappers;
+
+pub use mods::{
+    DISABLED_MODS_PATH, DisabledMods, LoadedMods, MOD_MANIFEST_FILE, ModConflict, ModLoadWarnings,
+    ModManifest, ModMetadata, ModProvenance, load_disabled_mods, load_mod_datasets,
+    save_disabled_mods,
+};
+pub(crate) use ron_loader::load_ron_file;
+pub use root::{
+    load_game_data, load_game_data_with_mods, load_unvalidated_game_data, reload_data_file,
+};
+
+/// Data files making up `GameData`, in load order.
+pub const DATA_FILES: [&str; 7] = [
+    "surface_cell_types.ron",
+    "surface_buildings.ron",
+    "technologies.ron",
+    "victory_conditions.ron",
+    "scenarios.ron",
+    "adjacency_bonuses.ron",
+    "planet_events.ron",
+];
 
-pub use root::load_game_data;
+/// Version of the data file schema understood by this build.
+///
+/// Mods declaring a newer `schema_version` are rejected.
+pub const DATA_SCHEMA_VERSION: u32 = 1;
@@ -0,0 +1,608 @@
+//! Loading of data mods from `assets/mods/`.
+//!
+//! Every subdirectory of the mods directory is one mod. A mod must contain a
+//! `mod.ron` manifest and may contain any of the base data files
+//! (`surface_buildings.ron`, `technologies.ron`, ...). Entries are merged
+//! into the base data by id: an entry with a known id replaces it, any other
+//! entry is appended. Mods are applied in directory name order, except
+//! that a mod always comes after the mods it lists in `dependencies`.
+//!
+//! What each mod changed is summarized in [`LoadedMods`], along with the
+//! [`ModProvenance`] of every modded entry. When two mods change the same
+//! entry the later one wins and the [`ModConflict`] is reported in
+//! [`ModLoadWarnings`]. Mods named in [`DisabledMods`] are listed but not
+//! applied; that list is saved to [`DISABLED_MODS_PATH`].
+//!
+//! # Manifest Example
+//! ```ron
+//! (
+//!     name: "Better Farms",
+//!     version: "1.0.0",
+//!     schema_version: 1,
+//!     dependencies: ["Farm Basics"],
+//! )
+//! ```
+//!
+//! # Failure Handling
+//! A missing or malformed manifest, a manifest targeting a newer data
+//! schema, or mods depending on each other in a cycle abort loading. A data
+//! file that fails to parse is skipped with a warning so the remaining
+//! files and mods still load, and so is a mod whose dependencies are
+//! missing or disabled.
+
+use std::collections::{BTreeSet, HashMap, HashSet};
+use std::fs;
+use std::path::{Path, PathBuf};
+
+use bevy::log::warn;
+use bevy::prelude::Resource;
+use serde::de::DeserializeOwned;
+use serde::{Deserialize, Serialize};
+
+use crate::data_types::errors::DataLoadError;
+use crate::data_types::game_data::GameData;
+
+use super::DATA_SCHEMA_VERSION;
+use super::ron_loader::load_ron_file;
+use super::wrappers::{
+    AdjacencyBonusesData, PlanetEventsData, ScenariosData, SurfaceBuildingsData,
+    SurfaceCellTypesData, TechnologiesData, VictoryConditionsData,
+};
+
+/// Name of the manifest file every mod directory must contain.
+pub const MOD_MANIFEST_FILE: &str = "mod.ron";
+
+/// Where the player's choice of disabled mods is stored.
+pub const DISABLED_MODS_PATH: &str = "settings/disabled_mods.ron";
+
+/// Contents of a mod's `mod.ron` manifest.
+#[derive(Debug, Clone, Deserialize)]
+pub struct ModManifest {
+    /// Display name of the mod.
+    pub name: String,
+    /// Version of the mod itself.
+    pub version: String,
+    /// Data schema version the mod was written for.
+    pub schema_version: u32,
+    /// Names of the mods that must be applied before this one.
+    #[serde(default)]
+    pub dependencies: Vec<String>,
+}
+
+/// Summary of one mod found in the mods directory.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct ModMetadata {
+    /// Display name from the manifest.
+    pub name: String,
+    /// Version from the manifest.
+    pub version: String,
+    /// Position in the load order; later mods win conflicts.
+    pub priority: usize,
+    /// Number of new entries the mod adds.
+    pub entities_added: usize,
+    /// Number of existing entries the mod replaces.
+    pub entities_overridden: usize,
+    /// Whether another mod changes an entry this mod also changes.
+    pub has_conflicts: bool,
+    /// Whether the player disabled the mod, so it was not applied.
+    pub disabled: bool,
+    /// Dependencies that are missing or disabled. The mod was not applied
+    /// if there are any.
+    pub missing_dependencies: Vec<String>,
+}
+
+/// Every mod found by the last full load, in load order.
+#[derive(Resource, Debug, Clone, Default)]
+pub struct LoadedMods {
+    /// One entry per mod directory. Mods skipped for missing dependencies
+    /// come last.
+    pub mods: Vec<ModMetadata>,
+    /// Names of the applied mods, in the order they were applied.
+    pub load_order: Vec<String>,
+    /// Which mod last wrote each modded entry.
+    pub provenance: ModProvenance,
+}
+
+/// The mod that last wrote each entry added or replaced by a mod.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct ModProvenance {
+    /// Mod name by entry id, for each entry kind (e.g. `"technology"`).
+    pub entries: HashMap<&'static str, HashMap<String, String>>,
+}
+
+impl ModProvenance {
+    /// Name of the mod that last wrote the `kind` entry `id`, or `None` if
+    /// it comes from the base data.
+    pub fn source(&self, kind: &str, id: &str) -> Option<&str> {
+        self.entries.get(kind)?.get(id).map(String::as_str)
+    }
+}
+
+/// Two mods changing the same entry. The later mod's version is used.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ModConflict {
+    /// Id of the entry both mods change.
+    pub entity_id: String,
+    /// Kind of the entry, e.g. `"technology"`.
+    pub kind: &'static str,
+    /// Mod whose version was replaced.
+    pub first_mod: String,
+    /// Mod whose version is used.
+    pub second_mod: String,
+}
+
+impl ModConflict {
+    /// Description of the conflict for logs and the warning list.
+    pub fn message(&self) -> String {
+        format!(
+            "Mod '{}' overrides {} '{}' also changed by mod '{}'",
+            self.second_mod, self.kind, self.entity_id, self.first_mod
+        )
+    }
+
+    /// The conflict as a duplicate id error, for strict loading.
+    pub fn to_error(&self) -> DataLoadError {
+        DataLoadError::duplicate_id(self.kind, self.entity_id.clone()).with_suggestion(
+            "Strict mod loading forbids two mods changing the same entry; remove it from one of them.",
+        )
+    }
+}
+
+/// Mods the player switched off in the mod manager.
+#[derive(Resource, Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct DisabledMods {
+    /// Names (from the manifests) of the mods to skip.
+    pub disabled_mods: BTreeSet<String>,
+}
+
+/// Load the disabled mods saved at `path`.
+///
+/// A missing file means every mod is enabled; so does a broken one, with a
+/// warning.
+pub fn load_disabled_mods(path: &Path) -> DisabledMods {
+    if !path.exists() {
+        return DisabledMods::default();
+    }
+    load_ron_file(path).unwrap_or_else(|err| {
+        warn!("Ignoring disabled mods: {err}");
+        DisabledMods::default()
+    })
+}
+
+/// Write `disabled` to `path`, creating parent directories as needed.
+///
+/// # Errors
+/// Returns an error if the file cannot be written.
+pub fn save_disabled_mods(path: &Path, disabled: &DisabledMods) -> Result<(), DataLoadError> {
+    let io_error = |source| DataLoadError::io(source, path.display().to_string());
+
+    let content = ron::ser::to_string_pretty(disabled, ron::ser::PrettyConfig::default())
+        .map_err(|err| io_error(std::io::Error::other(err)))?;
+
+    if let Some(parent) = path.parent().filter(|p| !p.as_os_str().is_empty()) {
+        fs::create_dir_all(parent).map_err(io_error)?;
+    }
+    fs::write(path, content).map_err(io_error)
+}
+
+/// Problems found while loading mods that did not stop the game from
+/// starting (for example a mod data file that failed to parse).
+#[derive(Resource, Debug, Clone, Default)]
+pub struct ModLoadWarnings {
+    /// Human-readable description of each problem.
+    pub warnings: Vec<String>,
+    /// Every conflict between mods; each also has an entry in `warnings`.
+    pub conflicts: Vec<ModConflict>,
+}
+
+impl ModLoadWarnings {
+    /// Whether any mod reported a problem.
+    pub fn has_warnings(&self) -> bool {
+        !self.warnings.is_empty()
+    }
+
+    /// The warnings that are not about a conflict.
+    pub fn other_warnings(&self) -> impl Iterator<Item = &String> {
+        let messages: HashSet<String> = self.conflicts.iter().map(ModConflict::message).collect();
+        self.warnings
+            .iter()
+            .filter(move |warning| !messages.contains(*warning))
+    }
+
+    /// The conflicts as errors, for strict loading.
+    pub fn conflict_errors(&self) -> Vec<DataLoadError> {
+        self.conflicts.iter().map(ModConflict::to_error).collect()
+    }
+
+    fn push_conflict(&mut self, conflict: ModConflict) {
+        self.push(conflict.message());
+        self.conflicts.push(conflict);
+    }
+
+    fn push(&mut self, warning: String) {
+        warn!("{warning}");
+        self.warnings.push(warning);
+    }
+}
+
+/// Apply every mod in `mods_dir` to `game_data`.
+///
+/// A missing mods directory is not an error.
+///
+/// # Errors
+/// Returns an error if the mods directory cannot be listed, a manifest is
+/// missing or malformed, a mod targets a newer data schema, or mods depend
+/// on each other in a cycle.
+pub fn load_mod_datasets(
+    mods_dir: &Path,
+    game_data: &mut GameData,
+    disabled: &DisabledMods,
+) -> Result<(LoadedMods, ModLoadWarnings), DataLoadError> {
+    apply_mods(mods_dir, game_data, None, disabled)
+}
+
+/// Apply the enabled mods in `mods_dir`, limited to the data file named
+/// `only` when given.
+///
+/// Conflicts and missing dependencies are only reported when every file is
+/// applied, so reloading a single file does not report them again.
+pub(super) fn apply_mods(
+    mods_dir: &Path,
+    game_data: &mut GameData,
+    only: Option<&str>,
+    disabled: &DisabledMods,
+) -> Result<(LoadedMods, ModLoadWarnings), DataLoadError> {
+    let mut loaded = LoadedMods::default();
+    let mut warnings = ModLoadWarnings::default();
+    if !mods_dir.is_dir() {
+        return Ok((loaded, warnings));
+    }
+
+    let mut found = Vec::new();
+    for mod_dir in mod_directories(mods_dir)? {
+        let manifest: ModManifest = load_ron_file(&mod_dir.join(MOD_MANIFEST_FILE))?;
+        found.push((mod_dir, manifest));
+    }
+    let manifests: Vec<&ModManifest> = found.iter().map(|(_, manifest)| manifest).collect();
+    let order = resolve_load_order(&manifests, disabled)?;
+
+    // Mod (index into `loaded.mods`) that last changed each entry.
+    let mut changed_by: HashMap<(&'static str, String), usize> = HashMap::new();
+    for (priority, &index) in order.sorted.iter().enumerate() {
+        let (mod_dir, manifest) = &found[index];
+        let manifest_path = mod_dir.join(MOD_MANIFEST_FILE);
+        let mut metadata = ModMetadata {
+            name: manifest.name.clone(),
+            version: manifest.version.clone(),
+            priority,
+            disabled: disabled.disabled_mods.contains(&manifest.name),
+            ..Default::default()
+        };
+        if metadata.disabled {
+            loaded.mods.push(metadata);
+            continue;
+        }
+        if manifest.schema_version > DATA_SCHEMA_VERSION {
+            return Err(DataLoadError::unsupported_schema_version(
+                manifest.schema_version,
+                DATA_SCHEMA_VERSION,
+                manifest_path.display().to_string(),
+            ));
+        }
+
+        let changes = apply_mod(mod_dir, manifest, game_data, &mut warnings, only);
+        metadata.entities_added = changes.added;
+        metadata.entities_overridden = changes.overridden;
+        loaded.mods.push(metadata);
+        loaded.load_order.push(manifest.name.clone());
+
+        if only.is_some() {
+            continue;
+        }
+        for (kind, id) in changes.touched {
+            let Some(other) = changed_by.insert((kind, id.clone()), priority) else {
+                continue;
+            };
+            if other == priority {
+                continue;
+            }
+            loaded.mods[other].has_conflicts = true;
+            loaded.mods[priority].has_conflicts = true;
+            warnings.push_conflict(ModConflict {
+                entity_id: id,
+                kind,
+                first_mod: loaded.mods[other].name.clone(),
+                second_mod: manifest.name.clone(),
+            });
+        }
+    }
+    for ((kind, id), index) in changed_by {
+        let name = loaded.mods[index].name.clone();
+        let entries = loaded.provenance.entries.entry(kind).or_default();
+        entries.insert(id, name);
+    }
+
+    for (index, missing) in order.skipped {
+        let manifest = &found[index].1;
+        if only.is_none() {
+            warnings.push(format!(
+                "Mod '{}' skipped: missing dependencies {}",
+                manifest.name,
+                missing.join(", ")
+            ));
+        }
+        loaded.mods.push(ModMetadata {
+            name: manifest.name.clone(),
+            version: manifest.version.clone(),
+            priority: loaded.mods.len(),
+            missing_dependencies: missing,
+            ..Default::default()
+        });
+    }
+
+    Ok((loaded, warnings))
+}
+
+/// Order in which the mods found on disk are applied.
+#[derive(Debug, Default, PartialEq, Eq)]
+pub(super) struct ModLoadOrder {
+    /// Indices of the mods to list and apply (or skip, if disabled), with
+    /// every mod after its dependencies.
+    pub(super) sorted: Vec<usize>,
+    /// Indices of the enabled mods left out because a dependency is
+    /// missing or disabled, with the names of those dependencies.
+    pub(super) skipped: Vec<(usize, Vec<String>)>,
+}
+
+/// Sort `manifests` (in directory order) so every enabled mod comes after
+/// its dependencies, using Kahn's algorithm. Independent mods keep their
+/// directory order.
+///
+/// An enabled mod is skipped when a dependency is missing, disabled, or
+/// itself skipped. Disabled mods are listed where they are found.
+///
+/// # Errors
+/// Returns a validation error naming the mods involved if their
+/// dependencies form a cycle.
+pub(super) fn resolve_load_order(
+    manifests: &[&ModManifest],
+    disabled: &DisabledMods,
+) -> Result<ModLoadOrder, DataLoadError> {
+    let is_enabled = |i: usize| !disabled.disabled_mods.contains(&manifests[i].name);
+    let by_name: HashMap<&str, usize> = manifests
+        .iter()
+        .enumerate()
+        .map(|(i, manifest)| (manifest.name.as_str(), i))
+        .collect();
+
+    // Skip mods with unavailable dependencies until nothing changes, since
+    // skipping one mod can strand the mods depending on it.
+    let mut available: HashSet<usize> = (0..manifests.len()).filter(|&i| is_enabled(i)).collect();
+    let mut skipped = Vec::new();
+    loop {
+        let mut newly_skipped = Vec::new();
+        for i in 0..manifests.len() {
+            if !available.contains(&i) {
+                continue;
+            }
+            let missing: Vec<String> = manifests[i]
+                .dependencies
+                .iter()
+                .filter(|dep| {
+                    by_name
+                        .get(dep.as_str())
+                        .is_none_or(|index| !available.contains(index))
+                })
+                .cloned()
+                .collect();
+            if !missing.is_empty() {
+                newly_skipped.push((i, missing));
+            }
+        }
+        if newly_skipped.is_empty() {
+            break;
+        }
+        for (i, missing) in newly_skipped {
+            available.remove(&i);
+            skipped.push((i, missing));
+        }
+    }
+    skipped.sort();
+
+    let listed: Vec<usize> = (0..manifests.len())
+        .filter(|i| available.contains(i) || !is_enabled(*i))
+        .collect();
+    let mut in_degree: HashMap<usize, usize> = listed.iter().map(|&i| (i, 0)).collect();
+    let mut dependents: HashMap<usize, Vec<usize>> = HashMap::new();
+    for &i in listed.iter().filter(|i| available.contains(i)) {
+        for dep in &manifests[i].dependencies {
+            dependents.entry(by_name[dep.as_str()]).or_default().push(i);
+            *in_degree.entry(i).or_default() += 1;
+        }
+    }
+
+    let mut ready: BTreeSet<usize> = listed
+        .iter()
+        .copied()
+        .filter(|i| in_degree[i] == 0)
+        .collect();
+    let mut sorted = Vec::with_capacity(listed.len());
+    while let Some(i) = ready.pop_first() {
+        sorted.push(i);
+        for &dependent in dependents.get(&i).into_iter().flatten() {
+            let degree = in_degree.get_mut(&dependent).expect("listed mod");
+            *degree -= 1;
+            if *degree == 0 {
+                ready.insert(dependent);
+            }
+        }
+    }
+
+    if sorted.len() < listed.len() {
+        let cycle: Vec<&str> = listed
+            .iter()
+            .filter(|i| in_degree[i] > 0)
+            .map(|&i| manifests[i].name.as_str())
+            .collect();
+        return Err(DataLoadError::validation(
+            "mod",
+            cycle[0],
+            format!("dependency cycle involving mods {}", cycle.join(", ")),
+        )
+        .with_suggestion("Remove one of the dependencies in the mods' mod.ron manifests."));
+    }
+
+    Ok(ModLoadOrder { sorted, skipped })
+}
+
+/// Subdirectories of `mods_dir`, sorted by name.
+fn mod_directories(mods_dir: &Path) -> Result<Vec<PathBuf>, DataLoadError> {
+    let io_error = |source| DataLoadError::io(source, mods_dir.display().to_string());
+
+    let mut dirs = Vec::new();
+    for entry in fs::read_dir(mods_dir).map_err(io_error)? {
+        let path = entry.map_err(io_error)?.path();
+        if path.is_dir() {
+            dirs.push(path);
+        }
+    }
+    dirs.sort();
+    Ok(dirs)
+}
+
+/// Merge every data file present in `mod_dir` into `game_data`.
+fn apply_mod(
+    mod_dir: &Path,
+    manifest: &ModManifest,
+    game_data: &mut GameData,
+    warnings: &mut ModLoadWarnings,
+    only: Option<&str>,
+) -> ModChanges {
+    let mut file = ModFile {
+        mod_dir,
+        manifest,
+        warnings,
+        only,
+    };
+    let mut changes = ModChanges::default();
+
+    if let Some(data) = file.load::<SurfaceCellTypesData>("surface_cell_types.ron") {
+        changes.merge_by_id(
+            "surface_cell_type",
+            &mut game_data.surface_cell_types,
+            data.surface_cell_type,
+            |e| &e.id,
+        );
+    }
+    if let Some(data) = file.load::<SurfaceBuildingsData>("surface_buildings.ron") {
+        changes.merge_by_id(
+            "surface_building",
+            &mut game_data.surface_buildings,
+            data.surface_building,
+            |e| &e.id,
+        );
+    }
+    if let Some(data) = file.load::<TechnologiesData>("technologies.ron") {
+        changes.merge_by_id(
+            "technology",
+            &mut game_data.technologies,
+            data.technology,
+            |e| &e.id,
+        );
+    }
+    if let Some(data) = file.load::<VictoryConditionsData>("victory_conditions.ron") {
+        changes.merge_by_id(
+            "victory_condition",
+            &mut game_data.victory_conditions,
+            data.victory_condition,
+            |e| &e.id,
+        );
+    }
+    if let Some(data) = file.load::<ScenariosData>("scenarios.ron") {
+        changes.merge_by_id("scenario", &mut game_data.scenarios, data.scenario, |e| {
+            &e.id
+        });
+    }
+    if let Some(data) = file.load::<AdjacencyBonusesData>("adjacency_bonuses.ron") {
+        // Bonuses have no id, so a mod's rules are added to the base ones.
+        changes.added += data.adjacency_bonus.len();
+        game_data.adjacency_bonuses.extend(data.adjacency_bonus);
+    }
+    if let Some(data) = file.load::<PlanetEventsData>("planet_events.ron") {
+        changes.merge_by_id(
+            "planet_event",
+            &mut game_data.planet_events,
+            data.planet_event,
+            |e| &e.id,
+        );
+    }
+
+    changes
+}
+
+/// Loads the optional data files of one mod, recording parse failures.
+struct ModFile<'a> {
+    mod_dir: &'a Path,
+    manifest: &'a ModManifest,
+    warnings: &'a mut ModLoadWarnings,
+    only: Option<&'a str>,
+}
+
+impl ModFile<'_> {
+    /// Parse `file` if the mod provides it.
+    ///
+    /// Returns `None` if the file is absent, filtered out, or fails to parse
+    /// (with a warning).
+    fn load<T: DeserializeOwned>(&mut self, file: &str) -> Option<T> {
+        let path = self.mod_dir.join(file);
+        if self.only.is_some_and(|only| only != file) || !path.exists() {
+            return None;
+        }
+        match load_ron_file(&path) {
+            Ok(data) => Some(data),
+            Err(err) => {
+                self.warnings.push(format!(
+                    "Mod '{}': skipped {file}: {err}",
+                    self.manifest.name
+                ));
+                None
+            }
+        }
+    }
+}
+
+/// Entries added or replaced by one mod.
+#[derive(Default)]
+struct ModChanges {
+    added: usize,
+    overridden: usize,
+    /// Kind and id of every entry the mod added or replaced.
+    touched: Vec<(&'static str, String)>,
+}
+
+impl ModChanges {
+    /// Replace entries of `base` that share an id with an entry of
+    /// `entries`, and append the rest.
+    fn merge_by_id<T>(
+        &mut self,
+        kind: &'static str,
+        base: &mut Vec<T>,
+        entries: Vec<T>,
+        id: impl Fn(&T) -> &String,
+    ) {
+        for entry in entries {
+            self.touched.push((kind, id(&entry).clone()));
+            match base.iter().position(|existing| id(existing) == id(&entry)) {
+                Some(index) => {
+                    base[index] = entry;
+                    self.overridden += 1;
+                }
+                None => {
+                    base.push(entry);
+                    self.added += 1;
+                }
+            }
+        }
+    }
+}
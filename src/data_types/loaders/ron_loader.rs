@@ -9,13 +9,9 @@ pub(crate) fn load_ron_file<T>(path: &Path) -> Result<T, DataLoadError>
 where
     T: for<'de> Deserialize<'de>,
 {
-    let content = fs::read_to_string(path).map_err(|source| DataLoadError::Io {
-        source,
-        path: path.display().to_string(),
-    })?;
+    let content = fs::read_to_string(path)
+        .map_err(|source| DataLoadError::io(source, path.display().to_string()))?;
 
-    ron::from_str::<T>(&content).map_err(|source| DataLoadError::Parse {
-        source,
-        path: path.display().to_string(),
-    })
+    ron::from_str::<T>(&content)
+        .map_err(|source| DataLoadError::parse(source, path.display().to_string()))
 }
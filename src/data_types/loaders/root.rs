@@ -3,41 +3,147 @@ use std::path::Path;
 use crate::data_types::errors::DataLoadError;
 use crate::data_types::game_data::GameData;
 use crate::data_types::registry::GameRegistry;
-// use crate::data_types::validation::validate_game_data;
+use crate::data_types::validation::validate_game_data;
 
+use super::DATA_FILES;
+use super::mods::{DisabledMods, LoadedMods, ModLoadWarnings, apply_mods, load_mod_datasets};
 use super::ron_loader::load_ron_file;
 use super::wrappers::{
-    ScenariosData, SurfaceBuildingsData, SurfaceCellTypesData, TechnologiesData,
-    VictoryConditionsData,
+    AdjacencyBonusesData, PlanetEventsData, ScenariosData, SurfaceBuildingsData,
+    SurfaceCellTypesData, TechnologiesData, VictoryConditionsData,
 };
 
 /// Load the full set of game data from the provided directory.
 pub fn load_game_data<P: AsRef<Path>>(
     data_dir: P,
 ) -> Result<(GameData, GameRegistry), DataLoadError> {
-    let base = data_dir.as_ref();
+    let game_data = load_data_files(data_dir.as_ref())?;
+    validate_and_index(game_data)
+}
+
+/// Load the game data, then apply every mod found in `mods_dir` except
+/// the `disabled` ones.
+///
+/// Mod data files that fail to parse are skipped and reported in the
+/// returned [`ModLoadWarnings`]; see [`load_mod_datasets`] for the failures
+/// that abort loading.
+pub fn load_game_data_with_mods<P: AsRef<Path>, M: AsRef<Path>>(
+    data_dir: P,
+    mods_dir: M,
+    disabled: &DisabledMods,
+) -> Result<(GameData, GameRegistry, LoadedMods, ModLoadWarnings), DataLoadError> {
+    let (game_data, loaded, warnings) = load_unvalidated_game_data(data_dir, mods_dir, disabled)?;
+    let (game_data, registry) = validate_and_index(game_data)?;
+    Ok((game_data, registry, loaded, warnings))
+}
 
-    let surface_cell_types_path = base.join("surface_cell_types.ron");
-    let surface_buildings_path = base.join("surface_buildings.ron");
-    let technologies_path = base.join("technologies.ron");
-    let victory_conditions_path = base.join("victory_conditions.ron");
-    let scenarios_path = base.join("scenarios.ron");
+/// Load the game data and apply mods without validating the result.
+///
+/// For callers that report every problem at once with
+/// [`GameData::validate_all`] instead of stopping at the first.
+pub fn load_unvalidated_game_data<P: AsRef<Path>, M: AsRef<Path>>(
+    data_dir: P,
+    mods_dir: M,
+    disabled: &DisabledMods,
+) -> Result<(GameData, LoadedMods, ModLoadWarnings), DataLoadError> {
+    let mut game_data = load_data_files(data_dir.as_ref())?;
+    let (loaded, warnings) = load_mod_datasets(mods_dir.as_ref(), &mut game_data, disabled)?;
+    Ok((game_data, loaded, warnings))
+}
+
+/// Re-read a single data file and re-apply the enabled mods that provide it.
+///
+/// Only the collection stored in `file` (e.g. `technologies.ron`) is
+/// replaced; the rest of `game_data` is left as is. The caller is expected
+/// to validate the result and rebuild the [`GameRegistry`].
+///
+/// # Errors
+/// Returns an error if `file` is not one of [`DATA_FILES`], the base file
+/// fails to load, or a mod manifest is broken. Mod data files that fail to
+/// parse are reported in the returned [`ModLoadWarnings`].
+pub fn reload_data_file(
+    game_data: &mut GameData,
+    data_dir: &Path,
+    mods_dir: &Path,
+    disabled: &DisabledMods,
+    file: &str,
+) -> Result<ModLoadWarnings, DataLoadError> {
+    let path = data_dir.join(file);
+    match file {
+        "surface_cell_types.ron" => {
+            let data: SurfaceCellTypesData = load_ron_file(&path)?;
+            game_data.surface_cell_types = data.surface_cell_type;
+        }
+        "surface_buildings.ron" => {
+            let data: SurfaceBuildingsData = load_ron_file(&path)?;
+            game_data.surface_buildings = data.surface_building;
+        }
+        "technologies.ron" => {
+            let data: TechnologiesData = load_ron_file(&path)?;
+            game_data.technologies = data.technology;
+        }
+        "victory_conditions.ron" => {
+            let data: VictoryConditionsData = load_ron_file(&path)?;
+            game_data.victory_conditions = data.victory_condition;
+        }
+        "scenarios.ron" => {
+            let data: ScenariosData = load_ron_file(&path)?;
+            game_data.scenarios = data.scenario;
+        }
+        "adjacency_bonuses.ron" => {
+            let data: AdjacencyBonusesData = load_ron_file(&path)?;
+            game_data.adjacency_bonuses = data.adjacency_bonus;
+        }
+        "planet_events.ron" => {
+            let data: PlanetEventsData = load_ron_file(&path)?;
+            game_data.planet_events = data.planet_event;
+        }
+        _ => {
+            return Err(
+                DataLoadError::validation("data_file", file, "not a game data file")
+                    .with_suggestion("Use one of the file names listed in DATA_FILES."),
+            );
+        }
+    }
+
+    let (_, warnings) = apply_mods(mods_dir, game_data, Some(file), disabled)?;
+    Ok(warnings)
+}
+
+/// Parse every base data file in `base`.
+fn load_data_files(base: &Path) -> Result<GameData, DataLoadError> {
+    let [
+        surface_cell_types_path,
+        surface_buildings_path,
+        technologies_path,
+        victory_conditions_path,
+        scenarios_path,
+        adjacency_bonuses_path,
+        planet_events_path,
+    ] = DATA_FILES.map(|file| base.join(file));
 
     let surface_cell_types_data: SurfaceCellTypesData = load_ron_file(&surface_cell_types_path)?;
     let surface_buildings_data: SurfaceBuildingsData = load_ron_file(&surface_buildings_path)?;
     let technologies_data: TechnologiesData = load_ron_file(&technologies_path)?;
     let victory_conditions_data: VictoryConditionsData = load_ron_file(&victory_conditions_path)?;
     let scenarios_data: ScenariosData = load_ron_file(&scenarios_path)?;
+    let adjacency_bonuses_data: AdjacencyBonusesData = load_ron_file(&adjacency_bonuses_path)?;
+    let planet_events_data: PlanetEventsData = load_ron_file(&planet_events_path)?;
 
-    let game_data = GameData {
+    Ok(GameData {
         surface_cell_types: surface_cell_types_data.surface_cell_type,
         surface_buildings: surface_buildings_data.surface_building,
         technologies: technologies_data.technology,
         victory_conditions: victory_conditions_data.victory_condition,
         scenarios: scenarios_data.scenario,
-    };
+        adjacency_bonuses: adjacency_bonuses_data.adjacency_bonus,
+        planet_events: planet_events_data.planet_event,
+    })
+}
 
-    // validate_game_data(&game_data)?;
+/// Validate the merged data and build its lookup registry.
+fn validate_and_index(game_data: GameData) -> Result<(GameData, GameRegistry), DataLoadError> {
+    validate_game_data(&game_data)?;
 
     let registry = GameRegistry::from_game_data(&game_data)?;
 
@@ -1,7 +1,8 @@
 use serde::Deserialize;
 
 use crate::data_types::entities::{
-    Scenario, SurfaceBuilding, SurfaceCellType, Technology, VictoryCondition,
+    AdjacencyBonus, PlanetEvent, Scenario, SurfaceBuilding, SurfaceCellType, Technology,
+    VictoryCondition,
 };
 
 #[derive(Deserialize)]
@@ -28,3 +29,13 @@ pub(crate) struct VictoryConditionsData {
 pub(crate) struct ScenariosData {
     pub scenario: Vec<Scenario>,
 }
+
+#[derive(Deserialize)]
+pub(crate) struct AdjacencyBonusesData {
+    pub adjacency_bonus: Vec<AdjacencyBonus>,
+}
+
+#[derive(Deserialize)]
+pub(crate) struct PlanetEventsData {
+    pub planet_event: Vec<PlanetEvent>,
+}
@@ -11,6 +11,7 @@
 //! - [`ids`] - Strongly-typed ID types for type-safe lookups
 //! - [`loaders`] - RON file parsing and validation
 //! - [`registry`] - `GameRegistry` for O(1) ID-based lookups
+//! - [`validation`] - Cross-entry checks run after loading
 //!
 //! # Usage
 //!
@@ -27,17 +28,27 @@ mod game_data;
 mod ids;
 mod loaders;
 mod registry;
+mod validation;
 
 #[cfg(test)]
 #[path = "tests/mod.rs"]
 mod tests;
 
 pub use entities::{
-    BuildableOn, GenerationMode, Scenario, SpecialBehavior, SurfaceBuilding, SurfaceCellType,
-    Technology, VictoryCondition, VictoryType,
+    AdjacencyBonus, BuildableOn, GenerationMode, PlanetEvent, PlanetEventEffect, ResourceKind,
+    Scenario, SpecialBehavior, SurfaceBuilding, SurfaceCellType, Technology, VictoryCondition,
+    VictoryType,
 };
 pub use errors::DataLoadError;
 pub use game_data::GameData;
-pub use ids::{ScenarioId, SurfaceBuildingId, SurfaceCellTypeId, TechnologyId, VictoryConditionId};
-pub use loaders::load_game_data;
-pub use registry::GameRegistry;
+pub use ids::{
+    ScenarioId, SpeciesId, SurfaceBuildingId, SurfaceCellTypeId, TechnologyId, VictoryConditionId,
+};
+pub(crate) use loaders::load_ron_file;
+pub use loaders::{
+    DATA_FILES, DATA_SCHEMA_VERSION, DISABLED_MODS_PATH, DisabledMods, LoadedMods,
+    MOD_MANIFEST_FILE, ModConflict, ModLoadWarnings, ModManifest, ModMetadata, ModProvenance,
+    load_disabled_mods, load_game_data, load_game_data_with_mods, load_mod_datasets,
+    load_unvalidated_game_data, reload_data_file, save_disabled_mods,
+};
+pub use registry::{GameRegistry, RegistryEntity};
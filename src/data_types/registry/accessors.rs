@@ -1,8 +1,60 @@
+use std::collections::HashMap;
+use std::hash::Hash;
+
 use super::GameRegistry;
 use crate::data_types::entities::*;
 use crate::data_types::game_data::GameData;
 use crate::data_types::ids::*;
 
+/// An entity type indexed by ID in the [`GameRegistry`].
+pub trait RegistryEntity: Sized {
+    /// Typed ID of the entity.
+    type Id: Eq + Hash;
+
+    /// The registry's index for this entity type.
+    fn index(registry: &GameRegistry) -> &HashMap<Self::Id, usize>;
+
+    /// All entities of this type in `data`.
+    fn all(data: &GameData) -> &[Self];
+}
+
+macro_rules! registry_entity {
+    ($entity:ty, $id:ty, $index:ident, $all:ident) => {
+        impl RegistryEntity for $entity {
+            type Id = $id;
+
+            fn index(registry: &GameRegistry) -> &HashMap<Self::Id, usize> {
+                &registry.$index
+            }
+
+            fn all(data: &GameData) -> &[Self] {
+                data.$all()
+            }
+        }
+    };
+}
+
+registry_entity!(
+    SurfaceCellType,
+    SurfaceCellTypeId,
+    surface_cell_type_by_id,
+    surface_cell_types
+);
+registry_entity!(
+    SurfaceBuilding,
+    SurfaceBuildingId,
+    surface_building_by_id,
+    surface_buildings
+);
+registry_entity!(Technology, TechnologyId, technology_by_id, technologies);
+registry_entity!(
+    VictoryCondition,
+    VictoryConditionId,
+    victory_condition_by_id,
+    victory_conditions
+);
+registry_entity!(Scenario, ScenarioId, scenario_by_id, scenarios);
+
 impl GameRegistry {
     fn resolve<'a, I: Eq + std::hash::Hash, T>(
         &self,
@@ -64,4 +116,85 @@ impl GameRegistry {
     ) -> Option<&'a Scenario> {
         self.resolve(&self.scenario_by_id, data.scenarios(), id.into())
     }
+
+    /// ID of the surface cell type at `index` in `GameData`.
+    pub fn surface_cell_type_id_at(&self, index: usize) -> Option<&SurfaceCellTypeId> {
+        self.surface_cell_type_ids.get(index)
+    }
+
+    /// ID of the surface building at `index` in `GameData`.
+    pub fn surface_building_id_at(&self, index: usize) -> Option<&SurfaceBuildingId> {
+        self.surface_building_ids.get(index)
+    }
+
+    /// ID of the technology at `index` in `GameData`.
+    pub fn technology_id_at(&self, index: usize) -> Option<&TechnologyId> {
+        self.technology_ids.get(index)
+    }
+
+    /// ID of the victory condition at `index` in `GameData`.
+    pub fn victory_condition_id_at(&self, index: usize) -> Option<&VictoryConditionId> {
+        self.victory_condition_ids.get(index)
+    }
+
+    /// ID of the scenario at `index` in `GameData`.
+    pub fn scenario_id_at(&self, index: usize) -> Option<&ScenarioId> {
+        self.scenario_ids.get(index)
+    }
+
+    /// Look up a batch of entities of one type, in the order of `ids`.
+    ///
+    /// Each ID maps to `None` when no entity has it.
+    pub fn lookup_many<'a, T, I>(&self, data: &'a GameData, ids: &[I]) -> Vec<Option<&'a T>>
+    where
+        T: RegistryEntity,
+        I: Clone + Into<T::Id>,
+    {
+        let index = T::index(self);
+        let all = T::all(data);
+        ids.iter()
+            .map(|id| index.get(&id.clone().into()).map(|&idx| &all[idx]))
+            .collect()
+    }
+
+    /// Surface buildings unlocked by `tech`, in data order.
+    ///
+    /// Uses the registry's secondary index, so the result matches
+    /// [`GameData::tech_unlocks`] without scanning every building.
+    pub fn buildings_unlocked_by_tech<'a>(
+        &self,
+        data: &'a GameData,
+        tech: impl Into<TechnologyId>,
+    ) -> Vec<&'a SurfaceBuilding> {
+        let buildings = data.surface_buildings();
+        self.buildings_by_tech
+            .get(&tech.into())
+            .map(|indices| indices.iter().map(|&idx| &buildings[idx]).collect())
+            .unwrap_or_default()
+    }
+
+    /// Follow `upgrades_to` links from `start`.
+    ///
+    /// The chain begins with `start` itself and ends at the first building
+    /// without an upgrade (or with an unknown one). A circular chain stops
+    /// before revisiting a building; such data is rejected at load time.
+    pub fn building_upgrade_chain<'a>(
+        &self,
+        data: &'a GameData,
+        start: &SurfaceBuildingId,
+    ) -> Vec<&'a SurfaceBuilding> {
+        let mut chain: Vec<&SurfaceBuilding> = Vec::new();
+        let mut next = self.surface_building(data, start.clone());
+        while let Some(building) = next {
+            if chain.iter().any(|b| b.id == building.id) {
+                break;
+            }
+            chain.push(building);
+            next = building
+                .upgrades_to
+                .as_ref()
+                .and_then(|id| self.surface_building(data, id.clone()));
+        }
+        chain
+    }
 }
@@ -14,11 +14,32 @@ where
     for (i, item) in items.iter().enumerate() {
         let id = id_fn(item);
         if index.insert(id.clone(), i).is_some() {
-            return Err(DataLoadError::DuplicateId {
-                kind,
-                id: id.into(),
-            });
+            return Err(DataLoadError::duplicate_id(kind, id));
         }
     }
     Ok(index)
 }
+
+/// IDs of `items`, in order.
+pub(crate) fn collect_ids<T, F, I>(items: &[T], id_fn: F) -> Vec<I>
+where
+    F: Fn(&T) -> I,
+{
+    items.iter().map(id_fn).collect()
+}
+
+/// Group the indices of `items` by the key `key_fn` returns, skipping items
+/// without a key. Each group keeps the items' order.
+pub(crate) fn group_indices<T, F, K>(items: &[T], key_fn: F) -> HashMap<K, Vec<usize>>
+where
+    F: Fn(&T) -> Option<K>,
+    K: Eq + std::hash::Hash,
+{
+    let mut groups: HashMap<K, Vec<usize>> = HashMap::new();
+    for (i, item) in items.iter().enumerate() {
+        if let Some(key) = key_fn(item) {
+            groups.entry(key).or_default().push(i);
+        }
+    }
+    groups
+}
@@ -8,6 +8,14 @@
 //! let registry = GameRegistry::from_game_data(&game_data)?;
 //! let building = registry.get_surface_building(&game_data, "building_farm_1");
 //! ```
+//!
+//! Each entity type also has a list of IDs in data order, so an index into
+//! a `GameData` slice (as stored in save games) resolves back to its ID
+//! with e.g. [`GameRegistry::technology_id_at`].
+//!
+//! Besides the by-ID indices it keeps a secondary index of the buildings
+//! each technology unlocks, and [`GameRegistry::lookup_many`] resolves a
+//! batch of IDs of any entity type implementing [`RegistryEntity`].
 
 use bevy::prelude::*;
 use std::collections::HashMap;
@@ -21,12 +29,14 @@ use crate::data_types::ids::{
 mod accessors;
 mod builder;
 
-use builder::build_typed_index;
+pub use accessors::RegistryEntity;
+use builder::{build_typed_index, collect_ids, group_indices};
 
 /// Registry providing O(1) lookups of game entities by ID.
 ///
 /// Internally stores `HashMap<TypedId, usize>` indices into the
-/// corresponding vectors in `GameData`.
+/// corresponding vectors in `GameData`, and the reverse `Vec<TypedId>`
+/// lists.
 ///
 /// # Construction
 /// Use `GameRegistry::from_game_data()` after loading `GameData`
@@ -43,6 +53,19 @@ pub struct GameRegistry {
     pub(crate) victory_condition_by_id: HashMap<VictoryConditionId, usize>,
     /// Index of scenarios by ID.
     pub(crate) scenario_by_id: HashMap<ScenarioId, usize>,
+    /// Surface cell type IDs in data order.
+    pub(crate) surface_cell_type_ids: Vec<SurfaceCellTypeId>,
+    /// Surface building IDs in data order.
+    pub(crate) surface_building_ids: Vec<SurfaceBuildingId>,
+    /// Technology IDs in data order.
+    pub(crate) technology_ids: Vec<TechnologyId>,
+    /// Victory condition IDs in data order.
+    pub(crate) victory_condition_ids: Vec<VictoryConditionId>,
+    /// Scenario IDs in data order.
+    pub(crate) scenario_ids: Vec<ScenarioId>,
+    /// Indices of the surface buildings each technology unlocks, in data
+    /// order.
+    pub(crate) buildings_by_tech: HashMap<TechnologyId, Vec<usize>>,
 }
 
 impl GameRegistry {
@@ -74,6 +97,30 @@ impl GameRegistry {
             scenario_by_id: build_typed_index("scenario", data.scenarios(), |s| {
                 ScenarioId::from(s.id.clone())
             })?,
+            surface_cell_type_ids: collect_ids(data.surface_cell_types(), |s| {
+                SurfaceCellTypeId::from(s.id.clone())
+            }),
+            surface_building_ids: collect_ids(data.surface_buildings(), |s| {
+                SurfaceBuildingId::from(s.id.clone())
+            }),
+            technology_ids: collect_ids(data.technologies(), |t| TechnologyId::from(t.id.clone())),
+            victory_condition_ids: collect_ids(data.victory_conditions(), |v| {
+                VictoryConditionId::from(v.id.clone())
+            }),
+            scenario_ids: collect_ids(data.scenarios(), |s| ScenarioId::from(s.id.clone())),
+            buildings_by_tech: group_indices(data.surface_buildings(), |b| {
+                b.unlocked_by_tech_id.as_deref().map(TechnologyId::from)
+            }),
         })
     }
+
+    /// Rebuild the indices after `data` has changed.
+    ///
+    /// # Errors
+    /// Returns the same errors as [`GameRegistry::from_game_data`]; the
+    /// registry is left unchanged in that case.
+    pub fn rebuild(&mut self, data: &GameData) -> Result<(), DataLoadError> {
+        *self = Self::from_game_data(data)?;
+        Ok(())
+    }
 }
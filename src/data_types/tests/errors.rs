@@ -0,0 +1,44 @@
+use crate::data_types::errors::DataLoadError;
+
+fn parse_error() -> ron::error::SpannedError {
+    ron::from_str::<u32>("not a number").expect_err("invalid RON")
+}
+
+#[test]
+fn every_constructor_suggests_a_fix() {
+    let errors = [
+        DataLoadError::io(std::io::Error::other("denied"), "a.ron"),
+        DataLoadError::parse(parse_error(), "a.ron"),
+        DataLoadError::unsupported_schema_version(3, 1, "mod.ron"),
+        DataLoadError::duplicate_id("technology", "tech_a"),
+        DataLoadError::validation("technology", "tech_a", "name_en must not be empty"),
+    ];
+
+    for err in errors {
+        let suggestion = err.suggestion().expect("constructors set a suggestion");
+        assert!(!suggestion.trim().is_empty(), "{err}");
+    }
+}
+
+#[test]
+fn duplicate_ids_suggest_renaming() {
+    let err = DataLoadError::duplicate_id("technology", "tech_a");
+
+    assert_eq!(
+        err.suggestion(),
+        Some("Remove or rename the duplicate entry in its RON file.")
+    );
+}
+
+#[test]
+fn specific_suggestions_replace_the_generic_one() {
+    let err = DataLoadError::validation("surface_building", "building_a", "bad amount")
+        .with_suggestion("Set the amount to 0 or more.");
+
+    assert_eq!(err.suggestion(), Some("Set the amount to 0 or more."));
+    assert_eq!(
+        err.report(),
+        "Validation failed for surface_building 'building_a': bad amount \
+         (suggestion: Set the amount to 0 or more.)"
+    );
+}
@@ -7,5 +7,7 @@ pub fn base_game_data() -> GameData {
         technologies: Vec::new(),
         victory_conditions: Vec::new(),
         scenarios: Vec::new(),
+        adjacency_bonuses: Vec::new(),
+        planet_events: Vec::new(),
     }
 }
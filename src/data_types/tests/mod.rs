@@ -1,6 +1,8 @@
+mod errors;
 mod helpers;
 mod loading;
+mod mods;
 mod registry;
 // mod compute;
 // mod localization;
-// mod validation;
+mod validation;
@@ -0,0 +1,380 @@
+use std::fs;
+use std::path::{Path, PathBuf};
+
+use crate::data_types::errors::DataLoadError;
+use crate::data_types::{
+    DATA_SCHEMA_VERSION, DisabledMods, ModConflict, load_disabled_mods, load_game_data_with_mods,
+    reload_data_file, save_disabled_mods,
+};
+use crate::game_data::validate::check_strict_mods;
+
+/// Fresh, empty mods directory unique to this test.
+fn temp_mods_dir(name: &str) -> PathBuf {
+    let dir = std::env::temp_dir()
+        .join(format!("ascenoria_mods_{}", std::process::id()))
+        .join(name);
+    let _ = fs::remove_dir_all(&dir);
+    fs::create_dir_all(&dir).unwrap();
+    dir
+}
+
+fn write_mod(mods_dir: &Path, id: &str, schema_version: u32, files: &[(&str, &str)]) {
+    let dir = mods_dir.join(id);
+    fs::create_dir_all(&dir).unwrap();
+    fs::write(
+        dir.join("mod.ron"),
+        format!(r#"(name: "{id}", version: "1.0.0", schema_version: {schema_version})"#),
+    )
+    .unwrap();
+    for (file, content) in files {
+        fs::write(dir.join(file), content).unwrap();
+    }
+}
+
+const MODDED_TECH: &str = r#"(technology: [
+    (id: "tech_modded", name_en: "Modded Tech", science_cost: 10),
+])"#;
+
+#[test]
+fn corrupt_mod_file_does_not_block_other_mods() {
+    let mods = temp_mods_dir("corrupt");
+    write_mod(
+        &mods,
+        "a_broken",
+        DATA_SCHEMA_VERSION,
+        &[("technologies.ron", "(technology: [ (id: ")],
+    );
+    write_mod(
+        &mods,
+        "b_working",
+        DATA_SCHEMA_VERSION,
+        &[("technologies.ron", MODDED_TECH)],
+    );
+
+    let (data, registry, _, warnings) =
+        load_game_data_with_mods("assets/data", &mods, &DisabledMods::default())
+            .expect("mods load");
+
+    assert_eq!(warnings.warnings.len(), 1, "{:?}", warnings.warnings);
+    assert!(warnings.warnings[0].contains("a_broken"));
+    assert!(registry.technology(&data, "tech_modded").is_some());
+    assert!(registry.technology(&data, "tech_terraforming").is_some());
+}
+
+#[test]
+fn mods_override_entries_by_id() {
+    let mods = temp_mods_dir("override");
+    let base = fs::read_to_string("assets/data/technologies.ron").unwrap();
+    write_mod(
+        &mods,
+        "renamer",
+        DATA_SCHEMA_VERSION,
+        &[(
+            "technologies.ron",
+            &base.replace(
+                "name_en: \"Terraforming\"",
+                "name_en: \"Modded Terraforming\"",
+            ),
+        )],
+    );
+
+    let (data, registry, _, warnings) =
+        load_game_data_with_mods("assets/data", &mods, &DisabledMods::default())
+            .expect("mods load");
+
+    assert!(!warnings.has_warnings());
+    let tech = registry.technology(&data, "tech_terraforming").unwrap();
+    assert_eq!(tech.name_en, "Modded Terraforming");
+    let count = data
+        .technologies
+        .iter()
+        .filter(|t| t.id == "tech_terraforming")
+        .count();
+    assert_eq!(count, 1);
+}
+
+#[test]
+fn missing_manifest_is_fatal() {
+    let mods = temp_mods_dir("no_manifest");
+    fs::create_dir_all(mods.join("nameless")).unwrap();
+
+    let error = load_game_data_with_mods("assets/data", &mods, &DisabledMods::default())
+        .expect_err("manifest is required");
+    assert!(matches!(error, DataLoadError::Io { .. }));
+}
+
+#[test]
+fn newer_schema_is_fatal() {
+    let mods = temp_mods_dir("future");
+    write_mod(&mods, "future", DATA_SCHEMA_VERSION + 1, &[]);
+
+    match load_game_data_with_mods("assets/data", &mods, &DisabledMods::default())
+        .expect_err("schema is too new")
+    {
+        DataLoadError::UnsupportedSchemaVersion { found, current, .. } => {
+            assert_eq!(found, DATA_SCHEMA_VERSION + 1);
+            assert_eq!(current, DATA_SCHEMA_VERSION);
+        }
+        other => panic!("Unexpected error: {other:?}"),
+    }
+}
+
+#[test]
+fn reloading_one_file_only_replaces_its_collection() {
+    let mods = temp_mods_dir("reload_file");
+    write_mod(
+        &mods,
+        "techs",
+        DATA_SCHEMA_VERSION,
+        &[("technologies.ron", MODDED_TECH)],
+    );
+    let (mut data, _, _, _) =
+        load_game_data_with_mods("assets/data", &mods, &DisabledMods::default())
+            .expect("mods load");
+    let building_count = data.surface_buildings().len();
+
+    fs::write(
+        mods.join("techs").join("technologies.ron"),
+        r#"(technology: [ (id: "tech_reloaded", name_en: "Reloaded", science_cost: 5) ])"#,
+    )
+    .unwrap();
+    let warnings = reload_data_file(
+        &mut data,
+        Path::new("assets/data"),
+        &mods,
+        &DisabledMods::default(),
+        "technologies.ron",
+    )
+    .expect("reload succeeds");
+
+    assert!(!warnings.has_warnings());
+    let ids: Vec<&str> = data.technologies().iter().map(|t| t.id.as_str()).collect();
+    assert!(ids.contains(&"tech_reloaded"));
+    assert!(!ids.contains(&"tech_modded"));
+    assert!(ids.contains(&"tech_terraforming"));
+    assert_eq!(data.surface_buildings().len(), building_count);
+}
+
+#[test]
+fn reports_what_each_mod_changed_and_conflicts() {
+    let mods = temp_mods_dir("conflicts");
+    let rename = r#"(technology: [
+        (id: "tech_terraforming", name_en: "Renamed", science_cost: 10),
+    ])"#;
+    write_mod(
+        &mods,
+        "a_first",
+        DATA_SCHEMA_VERSION,
+        &[("technologies.ron", MODDED_TECH)],
+    );
+    write_mod(
+        &mods,
+        "b_second",
+        DATA_SCHEMA_VERSION,
+        &[("technologies.ron", rename)],
+    );
+    write_mod(
+        &mods,
+        "c_third",
+        DATA_SCHEMA_VERSION,
+        &[("technologies.ron", rename)],
+    );
+
+    let (_, _, loaded, warnings) =
+        load_game_data_with_mods("assets/data", &mods, &DisabledMods::default())
+            .expect("mods load");
+
+    let summary: Vec<(&str, usize, usize, usize, bool)> = loaded
+        .mods
+        .iter()
+        .map(|m| {
+            (
+                m.name.as_str(),
+                m.priority,
+                m.entities_added,
+                m.entities_overridden,
+                m.has_conflicts,
+            )
+        })
+        .collect();
+    assert_eq!(
+        summary,
+        [
+            ("a_first", 0, 1, 0, false),
+            ("b_second", 1, 0, 1, true),
+            ("c_third", 2, 0, 1, true),
+        ]
+    );
+    assert_eq!(warnings.warnings.len(), 1, "{:?}", warnings.warnings);
+    assert!(warnings.warnings[0].contains("tech_terraforming"));
+}
+
+#[test]
+fn disabled_mods_are_listed_but_not_applied() {
+    let mods = temp_mods_dir("disabled");
+    write_mod(
+        &mods,
+        "techs",
+        DATA_SCHEMA_VERSION,
+        &[("technologies.ron", MODDED_TECH)],
+    );
+    let disabled = DisabledMods {
+        disabled_mods: ["techs".to_string()].into(),
+    };
+
+    let (data, registry, loaded, _) =
+        load_game_data_with_mods("assets/data", &mods, &disabled).expect("mods load");
+
+    assert!(registry.technology(&data, "tech_modded").is_none());
+    assert_eq!(loaded.mods.len(), 1);
+    assert!(loaded.mods[0].disabled);
+}
+
+#[test]
+fn disabled_mods_round_trip_through_disk() {
+    let path = temp_mods_dir("disabled_file").join("disabled_mods.ron");
+    assert_eq!(load_disabled_mods(&path), DisabledMods::default());
+
+    let disabled = DisabledMods {
+        disabled_mods: ["techs".to_string(), "farms".to_string()].into(),
+    };
+    save_disabled_mods(&path, &disabled).expect("save disabled mods");
+    assert_eq!(load_disabled_mods(&path), disabled);
+
+    fs::write(&path, "(disabled_mods: 3)").unwrap();
+    assert_eq!(load_disabled_mods(&path), DisabledMods::default());
+}
+
+fn write_dependent_mod(mods_dir: &Path, id: &str, dependencies: &[&str]) {
+    let dir = mods_dir.join(id);
+    fs::create_dir_all(&dir).unwrap();
+    fs::write(
+        dir.join("mod.ron"),
+        format!(
+            r#"(name: "{id}", version: "1.0.0", schema_version: {DATA_SCHEMA_VERSION}, dependencies: {dependencies:?})"#
+        ),
+    )
+    .unwrap();
+}
+
+#[test]
+fn mods_load_after_their_dependencies() {
+    let mods = temp_mods_dir("dependency_chain");
+    write_dependent_mod(&mods, "a", &["b"]);
+    write_dependent_mod(&mods, "b", &["c"]);
+    write_dependent_mod(&mods, "c", &[]);
+
+    let (_, _, loaded, warnings) =
+        load_game_data_with_mods("assets/data", &mods, &DisabledMods::default())
+            .expect("mods load");
+
+    assert!(!warnings.has_warnings(), "{:?}", warnings.warnings);
+    assert_eq!(loaded.load_order, ["c", "b", "a"]);
+    let priorities: Vec<(&str, usize)> = loaded
+        .mods
+        .iter()
+        .map(|m| (m.name.as_str(), m.priority))
+        .collect();
+    assert_eq!(priorities, [("c", 0), ("b", 1), ("a", 2)]);
+}
+
+#[test]
+fn dependency_cycle_is_fatal() {
+    let mods = temp_mods_dir("dependency_cycle");
+    write_dependent_mod(&mods, "a", &["b"]);
+    write_dependent_mod(&mods, "b", &["a"]);
+
+    match load_game_data_with_mods("assets/data", &mods, &DisabledMods::default())
+        .expect_err("cycle is rejected")
+    {
+        DataLoadError::Validation { kind, message, .. } => {
+            assert_eq!(kind, "mod");
+            assert!(message.contains("a, b"), "{message}");
+        }
+        other => panic!("Unexpected error: {other:?}"),
+    }
+}
+
+#[test]
+fn missing_dependency_skips_only_the_dependent_mod() {
+    let mods = temp_mods_dir("dependency_missing");
+    write_dependent_mod(&mods, "a", &["ghost"]);
+    write_mod(
+        &mods,
+        "b",
+        DATA_SCHEMA_VERSION,
+        &[("technologies.ron", MODDED_TECH)],
+    );
+
+    let (data, registry, loaded, warnings) =
+        load_game_data_with_mods("assets/data", &mods, &DisabledMods::default())
+            .expect("mods load");
+
+    assert_eq!(loaded.load_order, ["b"]);
+    assert!(registry.technology(&data, "tech_modded").is_some());
+    let skipped = loaded.mods.iter().find(|m| m.name == "a").unwrap();
+    assert_eq!(skipped.missing_dependencies, ["ghost"]);
+    assert_eq!(warnings.warnings.len(), 1, "{:?}", warnings.warnings);
+    assert!(warnings.warnings[0].contains("ghost"));
+}
+
+#[test]
+fn records_provenance_and_conflicts() {
+    let mods = temp_mods_dir("provenance");
+    let rename = r#"(technology: [
+        (id: "tech_terraforming", name_en: "Renamed", science_cost: 10),
+    ])"#;
+    write_mod(
+        &mods,
+        "a_first",
+        DATA_SCHEMA_VERSION,
+        &[("technologies.ron", rename)],
+    );
+    write_mod(
+        &mods,
+        "b_second",
+        DATA_SCHEMA_VERSION,
+        &[("technologies.ron", MODDED_TECH)],
+    );
+    write_mod(
+        &mods,
+        "c_third",
+        DATA_SCHEMA_VERSION,
+        &[("technologies.ron", rename)],
+    );
+
+    let (_, _, loaded, warnings) =
+        load_game_data_with_mods("assets/data", &mods, &DisabledMods::default())
+            .expect("mods load");
+
+    let provenance = &loaded.provenance;
+    assert_eq!(
+        provenance.source("technology", "tech_terraforming"),
+        Some("c_third")
+    );
+    assert_eq!(
+        provenance.source("technology", "tech_modded"),
+        Some("b_second")
+    );
+    assert_eq!(provenance.source("surface_building", "building_base"), None);
+
+    assert_eq!(
+        warnings.conflicts,
+        [ModConflict {
+            entity_id: "tech_terraforming".to_string(),
+            kind: "technology",
+            first_mod: "a_first".to_string(),
+            second_mod: "c_third".to_string(),
+        }]
+    );
+    assert_eq!(warnings.other_warnings().count(), 0);
+
+    assert!(check_strict_mods(&warnings, false).is_ok());
+    match check_strict_mods(&warnings, true).expect_err("strict mode rejects conflicts") {
+        DataLoadError::DuplicateId { kind, id, .. } => {
+            assert_eq!(kind, "technology");
+            assert_eq!(id, "tech_terraforming");
+        }
+        other => panic!("Unexpected error: {other:?}"),
+    }
+}
@@ -1,5 +1,5 @@
 use super::helpers::base_game_data;
-use crate::data_types::entities::SurfaceCellType;
+use crate::data_types::entities::{SurfaceBuilding, SurfaceCellType, Technology};
 use crate::data_types::registry::GameRegistry;
 
 #[test]
@@ -21,10 +21,133 @@ fn rejects_duplicate_ids() {
     let error = GameRegistry::from_game_data(&data).expect_err("Duplicate ids should be reported");
 
     match error {
-        crate::data_types::errors::DataLoadError::DuplicateId { kind, id } => {
+        crate::data_types::errors::DataLoadError::DuplicateId { kind, id, .. } => {
             assert_eq!(kind, "surface_cell_type");
             assert_eq!(id, "duplicate");
         }
         other => panic!("Unexpected error: {other:?}"),
     }
 }
+
+#[test]
+fn follows_building_upgrade_chain() {
+    let (data, registry) = crate::data_types::load_game_data("assets/data").expect("data loads");
+
+    let chain: Vec<&str> = registry
+        .building_upgrade_chain(&data, &"building_farm_1".into())
+        .iter()
+        .map(|b| b.id.as_str())
+        .collect();
+    assert_eq!(chain, ["building_farm_1", "building_farm_2"]);
+
+    let chain = registry.building_upgrade_chain(&data, &"building_base".into());
+    assert_eq!(chain.len(), 1);
+    assert!(
+        registry
+            .building_upgrade_chain(&data, &"missing".into())
+            .is_empty()
+    );
+}
+
+#[test]
+fn indexes_buildings_unlocked_by_tech() {
+    let (data, registry) = crate::data_types::load_game_data("assets/data").expect("data loads");
+
+    for tech in data.technologies() {
+        let indexed: Vec<&str> = registry
+            .buildings_unlocked_by_tech(&data, tech.id.as_str())
+            .iter()
+            .map(|b| b.id.as_str())
+            .collect();
+        let scanned: Vec<&str> = data.tech_unlocks(&tech.id).map(|b| b.id.as_str()).collect();
+        assert_eq!(indexed, scanned);
+    }
+    assert!(
+        !registry
+            .buildings_unlocked_by_tech(&data, "tech_terraforming")
+            .is_empty()
+    );
+    assert!(
+        registry
+            .buildings_unlocked_by_tech(&data, "missing")
+            .is_empty()
+    );
+}
+
+#[test]
+fn looks_up_many_ids_in_order() {
+    let (data, registry) = crate::data_types::load_game_data("assets/data").expect("data loads");
+
+    let found: Vec<Option<&str>> = registry
+        .lookup_many::<SurfaceBuilding, _>(
+            &data,
+            &["building_farm_2", "missing", "building_farm_1"],
+        )
+        .iter()
+        .map(|b| b.map(|b| b.id.as_str()))
+        .collect();
+    assert_eq!(
+        found,
+        [Some("building_farm_2"), None, Some("building_farm_1")]
+    );
+
+    let ids: Vec<&str> = data.technologies().iter().map(|t| t.id.as_str()).collect();
+    let techs = registry.lookup_many::<Technology, _>(&data, &ids);
+    for (id, tech) in ids.iter().zip(techs) {
+        let single = registry.technology(&data, *id);
+        assert_eq!(tech.map(|t| &t.id), single.map(|t| &t.id));
+    }
+}
+
+#[test]
+fn resolves_indices_back_to_ids() {
+    let (data, registry) = crate::data_types::load_game_data("assets/data").expect("data loads");
+
+    for (i, cell) in data.surface_cell_types().iter().enumerate() {
+        let id = registry.surface_cell_type_id_at(i).expect("index in range");
+        let found = registry.surface_cell_type(&data, id.clone());
+        assert_eq!(found.map(|c| &c.id), Some(&cell.id));
+    }
+    for (i, building) in data.surface_buildings().iter().enumerate() {
+        let id = registry.surface_building_id_at(i).expect("index in range");
+        let found = registry.surface_building(&data, id.clone());
+        assert_eq!(found.map(|b| &b.id), Some(&building.id));
+    }
+    for (i, tech) in data.technologies().iter().enumerate() {
+        let id = registry.technology_id_at(i).expect("index in range");
+        let found = registry.technology(&data, id.clone());
+        assert_eq!(found.map(|t| &t.id), Some(&tech.id));
+    }
+    for (i, condition) in data.victory_conditions().iter().enumerate() {
+        let id = registry.victory_condition_id_at(i).expect("index in range");
+        let found = registry.victory_condition(&data, id.clone());
+        assert_eq!(found.map(|v| &v.id), Some(&condition.id));
+    }
+    for (i, scenario) in data.scenarios().iter().enumerate() {
+        let id = registry.scenario_id_at(i).expect("index in range");
+        let found = registry.scenario(&data, id.clone());
+        assert_eq!(found.map(|s| &s.id), Some(&scenario.id));
+    }
+
+    assert!(
+        registry
+            .surface_cell_type_id_at(data.surface_cell_types().len())
+            .is_none()
+    );
+    assert!(
+        registry
+            .surface_building_id_at(data.surface_buildings().len())
+            .is_none()
+    );
+    assert!(
+        registry
+            .technology_id_at(data.technologies().len())
+            .is_none()
+    );
+    assert!(
+        registry
+            .victory_condition_id_at(data.victory_conditions().len())
+            .is_none()
+    );
+    assert!(registry.scenario_id_at(data.scenarios().len()).is_none());
+}
@@ -0,0 +1,250 @@
+use super::helpers::base_game_data;
+use crate::data_types::entities::{
+    BuildableOn, PlanetEvent, PlanetEventEffect, ResourceKind, SpecialBehavior, SurfaceBuilding,
+    Technology,
+};
+use crate::data_types::errors::DataLoadError;
+use crate::data_types::validation::validate_game_data;
+
+fn storage_building(amount: i32) -> SurfaceBuilding {
+    SurfaceBuilding {
+        id: "building_storage".to_string(),
+        name_en: "Storage".to_string(),
+        color: (0.5, 0.5, 0.5),
+        buildable_on_cell_type: BuildableOn::White,
+        counts_for_adjacency: true,
+        production_cost: 10,
+        yields_food: 0,
+        yields_housing: 0,
+        yields_production: 0,
+        yields_science: 0,
+        unlocked_by_tech_id: None,
+        special_behavior: SpecialBehavior::StorageBonus {
+            resource: ResourceKind::Food,
+            amount,
+        },
+        upgrades_to: None,
+    }
+}
+
+fn tech(id: &str, prerequisites: &[&str]) -> Technology {
+    Technology {
+        id: id.to_string(),
+        name_en: id.to_string(),
+        science_cost: 10,
+        description_en: String::new(),
+        prerequisites: prerequisites.iter().map(|p| p.to_string()).collect(),
+        accident_chance: 0.0,
+    }
+}
+
+fn planet_event(id: &str, trigger_chance: f32) -> PlanetEvent {
+    PlanetEvent {
+        id: id.to_string(),
+        name_en: id.to_string(),
+        description_en: String::new(),
+        trigger_chance,
+        min_turn: 0,
+        effect: PlanetEventEffect::ResearchBoost(10),
+    }
+}
+
+#[test]
+fn accepts_non_negative_storage_bonus() {
+    let mut data = base_game_data();
+    data.surface_buildings = vec![storage_building(0)];
+
+    validate_game_data(&data).expect("a zero bonus is valid");
+}
+
+#[test]
+fn rejects_negative_storage_bonus() {
+    let mut data = base_game_data();
+    data.surface_buildings = vec![storage_building(-5)];
+
+    match validate_game_data(&data).expect_err("negative caps should be reported") {
+        DataLoadError::Validation { kind, id, .. } => {
+            assert_eq!(kind, "surface_building");
+            assert_eq!(id, "building_storage");
+        }
+        other => panic!("Unexpected error: {other:?}"),
+    }
+}
+
+#[test]
+fn rejects_circular_upgrade_chains() {
+    let mut data = base_game_data();
+    let mut first = storage_building(0);
+    first.id = "building_a".to_string();
+    first.upgrades_to = Some("building_b".into());
+    let mut second = storage_building(0);
+    second.id = "building_b".to_string();
+    second.upgrades_to = Some("building_a".into());
+    data.surface_buildings = vec![first, second];
+
+    match validate_game_data(&data).expect_err("cycles should be reported") {
+        DataLoadError::Validation { message, .. } => {
+            assert!(message.contains("circular"), "{message}");
+        }
+        other => panic!("Unexpected error: {other:?}"),
+    }
+}
+
+#[test]
+fn rejects_unknown_upgrade_targets() {
+    let mut data = base_game_data();
+    let mut building = storage_building(0);
+    building.upgrades_to = Some("building_missing".into());
+    data.surface_buildings = vec![building];
+
+    assert!(matches!(
+        validate_game_data(&data),
+        Err(DataLoadError::Validation { .. })
+    ));
+}
+
+#[test]
+fn accepts_acyclic_research_graph() {
+    let mut data = base_game_data();
+    data.technologies = vec![
+        tech("tech_a", &[]),
+        tech("tech_b", &["tech_a"]),
+        tech("tech_c", &["tech_a", "tech_b"]),
+    ];
+
+    validate_game_data(&data).expect("a DAG is valid");
+}
+
+#[test]
+fn names_the_technologies_in_a_research_cycle() {
+    let mut data = base_game_data();
+    data.technologies = vec![
+        tech("tech_root", &[]),
+        tech("tech_a", &["tech_c", "tech_root"]),
+        tech("tech_b", &["tech_a"]),
+        tech("tech_c", &["tech_b"]),
+        tech("tech_after", &["tech_c"]),
+    ];
+
+    match validate_game_data(&data).expect_err("cycles should be reported") {
+        DataLoadError::Validation {
+            kind, id, message, ..
+        } => {
+            assert_eq!(kind, "technology");
+            assert_eq!(id, "tech_a");
+            assert_eq!(
+                message,
+                "circular prerequisites between tech_a, tech_b, tech_c"
+            );
+        }
+        other => panic!("Unexpected error: {other:?}"),
+    }
+}
+
+#[test]
+fn rejects_blank_names() {
+    let mut data = base_game_data();
+    let mut blank = tech("tech_blank", &[]);
+    blank.name_en = "  ".to_string();
+    data.technologies = vec![blank];
+
+    match validate_game_data(&data).expect_err("blank names should be reported") {
+        DataLoadError::Validation {
+            kind, id, message, ..
+        } => {
+            assert_eq!(kind, "technology");
+            assert_eq!(id, "tech_blank");
+            assert_eq!(message, "name_en must not be empty");
+        }
+        other => panic!("Unexpected error: {other:?}"),
+    }
+}
+
+#[test]
+fn allows_missing_descriptions() {
+    let mut data = base_game_data();
+    data.technologies = vec![tech("tech_undocumented", &[])];
+
+    validate_game_data(&data).expect("descriptions are optional");
+}
+
+#[test]
+fn rejects_planet_event_chances_outside_zero_to_one() {
+    let mut data = base_game_data();
+    data.planet_events = vec![
+        planet_event("event_ok", 1.0),
+        planet_event("event_bad", 1.5),
+    ];
+
+    match validate_game_data(&data).expect_err("a 150% chance should be reported") {
+        DataLoadError::Validation { kind, id, .. } => {
+            assert_eq!(kind, "planet_event");
+            assert_eq!(id, "event_bad");
+        }
+        other => panic!("Unexpected error: {other:?}"),
+    }
+}
+
+#[test]
+fn rejects_accident_chances_outside_zero_to_one() {
+    let mut data = base_game_data();
+    let mut risky = tech("tech_risky", &[]);
+    risky.accident_chance = 0.5;
+    let mut broken = tech("tech_broken", &[]);
+    broken.accident_chance = -0.1;
+    data.technologies = vec![risky, broken];
+
+    match validate_game_data(&data).expect_err("a negative chance should be reported") {
+        DataLoadError::Validation { kind, id, .. } => {
+            assert_eq!(kind, "technology");
+            assert_eq!(id, "tech_broken");
+        }
+        other => panic!("Unexpected error: {other:?}"),
+    }
+}
+
+#[test]
+fn rejects_duplicate_planet_events() {
+    let mut data = base_game_data();
+    data.planet_events = vec![planet_event("event_a", 0.1), planet_event("event_a", 0.2)];
+
+    assert!(matches!(
+        validate_game_data(&data),
+        Err(DataLoadError::DuplicateId {
+            kind: "planet_event",
+            ..
+        })
+    ));
+}
+
+#[test]
+fn validate_all_reports_every_problem() {
+    let mut data = base_game_data();
+    let mut blank = tech("tech_blank", &[]);
+    blank.name_en = String::new();
+    data.technologies = vec![blank, tech("tech_blank", &[])];
+    let mut broken = storage_building(-1);
+    broken.upgrades_to = Some("building_missing".into());
+    data.surface_buildings = vec![broken];
+
+    let errors = data.validate_all();
+
+    let messages: Vec<String> = errors.iter().map(ToString::to_string).collect();
+    assert_eq!(errors.len(), 4, "{messages:#?}");
+    assert!(messages[0].contains("name_en must not be empty"));
+    assert!(messages[1].contains("storage bonus must not be negative"));
+    assert!(messages[2].contains("unknown building 'building_missing'"));
+    assert!(matches!(errors[3], DataLoadError::DuplicateId { .. }));
+}
+
+#[test]
+fn validate_all_accepts_the_shipped_data() {
+    let (data, _, _) = crate::data_types::load_unvalidated_game_data(
+        "assets/data",
+        "assets/mods",
+        &Default::default(),
+    )
+    .expect("data loads");
+
+    assert!(data.validate_all().is_empty());
+}
@@ -0,0 +1,327 @@
+//! Validation of loaded game data.
+//!
+//! RON parsing only checks that each file matches its schema. The checks
+//! here catch values that parse fine but make no sense for the game.
+//!
+//! Each check pushes every problem it finds instead of stopping at the
+//! first, so [`GameData::validate_all`] can report them all at once.
+
+use std::collections::{BTreeSet, HashMap, HashSet, VecDeque};
+
+use bevy::log::warn;
+
+use crate::data_types::entities::SpecialBehavior;
+use crate::data_types::errors::DataLoadError;
+use crate::data_types::game_data::GameData;
+use crate::data_types::registry::GameRegistry;
+
+/// Research accident chance above which a technology is logged as suspicious.
+const HIGH_ACCIDENT_CHANCE: f32 = 0.1;
+
+impl GameData {
+    /// Check the data for invalid values (see [`validate_game_data`]).
+    ///
+    /// # Errors
+    /// Returns [`DataLoadError::Validation`] for the first invalid entry found.
+    pub fn validate(&self) -> Result<(), DataLoadError> {
+        validate_game_data(self)
+    }
+
+    /// Run every check, including the duplicate id check done when building
+    /// the [`GameRegistry`], and return all the problems found.
+    ///
+    /// An empty list means the data is valid.
+    pub fn validate_all(&self) -> Vec<DataLoadError> {
+        let mut errors = collect_validation_errors(self);
+        if let Err(err) = GameRegistry::from_game_data(self) {
+            errors.push(err);
+        }
+        errors
+    }
+}
+
+/// Check the loaded data for invalid values.
+///
+/// # Errors
+/// Returns [`DataLoadError::Validation`] for the first invalid entry found.
+pub(crate) fn validate_game_data(game_data: &GameData) -> Result<(), DataLoadError> {
+    match collect_validation_errors(game_data).into_iter().next() {
+        Some(err) => Err(err),
+        None => Ok(()),
+    }
+}
+
+/// Run every validation check and collect the problems in order.
+fn collect_validation_errors(game_data: &GameData) -> Vec<DataLoadError> {
+    let mut errors = Vec::new();
+    validate_display_texts(game_data, &mut errors);
+    validate_storage_bonuses(game_data, &mut errors);
+    validate_upgrade_chains(game_data, &mut errors);
+    validate_planet_events(game_data, &mut errors);
+    validate_research_accidents(game_data, &mut errors);
+    validate_research_graph_acyclic(game_data, &mut errors);
+    errors
+}
+
+/// Storage bonuses must not be negative.
+fn validate_storage_bonuses(game_data: &GameData, errors: &mut Vec<DataLoadError>) {
+    for building in &game_data.surface_buildings {
+        if let SpecialBehavior::StorageBonus { amount, .. } = building.special_behavior {
+            if amount < 0 {
+                errors.push(
+                    DataLoadError::validation(
+                        "surface_building",
+                        &building.id,
+                        format!("storage bonus must not be negative (got {amount})"),
+                    )
+                    .with_suggestion("Set the storage bonus amount to 0 or more."),
+                );
+            }
+        }
+    }
+}
+
+/// Display names must not be blank. Technology descriptions are optional,
+/// so a missing one is only logged.
+fn validate_display_texts(game_data: &GameData, errors: &mut Vec<DataLoadError>) {
+    let names = game_data
+        .surface_cell_types
+        .iter()
+        .map(|e| ("surface_cell_type", &e.id, &e.name_en))
+        .chain(
+            game_data
+                .surface_buildings
+                .iter()
+                .map(|e| ("surface_building", &e.id, &e.name_en)),
+        )
+        .chain(
+            game_data
+                .technologies
+                .iter()
+                .map(|e| ("technology", &e.id, &e.name_en)),
+        )
+        .chain(
+            game_data
+                .victory_conditions
+                .iter()
+                .map(|e| ("victory_condition", &e.id, &e.name_en)),
+        )
+        .chain(
+            game_data
+                .scenarios
+                .iter()
+                .map(|e| ("scenario", &e.id, &e.name_en)),
+        )
+        .chain(
+            game_data
+                .planet_events
+                .iter()
+                .map(|e| ("planet_event", &e.id, &e.name_en)),
+        );
+    for (kind, id, name) in names {
+        errors.extend(validate_text(kind, id, "name_en", name));
+    }
+
+    for tech in &game_data.technologies {
+        if tech.description_en.trim().is_empty() {
+            warn!("technology '{}' has no description_en", tech.id);
+        }
+    }
+}
+
+/// Reject a display text that is empty or only whitespace.
+fn validate_text(
+    kind: &'static str,
+    id: &str,
+    field: &'static str,
+    text: &str,
+) -> Option<DataLoadError> {
+    text.trim().is_empty().then(|| {
+        DataLoadError::validation(kind, id, format!("{field} must not be empty"))
+            .with_suggestion("Give the entry a display name.")
+    })
+}
+
+/// Research accident chances must be between 0 and 1; chances above
+/// [`HIGH_ACCIDENT_CHANCE`] are allowed but logged as likely mistakes.
+fn validate_research_accidents(game_data: &GameData, errors: &mut Vec<DataLoadError>) {
+    for tech in &game_data.technologies {
+        if !(0.0..=1.0).contains(&tech.accident_chance) {
+            errors.push(
+                DataLoadError::validation(
+                    "technology",
+                    &tech.id,
+                    format!(
+                        "accident_chance must be between 0 and 1 (got {})",
+                        tech.accident_chance
+                    ),
+                )
+                .with_suggestion("Use a chance such as 0.05 for 5% per turn."),
+            );
+        } else if tech.accident_chance > HIGH_ACCIDENT_CHANCE {
+            warn!(
+                "technology '{}' has a high accident_chance ({}); accidents will be frequent",
+                tech.id, tech.accident_chance
+            );
+        }
+    }
+}
+
+/// Planet event ids must be unique and trigger chances between 0 and 1.
+fn validate_planet_events(game_data: &GameData, errors: &mut Vec<DataLoadError>) {
+    let mut seen = HashSet::new();
+    for event in &game_data.planet_events {
+        if !seen.insert(event.id.as_str()) {
+            errors.push(DataLoadError::duplicate_id(
+                "planet_event",
+                event.id.clone(),
+            ));
+        }
+        if !(0.0..=1.0).contains(&event.trigger_chance) {
+            errors.push(
+                DataLoadError::validation(
+                    "planet_event",
+                    &event.id,
+                    format!(
+                        "trigger_chance must be between 0 and 1 (got {})",
+                        event.trigger_chance
+                    ),
+                )
+                .with_suggestion("Use a chance such as 0.05 for 5% per turn."),
+            );
+        }
+    }
+}
+
+/// Every `upgrades_to` must name a known building, and following the links
+/// must never lead back to a building already in the chain.
+fn validate_upgrade_chains(game_data: &GameData, errors: &mut Vec<DataLoadError>) {
+    let upgrades: HashMap<&str, Option<&str>> = game_data
+        .surface_buildings
+        .iter()
+        .map(|b| (b.id.as_str(), b.upgrades_to.as_ref().map(|id| id.as_str())))
+        .collect();
+
+    for building in &game_data.surface_buildings {
+        let mut visited = HashSet::from([building.id.as_str()]);
+        let mut current = building.id.as_str();
+
+        while let Some(next) = upgrades[current] {
+            // Report broken links and cycles only from the buildings that
+            // cause them, not from every building leading up to them.
+            if !upgrades.contains_key(next) {
+                if current == building.id {
+                    errors.push(
+                        DataLoadError::validation(
+                            "surface_building",
+                            current,
+                            format!("upgrades to unknown building '{next}'"),
+                        )
+                        .with_suggestion(
+                            "Point upgrades_to at an existing building id, or set it to None.",
+                        ),
+                    );
+                }
+                break;
+            }
+            if !visited.insert(next) {
+                if next == building.id {
+                    errors.push(
+                        DataLoadError::validation(
+                            "surface_building",
+                            &building.id,
+                            format!("circular upgrade chain through '{current}'"),
+                        )
+                        .with_suggestion(
+                            "Set upgrades_to to None on the last building of the chain.",
+                        ),
+                    );
+                }
+                break;
+            }
+            current = next;
+        }
+    }
+}
+
+/// Technology prerequisites must form a directed acyclic graph.
+///
+/// Uses Kahn's algorithm: technologies are removed once all their
+/// prerequisites have been removed. Whatever remains depends on a cycle;
+/// pruning the remainder down to technologies that other remaining
+/// technologies still require leaves the cycle participants themselves.
+/// Prerequisites naming unknown technologies are ignored here.
+fn validate_research_graph_acyclic(game_data: &GameData, errors: &mut Vec<DataLoadError>) {
+    let known: HashSet<&str> = game_data
+        .technologies
+        .iter()
+        .map(|tech| tech.id.as_str())
+        .collect();
+    let prerequisites: HashMap<&str, Vec<&str>> = game_data
+        .technologies
+        .iter()
+        .map(|tech| {
+            let prereqs = tech
+                .prerequisites
+                .iter()
+                .map(String::as_str)
+                .filter(|id| known.contains(id))
+                .collect();
+            (tech.id.as_str(), prereqs)
+        })
+        .collect();
+
+    let mut in_degree: HashMap<&str, usize> = prerequisites
+        .iter()
+        .map(|(&id, prereqs)| (id, prereqs.len()))
+        .collect();
+    let mut ready: VecDeque<&str> = in_degree
+        .iter()
+        .filter(|&(_, &count)| count == 0)
+        .map(|(&id, _)| id)
+        .collect();
+
+    while let Some(id) = ready.pop_front() {
+        for (dependent, prereqs) in &prerequisites {
+            if prereqs.contains(&id) {
+                let count = in_degree.get_mut(dependent).expect("known technology");
+                *count -= 1;
+                if *count == 0 {
+                    ready.push_back(*dependent);
+                }
+            }
+        }
+    }
+
+    let mut remaining: BTreeSet<&str> = in_degree
+        .into_iter()
+        .filter(|&(_, count)| count > 0)
+        .map(|(id, _)| id)
+        .collect();
+    if remaining.is_empty() {
+        return;
+    }
+
+    // Drop technologies that merely depend on a cycle.
+    loop {
+        let required: HashSet<&str> = remaining
+            .iter()
+            .flat_map(|id| prerequisites[id].iter().copied())
+            .collect();
+        let before = remaining.len();
+        remaining.retain(|id| required.contains(id));
+        if remaining.len() == before {
+            break;
+        }
+    }
+
+    let cycle: Vec<&str> = remaining.into_iter().collect();
+    errors.push(
+        DataLoadError::validation(
+            "technology",
+            cycle[0],
+            format!("circular prerequisites between {}", cycle.join(", ")),
+        )
+        .with_suggestion("Remove a prerequisite so the technologies no longer require each other."),
+    );
+}
@@ -0,0 +1,62 @@
+//! Defeat screen and game statistics.
+//!
+//! [`GameStatsTracker`] counts what the player achieved during the game.
+//! When a [`DefeatEvent`] arrives, the reason and a snapshot of the stats
+//! are stored in [`LastGameResult`] and the game moves to
+//! `GameState::DefeatScreen`, which shows them with buttons to retry or
+//! return to the main menu.
+//!
+//! # Module Structure
+//!
+//! - [`setup`] - UI spawning/cleanup
+//! - [`systems`] - Stats tracking, the switch to the screen, and buttons
+//! - [`types`] - Components and resources
+//!
+//! [`DefeatEvent`]: crate::victory::DefeatEvent
+
+mod setup;
+mod systems;
+pub mod types;
+
+use bevy::prelude::*;
+
+use crate::defeat_screen::types::{GameStatsTracker, LastGameResult};
+use crate::main_menu::GameState;
+
+#[cfg(test)]
+mod tests;
+
+/// Plugin that tracks game statistics and manages the defeat screen.
+///
+/// Statistics are tracked while in `GameState::PlanetView`; the screen's
+/// systems only run in `GameState::DefeatScreen`.
+pub struct DefeatPlugin;
+
+impl Plugin for DefeatPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<GameStatsTracker>()
+            .init_resource::<LastGameResult>()
+            .add_systems(OnEnter(GameState::DefeatScreen), setup::setup_defeat_screen)
+            .add_systems(
+                OnExit(GameState::DefeatScreen),
+                setup::cleanup_defeat_screen,
+            )
+            .add_systems(
+                Update,
+                (
+                    systems::game_stats_system,
+                    systems::defeat_transition_system,
+                )
+                    .chain()
+                    .run_if(in_state(GameState::PlanetView)),
+            )
+            .add_systems(
+                Update,
+                (
+                    systems::defeat_screen_action_system,
+                    systems::defeat_screen_button_style_system,
+                )
+                    .run_if(in_state(GameState::DefeatScreen)),
+            );
+    }
+}
@@ -0,0 +1,168 @@
+//! Spawning and despawning of the defeat screen.
+
+use bevy::{ecs::hierarchy::ChildSpawnerCommands, prelude::*};
+
+use crate::defeat_screen::types::{DefeatScreenAction, DefeatScreenRoot, LastGameResult};
+use crate::diplomacy::PLAYER_SPECIES_NAME;
+
+/// Screen background.
+const BACKGROUND: Color = Color::srgb(0.12, 0.02, 0.03);
+/// Background of the summary panel.
+const PANEL_BG: Color = Color::srgb(0.2, 0.05, 0.06);
+/// Button background.
+pub(super) const BUTTON_NORMAL: Color = Color::srgb(0.3, 0.06, 0.07);
+/// Button background on hover.
+pub(super) const BUTTON_HOVERED: Color = Color::srgb(0.42, 0.1, 0.11);
+/// Button background while pressed.
+pub(super) const BUTTON_PRESSED: Color = Color::srgb(0.55, 0.14, 0.15);
+/// Borders.
+const BORDER: Color = Color::srgb(0.6, 0.15, 0.15);
+/// Title text.
+const TITLE: Color = Color::srgb(0.95, 0.3, 0.3);
+/// Regular text.
+const TEXT: Color = Color::srgb(0.9, 0.8, 0.8);
+/// Secondary text, and the grayed-out species name.
+const DIM_TEXT: Color = Color::srgb(0.5, 0.45, 0.45);
+
+/// Spawn the defeat screen.
+///
+/// # Layout
+/// ```text
+/// ┌──────────────────────────────────┐
+/// │             DEFEAT               │
+/// │       Time limit exceeded        │
+/// │ ┌──────────────────────────────┐ │
+/// │ │ Player                       │ │
+/// │ │ Turns survived          100  │ │
+/// │ │ Peak buildings           14  │ │
+/// │ │ Buildings constructed    21  │ │
+/// │ │ Technologies researched   6  │ │
+/// │ └──────────────────────────────┘ │
+/// │        [Retry] [Main Menu]       │
+/// └──────────────────────────────────┘
+/// ```
+pub fn setup_defeat_screen(mut commands: Commands, result: Res<LastGameResult>) {
+    let (reason, stats) = match &*result {
+        LastGameResult::Defeat { reason, stats } => (reason.label(), stats.clone()),
+        LastGameResult::None => ("", Default::default()),
+    };
+    let rows = [
+        ("Turns survived", stats.turns_survived.to_string()),
+        ("Peak buildings", stats.peak_buildings.to_string()),
+        (
+            "Buildings constructed",
+            stats.buildings_constructed.to_string(),
+        ),
+        (
+            "Technologies researched",
+            stats.techs_researched.to_string(),
+        ),
+    ];
+
+    commands.spawn((Camera2d::default(), DefeatScreenRoot));
+
+    commands
+        .spawn((
+            Node {
+                width: Val::Percent(100.0),
+                height: Val::Percent(100.0),
+                flex_direction: FlexDirection::Column,
+                align_items: AlignItems::Center,
+                justify_content: JustifyContent::Center,
+                row_gap: Val::Px(16.0),
+                ..default()
+            },
+            BackgroundColor(BACKGROUND),
+            DefeatScreenRoot,
+        ))
+        .with_children(|root| {
+            root.spawn((
+                Text::new("DEFEAT"),
+                TextFont {
+                    font_size: 56.0,
+                    ..default()
+                },
+                TextColor(TITLE),
+            ));
+            root.spawn((
+                Text::new(reason),
+                TextFont {
+                    font_size: 22.0,
+                    ..default()
+                },
+                TextColor(TEXT),
+            ));
+
+            root.spawn((
+                Node {
+                    width: Val::Px(360.0),
+                    flex_direction: FlexDirection::Column,
+                    padding: UiRect::all(Val::Px(16.0)),
+                    border: UiRect::all(Val::Px(2.0)),
+                    row_gap: Val::Px(6.0),
+                    ..default()
+                },
+                BackgroundColor(PANEL_BG),
+                BorderColor::all(BORDER),
+            ))
+            .with_children(|panel| {
+                panel.spawn((
+                    Text::new(PLAYER_SPECIES_NAME),
+                    TextFont {
+                        font_size: 20.0,
+                        ..default()
+                    },
+                    TextColor(DIM_TEXT),
+                ));
+                for (label, value) in rows {
+                    spawn_stat_row(panel, label, value);
+                }
+            });
+
+            root.spawn(Node {
+                column_gap: Val::Px(12.0),
+                ..default()
+            })
+            .with_children(|footer| {
+                spawn_button(footer, "Retry", DefeatScreenAction::Retry);
+                spawn_button(footer, "Main Menu", DefeatScreenAction::MainMenu);
+            });
+        });
+}
+
+/// Despawn every entity of the defeat screen.
+pub fn cleanup_defeat_screen(mut commands: Commands, query: Query<Entity, With<DefeatScreenRoot>>) {
+    for entity in &query {
+        commands.entity(entity).despawn();
+    }
+}
+
+fn spawn_stat_row(parent: &mut ChildSpawnerCommands, label: &str, value: String) {
+    parent
+        .spawn(Node {
+            justify_content: JustifyContent::SpaceBetween,
+            ..default()
+        })
+        .with_children(|row| {
+            row.spawn((Text::new(label), TextColor(DIM_TEXT)));
+            row.spawn((Text::new(value), TextColor(TEXT)));
+        });
+}
+
+fn spawn_button(parent: &mut ChildSpawnerCommands, label: &str, action: DefeatScreenAction) {
+    parent
+        .spawn((
+            Button,
+            Node {
+                padding: UiRect::axes(Val::Px(16.0), Val::Px(8.0)),
+                border: UiRect::all(Val::Px(2.0)),
+                ..default()
+            },
+            BackgroundColor(BUTTON_NORMAL),
+            BorderColor::all(BORDER),
+            action,
+        ))
+        .with_children(|button| {
+            button.spawn((Text::new(label), TextColor(TEXT)));
+        });
+}
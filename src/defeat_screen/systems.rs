@@ -0,0 +1,92 @@
+//! Stats tracking, the switch to the defeat screen, and button handling.
+
+use bevy::prelude::*;
+
+use crate::defeat_screen::setup::{BUTTON_HOVERED, BUTTON_NORMAL, BUTTON_PRESSED};
+use crate::defeat_screen::types::{DefeatScreenAction, GameStatsTracker, LastGameResult};
+use crate::main_menu::{GameState, reset_session};
+use crate::planet_view::types::{BuildingCompleted, PlanetViewState, TechUnlocked, TurnEnded};
+use crate::screen_transition::TransitionToState;
+use crate::victory::DefeatEvent;
+
+/// Count turns, finished buildings, and researched technologies.
+pub fn game_stats_system(
+    mut turn_events: MessageReader<TurnEnded>,
+    mut building_events: MessageReader<BuildingCompleted>,
+    mut tech_events: MessageReader<TechUnlocked>,
+    planet_state: Res<PlanetViewState>,
+    mut stats: ResMut<GameStatsTracker>,
+) {
+    for event in turn_events.read() {
+        stats.record_turn(event.turn);
+    }
+    let standing = planet_state.surface.as_ref().map_or(0, |surface| {
+        surface
+            .tiles
+            .iter()
+            .filter(|tile| tile.building.is_some())
+            .count()
+    });
+    for _ in building_events.read() {
+        stats.record_building(standing);
+    }
+    for _ in tech_events.read() {
+        stats.record_tech();
+    }
+}
+
+/// Keep the outcome and show the defeat screen once the game is lost.
+pub fn defeat_transition_system(
+    mut defeat_events: MessageReader<DefeatEvent>,
+    stats: Res<GameStatsTracker>,
+    mut result: ResMut<LastGameResult>,
+    mut transitions: MessageWriter<TransitionToState>,
+) {
+    let Some(event) = defeat_events.read().next() else {
+        return;
+    };
+    *result = LastGameResult::Defeat {
+        reason: event.reason,
+        stats: stats.clone(),
+    };
+    transitions.write(TransitionToState(GameState::DefeatScreen));
+}
+
+/// Handle the Retry and Main Menu buttons.
+pub fn defeat_screen_action_system(
+    query: Query<(&Interaction, &DefeatScreenAction), (Changed<Interaction>, With<Button>)>,
+    mut transitions: MessageWriter<TransitionToState>,
+    mut commands: Commands,
+) {
+    for (interaction, action) in &query {
+        if *interaction != Interaction::Pressed {
+            continue;
+        }
+        match action {
+            DefeatScreenAction::Retry => {
+                info!("Retrying with a new game...");
+                reset_session(&mut commands);
+                transitions.write(TransitionToState(GameState::PlanetView));
+            }
+            DefeatScreenAction::MainMenu => {
+                transitions.write(TransitionToState(GameState::MainMenu));
+            }
+        }
+    }
+}
+
+/// Highlight hovered and pressed buttons.
+pub fn defeat_screen_button_style_system(
+    mut query: Query<
+        (&Interaction, &mut BackgroundColor),
+        (Changed<Interaction>, With<DefeatScreenAction>),
+    >,
+) {
+    for (interaction, mut bg_color) in &mut query {
+        *bg_color = BackgroundColor(match interaction {
+            Interaction::Pressed => BUTTON_PRESSED,
+            Interaction::Hovered => BUTTON_HOVERED,
+            Interaction::None => BUTTON_NORMAL,
+        });
+    }
+}
@@ -0,0 +1,34 @@
+use super::types::GameStatsTracker;
+
+#[test]
+fn stats_count_turns_buildings_and_techs() {
+    let mut stats = GameStatsTracker::default();
+
+    stats.record_turn(2);
+    stats.record_turn(3);
+    stats.record_building(2);
+    stats.record_building(3);
+    stats.record_tech();
+
+    assert_eq!(
+        stats,
+        GameStatsTracker {
+            turns_survived: 2,
+            peak_buildings: 3,
+            buildings_constructed: 2,
+            techs_researched: 1,
+        }
+    );
+}
+
+#[test]
+fn peak_buildings_keeps_the_highest_count() {
+    let mut stats = GameStatsTracker::default();
+
+    stats.record_building(5);
+    // A building was demolished before the next one finished.
+    stats.record_building(4);
+
+    assert_eq!(stats.peak_buildings, 5);
+    assert_eq!(stats.buildings_constructed, 2);
+}
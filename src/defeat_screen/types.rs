@@ -0,0 +1,70 @@
+//! Components and resources for the defeat screen.
+
+use bevy::prelude::*;
+use serde::{Deserialize, Serialize};
+
+use crate::victory::DefeatReason;
+
+/// What the player achieved during the current game.
+///
+/// Saved with the game so the summary covers the whole session.
+#[derive(Resource, Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct GameStatsTracker {
+    /// Turns played to the end.
+    pub turns_survived: u32,
+    /// Most buildings standing on the colony at once.
+    pub peak_buildings: usize,
+    /// Buildings finished through the production queue.
+    pub buildings_constructed: u32,
+    /// Technologies researched.
+    pub techs_researched: u32,
+}
+
+impl GameStatsTracker {
+    /// Record that `turn` has begun.
+    pub fn record_turn(&mut self, turn: u32) {
+        self.turns_survived = self.turns_survived.max(turn.saturating_sub(1));
+    }
+
+    /// Record a finished building, with `standing` buildings now on the
+    /// colony.
+    pub fn record_building(&mut self, standing: usize) {
+        self.buildings_constructed += 1;
+        self.peak_buildings = self.peak_buildings.max(standing);
+    }
+
+    /// Record a researched technology.
+    pub fn record_tech(&mut self) {
+        self.techs_researched += 1;
+    }
+}
+
+/// Outcome of the last finished game, shown on the defeat screen.
+#[derive(Resource, Debug, Clone, Default, PartialEq, Eq)]
+pub enum LastGameResult {
+    /// No game has been lost yet.
+    #[default]
+    None,
+    /// The player lost the game.
+    Defeat {
+        /// Why the game was lost.
+        reason: DefeatReason,
+        /// Statistics at the moment of defeat.
+        stats: GameStatsTracker,
+    },
+}
+
+/// Marker component for all entities belonging to the defeat screen.
+///
+/// Used for cleanup when leaving the screen.
+#[derive(Component)]
+pub struct DefeatScreenRoot;
+
+/// Buttons on the defeat screen.
+#[derive(Component, Debug, Clone, Copy, PartialEq, Eq)]
+pub enum DefeatScreenAction {
+    /// Start a new game.
+    Retry,
+    /// Return to the main menu.
+    MainMenu,
+}
@@ -0,0 +1,207 @@
+//! Espionage: stealing technology from other species.
+//!
+//! A spy mission is launched from the "Spy" button of a species in the
+//! diplomacy panel. It costs production up front and then succeeds with
+//! [`SPY_SUCCESS_CHANCE`]:
+//!
+//! - On success the player learns the most expensive technology the target
+//!   has researched that the player could research now (see
+//!   [`stealable_tech`]).
+//! - On failure relations with the target worsen by one step, and after
+//!   [`MAX_FAILED_SPY_ATTEMPTS`] failures against the same species it
+//!   declares war.
+
+use bevy::prelude::*;
+use rand::prelude::*;
+use thiserror::Error;
+
+use crate::ai::AiState;
+use crate::data_types::{GameData, SpeciesId, TechnologyId};
+use crate::diplomacy::{DiplomacyState, DiplomaticStance, player_species_id};
+use crate::planet_view::types::{PlanetViewState, TechUnlocked};
+use crate::research::ResearchQueue;
+
+/// Production spent on a spy mission.
+pub const SPY_INDUSTRY_COST: u32 = 50;
+
+/// Chance that a spy mission succeeds.
+pub const SPY_SUCCESS_CHANCE: f32 = 0.5;
+
+/// Failed missions against one species after which it declares war.
+pub const MAX_FAILED_SPY_ATTEMPTS: u32 = 3;
+
+/// A spy mission against another species.
+#[derive(Debug, Clone, PartialEq)]
+pub struct EspionageAction {
+    /// Species to steal from.
+    pub target: SpeciesId,
+    /// Chance that the mission succeeds, from 0.0 to 1.0.
+    pub success_chance: f32,
+    /// Production spent on the mission, whatever its outcome.
+    pub industry_cost: u32,
+}
+
+impl EspionageAction {
+    /// A mission against `target` with the default chance and cost.
+    pub fn against(target: SpeciesId) -> Self {
+        Self {
+            target,
+            success_chance: SPY_SUCCESS_CHANCE,
+            industry_cost: SPY_INDUSTRY_COST,
+        }
+    }
+}
+
+/// Result of a spy mission that was carried out.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum EspionageOutcome {
+    /// The technology was stolen.
+    Stolen(TechnologyId),
+    /// The spies were caught; relations are now at this stance.
+    Caught(DiplomaticStance),
+}
+
+/// Reasons a spy mission cannot be launched.
+#[derive(Debug, Error, PartialEq, Eq)]
+pub enum EspionageError {
+    /// The production stockpile cannot pay for the mission.
+    #[error("A spy mission needs {needed} production, only {available} available")]
+    NotEnoughProduction {
+        /// Production cost of the mission.
+        needed: u32,
+        /// Production in the stockpile.
+        available: u32,
+    },
+    /// The target knows nothing the player could use.
+    #[error("{0} has no technology worth stealing")]
+    NothingToSteal(String),
+    /// The player has not met the target.
+    #[error("Species {0} has not been contacted")]
+    NotContacted(String),
+}
+
+/// Technology `player` could take from `target`: the most expensive one
+/// `target` has researched whose prerequisites `player` has researched but
+/// which `player` has not.
+pub fn stealable_tech(
+    player: &ResearchQueue,
+    target: &ResearchQueue,
+    game_data: &GameData,
+) -> Option<TechnologyId> {
+    game_data
+        .technologies()
+        .iter()
+        .filter(|tech| {
+            let id = TechnologyId::from(tech.id.as_str());
+            target.is_researched(&id)
+                && !player.is_researched(&id)
+                && tech
+                    .prerequisites
+                    .iter()
+                    .all(|prereq| player.is_researched(&TechnologyId::from(prereq.as_str())))
+        })
+        .max_by(|a, b| a.science_cost.cmp(&b.science_cost).then(b.id.cmp(&a.id)))
+        .map(|tech| TechnologyId::from(tech.id.as_str()))
+}
+
+/// Carry out `action` for the player.
+///
+/// The cost is paid before the roll. A stolen technology is granted with
+/// [`ResearchQueue::grant`].
+///
+/// # Errors
+/// Returns an [`EspionageError`] (leaving everything unchanged) if the
+/// target has not been met, has nothing to steal, or the player cannot pay.
+pub fn attempt_espionage(
+    action: &EspionageAction,
+    planet: &mut PlanetViewState,
+    research: &mut ResearchQueue,
+    target_research: &ResearchQueue,
+    diplomacy: &mut DiplomacyState,
+    game_data: &GameData,
+    rng: &mut impl Rng,
+) -> Result<EspionageOutcome, EspionageError> {
+    let player = player_species_id();
+    if diplomacy.stance(&player, &action.target) == DiplomaticStance::Unknown {
+        return Err(EspionageError::NotContacted(action.target.0.clone()));
+    }
+    let Some(tech) = stealable_tech(research, target_research, game_data) else {
+        return Err(EspionageError::NothingToSteal(action.target.0.clone()));
+    };
+    if planet.production < action.industry_cost {
+        return Err(EspionageError::NotEnoughProduction {
+            needed: action.industry_cost,
+            available: planet.production,
+        });
+    }
+
+    planet.production -= action.industry_cost;
+    if rng.r#gen::<f32>() < action.success_chance {
+        research.grant(tech.clone());
+        return Ok(EspionageOutcome::Stolen(tech));
+    }
+
+    let failures = diplomacy
+        .failed_spy_attempts
+        .entry(action.target.clone())
+        .or_default();
+    *failures += 1;
+    let stance = if *failures >= MAX_FAILED_SPY_ATTEMPTS {
+        // Already at war is fine: the stance is what matters.
+        let _ = diplomacy.declare_war(&player, &action.target);
+        DiplomaticStance::War
+    } else {
+        diplomacy
+            .worsen_relations(&player, &action.target)
+            .unwrap_or(DiplomaticStance::War)
+    };
+    Ok(EspionageOutcome::Caught(stance))
+}
+
+/// Spy button of a species row in the diplomacy panel.
+#[derive(Component)]
+pub struct EspionageButton {
+    /// Species to steal from.
+    pub target: SpeciesId,
+}
+
+/// Launch a spy mission when a Spy button is pressed.
+pub fn espionage_button_system(
+    button_query: Query<(&Interaction, &EspionageButton), Changed<Interaction>>,
+    mut diplomacy: ResMut<DiplomacyState>,
+    mut planet_state: ResMut<PlanetViewState>,
+    mut research: ResMut<ResearchQueue>,
+    ai_state: Res<AiState>,
+    game_data: Res<GameData>,
+    mut tech_events: MessageWriter<TechUnlocked>,
+) {
+    for (interaction, button) in &button_query {
+        if *interaction != Interaction::Pressed {
+            continue;
+        }
+        let Some(target) = ai_state.species.get(&button.target) else {
+            continue;
+        };
+        let result = attempt_espionage(
+            &EspionageAction::against(button.target.clone()),
+            &mut planet_state,
+            &mut research,
+            &target.research,
+            &mut diplomacy,
+            &game_data,
+            &mut rand::thread_rng(),
+        );
+        match result {
+            Ok(EspionageOutcome::Stolen(tech)) => {
+                info!("Spies stole {} from {}", tech.as_str(), target.name);
+                tech_events.write(TechUnlocked { tech });
+            }
+            Ok(EspionageOutcome::Caught(stance)) => info!(
+                "Spies were caught by {}; relations are now {}",
+                target.name,
+                stance.label()
+            ),
+            Err(err) => warn!("Spy mission refused: {err}"),
+        }
+    }
+}
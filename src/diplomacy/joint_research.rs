@@ -0,0 +1,284 @@
+//! Joint research between allied species.
+//!
+//! Allies can agree to research a technology together. Each turn every
+//! participant adds its full science yield to the project's pool; once the
+//! pool covers the technology's cost, every participant learns it at once.
+//! Cancelling a project refunds each participant what it put in, which is
+//! the pool split in proportion to the contributions.
+//!
+//! The player starts a project from the "Joint Research" button of an
+//! allied species in the diplomacy panel, on the technology they are
+//! currently researching.
+
+use std::collections::BTreeMap;
+
+use bevy::prelude::*;
+use serde::{Deserialize, Serialize};
+use thiserror::Error;
+
+use crate::ai::AiState;
+use crate::data_types::{GameData, SpeciesId, TechnologyId};
+use crate::diplomacy::{DiplomacyState, DiplomaticStance, player_species_id};
+use crate::planet_view::types::{PlanetViewState, TechUnlocked, TurnEnded};
+use crate::research::ResearchQueue;
+
+/// A technology researched together by allied species.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct JointResearchProject {
+    /// Species sharing the project.
+    pub participants: Vec<SpeciesId>,
+    /// Technology being researched.
+    pub tech: TechnologyId,
+    /// Science put into the project so far.
+    pub pooled_science: i32,
+    /// Science cost of the technology.
+    pub cost: i32,
+    /// Science each participant has put in, in `participants` order.
+    pub contributions: Vec<i32>,
+}
+
+impl JointResearchProject {
+    /// Whether `species` takes part in the project.
+    pub fn includes(&self, species: &SpeciesId) -> bool {
+        self.participants.contains(species)
+    }
+
+    /// Fraction of the cost already pooled, from 0.0 to 1.0.
+    pub fn progress(&self) -> f32 {
+        if self.cost <= 0 {
+            return 1.0;
+        }
+        (self.pooled_science as f32 / self.cost as f32).min(1.0)
+    }
+
+    /// Whether the pool covers the cost.
+    pub fn is_complete(&self) -> bool {
+        self.pooled_science >= self.cost
+    }
+
+    /// Add `science` from `species` to the pool.
+    fn contribute(&mut self, species: &SpeciesId, science: i32) {
+        let science = science.max(0);
+        let Some(index) = self.participants.iter().position(|p| p == species) else {
+            return;
+        };
+        self.contributions[index] += science;
+        self.pooled_science += science;
+    }
+
+    /// Science returned to each participant when the project is cancelled.
+    pub fn refunds(&self) -> impl Iterator<Item = (&SpeciesId, i32)> {
+        self.participants
+            .iter()
+            .zip(self.contributions.iter().copied())
+    }
+}
+
+/// Reasons a joint research project cannot be started.
+#[derive(Debug, Error, PartialEq, Eq)]
+pub enum JointResearchError {
+    /// Joint research needs an alliance.
+    #[error("Species {0} and {1} are not allied")]
+    NotAllied(String, String),
+    /// The technology does not exist.
+    #[error("Unknown technology {0}")]
+    UnknownTech(String),
+    /// A participant already knows the technology.
+    #[error("Species {0} has already researched {1}")]
+    AlreadyResearched(String, String),
+    /// The allies already share a project.
+    #[error("Species {0} and {1} already share a research project")]
+    AlreadyInProgress(String, String),
+}
+
+/// Start researching `tech` together with `a` and `b`.
+///
+/// # Errors
+/// Returns a [`JointResearchError`] (leaving `diplomacy` unchanged) if the
+/// species are not allied or already share a project, or if the technology
+/// is unknown or already researched by either of them.
+pub fn start_joint_research(
+    diplomacy: &mut DiplomacyState,
+    (a, a_research): (&SpeciesId, &ResearchQueue),
+    (b, b_research): (&SpeciesId, &ResearchQueue),
+    tech: TechnologyId,
+    game_data: &GameData,
+) -> Result<(), JointResearchError> {
+    if a == b || diplomacy.stance(a, b) != DiplomaticStance::Alliance {
+        return Err(JointResearchError::NotAllied(a.0.clone(), b.0.clone()));
+    }
+    if diplomacy
+        .joint_projects
+        .iter()
+        .any(|project| project.includes(a) && project.includes(b))
+    {
+        return Err(JointResearchError::AlreadyInProgress(
+            a.0.clone(),
+            b.0.clone(),
+        ));
+    }
+    let Some(cost) = game_data
+        .technologies()
+        .iter()
+        .find(|t| t.id == tech.as_str())
+        .map(|t| t.science_cost.max(0))
+    else {
+        return Err(JointResearchError::UnknownTech(tech.0));
+    };
+    for (species, research) in [(a, a_research), (b, b_research)] {
+        if research.is_researched(&tech) {
+            return Err(JointResearchError::AlreadyResearched(
+                species.0.clone(),
+                tech.0,
+            ));
+        }
+    }
+
+    diplomacy.joint_projects.push(JointResearchProject {
+        participants: vec![a.clone(), b.clone()],
+        tech,
+        pooled_science: 0,
+        cost,
+        contributions: vec![0, 0],
+    });
+    Ok(())
+}
+
+/// Cancel the project at `index` in [`DiplomacyState::joint_projects`].
+///
+/// Returns the removed project so its [`refunds`] can be paid out, or
+/// `None` if there is no such project.
+///
+/// [`refunds`]: JointResearchProject::refunds
+pub fn cancel_joint_research(
+    diplomacy: &mut DiplomacyState,
+    index: usize,
+) -> Option<JointResearchProject> {
+    (index < diplomacy.joint_projects.len()).then(|| diplomacy.joint_projects.remove(index))
+}
+
+/// Pool one turn of science into every project.
+///
+/// `science` holds each species' yield for the turn. Returns the projects
+/// that are now complete, removing them from `diplomacy`.
+pub fn advance_joint_research(
+    diplomacy: &mut DiplomacyState,
+    science: &BTreeMap<SpeciesId, i32>,
+) -> Vec<JointResearchProject> {
+    for project in &mut diplomacy.joint_projects {
+        for (species, &yield_) in science {
+            project.contribute(species, yield_);
+        }
+    }
+    let (complete, ongoing): (Vec<_>, Vec<_>) = std::mem::take(&mut diplomacy.joint_projects)
+        .into_iter()
+        .partition(JointResearchProject::is_complete);
+    diplomacy.joint_projects = ongoing;
+    complete
+}
+
+/// Joint Research button of an allied species row in the diplomacy panel.
+#[derive(Component)]
+pub struct JointResearchButton {
+    /// Ally to research with.
+    pub target: SpeciesId,
+}
+
+/// Cancel button of a joint project in the diplomacy panel.
+#[derive(Component)]
+pub struct CancelJointResearchButton {
+    /// Index of the project in [`DiplomacyState::joint_projects`].
+    pub index: usize,
+}
+
+/// Start or cancel joint projects from the diplomacy panel.
+///
+/// New projects target the player's active research.
+pub fn joint_research_button_system(
+    start_query: Query<(&Interaction, &JointResearchButton), Changed<Interaction>>,
+    cancel_query: Query<(&Interaction, &CancelJointResearchButton), Changed<Interaction>>,
+    mut diplomacy: ResMut<DiplomacyState>,
+    mut research: ResMut<ResearchQueue>,
+    mut ai_state: ResMut<AiState>,
+    game_data: Res<GameData>,
+) {
+    let player = player_species_id();
+
+    for (interaction, button) in &start_query {
+        if *interaction != Interaction::Pressed {
+            continue;
+        }
+        let Some(ally) = ai_state.species.get(&button.target) else {
+            continue;
+        };
+        let Some(tech) = research.active.clone() else {
+            warn!("Joint research needs an active technology to share");
+            continue;
+        };
+        match start_joint_research(
+            &mut diplomacy,
+            (&player, &research),
+            (&button.target, &ally.research),
+            tech.clone(),
+            &game_data,
+        ) {
+            Ok(()) => info!("Researching {} jointly with {}", tech.as_str(), ally.name),
+            Err(err) => warn!("Joint research refused: {err}"),
+        }
+    }
+
+    for (interaction, button) in &cancel_query {
+        if *interaction != Interaction::Pressed {
+            continue;
+        }
+        let Some(project) = cancel_joint_research(&mut diplomacy, button.index) else {
+            continue;
+        };
+        for (species, refund) in project.refunds() {
+            if *species == player {
+                research.progress += refund;
+            } else if let Some(ai) = ai_state.species.get_mut(species) {
+                ai.research.progress += refund;
+            }
+        }
+        info!("Cancelled joint research on {}", project.tech.as_str());
+    }
+}
+
+/// Pool each participant's science yield for the turn once it has been
+/// resolved and unlock finished projects for everyone.
+pub fn joint_research_turn_system(
+    mut turn_events: MessageReader<TurnEnded>,
+    mut diplomacy: ResMut<DiplomacyState>,
+    planet_state: Res<PlanetViewState>,
+    mut research: ResMut<ResearchQueue>,
+    mut ai_state: ResMut<AiState>,
+    mut tech_events: MessageWriter<TechUnlocked>,
+) {
+    for _ in turn_events.read() {
+        if diplomacy.joint_projects.is_empty() {
+            continue;
+        }
+        let to_i32 = |science: u32| i32::try_from(science).unwrap_or(i32::MAX);
+        let mut science =
+            BTreeMap::from([(player_species_id(), to_i32(planet_state.science_yield))]);
+        for (id, ai) in &ai_state.species {
+            science.insert(id.clone(), to_i32(ai.planet.science_yield));
+        }
+
+        for project in advance_joint_research(&mut diplomacy, &science) {
+            for species in &project.participants {
+                if *species == player_species_id() {
+                    if !research.is_researched(&project.tech) {
+                        research.grant(project.tech.clone());
+                        tech_events.write(TechUnlocked {
+                            tech: project.tech.clone(),
+                        });
+                    }
+                } else if let Some(ai) = ai_state.species.get_mut(species) {
+                    ai.research.grant(project.tech.clone());
+                }
+            }
+        }
+    }
+}
@@ -0,0 +1,285 @@
+//! Diplomatic relations between species.
+//!
+//! [`DiplomacyState`] stores one [`DiplomaticStance`] per pair of species.
+//! Pairs are unordered: the stance of A toward B is always the stance of B
+//! toward A, so every lookup normalizes the pair first.
+//!
+//! # Stance Transitions
+//!
+//! - Unknown → Neutral on first contact
+//! - Neutral → Treaty → Alliance through accepted treaty proposals
+//! - Any known stance → War on a declaration of war
+//! - War → Neutral through a treaty proposal (peace)
+//!
+//! Species meet through [`DiplomacyState::make_contact`]. Until there is a
+//! galaxy map with scanners, the player meets every AI species when the game
+//! starts.
+//!
+//! AI species currently accept every proposal made to them.
+//!
+//! The player can also send spies to steal technology from a species (see
+//! [`espionage`]); failed attempts sour relations. Allies can pool their
+//! science into shared projects (see [`joint_research`]).
+
+pub mod espionage;
+pub mod joint_research;
+pub mod panel;
+
+use std::collections::HashMap;
+
+use bevy::prelude::*;
+use serde::{Deserialize, Serialize};
+use thiserror::Error;
+
+use crate::ai::{AiState, setup_ai_species};
+use crate::data_types::SpeciesId;
+use crate::diplomacy::joint_research::JointResearchProject;
+use crate::main_menu::GameState;
+
+#[cfg(test)]
+mod tests;
+
+/// Species id used for the player.
+pub const PLAYER_SPECIES_ID: &str = "player";
+
+/// Display name of the player's species.
+pub const PLAYER_SPECIES_NAME: &str = "Player";
+
+/// Plugin that tracks diplomatic relations and shows the diplomacy panel.
+pub struct DiplomacyPlugin;
+
+impl Plugin for DiplomacyPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<DiplomacyState>()
+            .init_resource::<panel::DiplomacyPanelState>()
+            .add_systems(
+                OnEnter(GameState::PlanetView),
+                setup_diplomacy.after(setup_ai_species),
+            )
+            .add_systems(OnExit(GameState::PlanetView), panel::close_diplomacy_panel)
+            .add_systems(
+                Update,
+                (
+                    panel::update_diplomacy_panel,
+                    panel::diplomacy_panel_interaction,
+                    espionage::espionage_button_system,
+                    joint_research::joint_research_button_system,
+                    joint_research::joint_research_turn_system,
+                )
+                    .run_if(in_state(GameState::PlanetView)),
+            );
+    }
+}
+
+/// Relationship between two species.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub enum DiplomaticStance {
+    /// The species have not met yet.
+    #[default]
+    Unknown,
+    /// The species know each other but have no agreements.
+    Neutral,
+    /// The species have signed a non-aggression treaty.
+    Treaty,
+    /// The species are allied.
+    Alliance,
+    /// The species are at war.
+    War,
+}
+
+impl DiplomaticStance {
+    /// Display name of the stance.
+    pub fn label(self) -> &'static str {
+        match self {
+            Self::Unknown => "Unknown",
+            Self::Neutral => "Neutral",
+            Self::Treaty => "Treaty",
+            Self::Alliance => "Alliance",
+            Self::War => "War",
+        }
+    }
+}
+
+/// Errors returned when a diplomatic action is not allowed.
+#[derive(Debug, Error, PartialEq, Eq)]
+pub enum DiplomacyError {
+    /// A species cannot have relations with itself.
+    #[error("Species {0} cannot negotiate with itself")]
+    SameSpecies(String),
+    /// The species have not made contact yet.
+    #[error("Species {0} and {1} have not met")]
+    NotContacted(String, String),
+    /// The species are already allied.
+    #[error("Species {0} and {1} are already allied")]
+    AlreadyAllied(String, String),
+    /// The species are already at war.
+    #[error("Species {0} and {1} are already at war")]
+    AlreadyAtWar(String, String),
+}
+
+/// Diplomatic stances between every pair of species in the game.
+///
+/// Saved alongside the other session resources by [`crate::save_load`].
+#[derive(Resource, Debug, Clone, Default, Serialize, Deserialize)]
+pub struct DiplomacyState {
+    /// Stance of each unordered species pair, keyed with the smaller id first.
+    pub stances: HashMap<(SpeciesId, SpeciesId), DiplomaticStance>,
+    /// Failed espionage attempts by the player, keyed by target species.
+    #[serde(default)]
+    pub failed_spy_attempts: HashMap<SpeciesId, u32>,
+    /// Joint research projects between allies.
+    #[serde(default)]
+    pub joint_projects: Vec<JointResearchProject>,
+}
+
+impl DiplomacyState {
+    /// Create relations for `species`, with every pair starting as
+    /// [`DiplomaticStance::Unknown`].
+    pub fn new(species: &[SpeciesId]) -> Self {
+        let mut stances = HashMap::new();
+        for (i, a) in species.iter().enumerate() {
+            for b in &species[i + 1..] {
+                stances.insert(pair_key(a, b), DiplomaticStance::Unknown);
+            }
+        }
+        Self {
+            stances,
+            ..Default::default()
+        }
+    }
+
+    /// Current stance between `a` and `b` (`Unknown` for unknown pairs).
+    pub fn stance(&self, a: &SpeciesId, b: &SpeciesId) -> DiplomaticStance {
+        self.stances
+            .get(&pair_key(a, b))
+            .copied()
+            .unwrap_or_default()
+    }
+
+    /// Record first contact between `a` and `b`.
+    ///
+    /// Species that have already met keep their current stance.
+    pub fn make_contact(&mut self, a: &SpeciesId, b: &SpeciesId) {
+        if a == b {
+            return;
+        }
+        let stance = self.stances.entry(pair_key(a, b)).or_default();
+        if *stance == DiplomaticStance::Unknown {
+            *stance = DiplomaticStance::Neutral;
+        }
+    }
+
+    /// Improve relations between `a` and `b` by one step.
+    ///
+    /// Neutral species sign a treaty, treaty partners become allies, and
+    /// species at war make peace (back to neutral).
+    ///
+    /// # Errors
+    /// Returns a [`DiplomacyError`] (leaving the stance unchanged) if the
+    /// species are the same, have not met, or are already allied.
+    pub fn propose_treaty(
+        &mut self,
+        a: &SpeciesId,
+        b: &SpeciesId,
+    ) -> Result<DiplomaticStance, DiplomacyError> {
+        let next = match self.check_pair(a, b)? {
+            DiplomaticStance::Unknown => unreachable!("checked by check_pair"),
+            DiplomaticStance::Neutral => DiplomaticStance::Treaty,
+            DiplomaticStance::Treaty => DiplomaticStance::Alliance,
+            DiplomaticStance::War => DiplomaticStance::Neutral,
+            DiplomaticStance::Alliance => {
+                return Err(DiplomacyError::AlreadyAllied(a.0.clone(), b.0.clone()));
+            }
+        };
+        self.stances.insert(pair_key(a, b), next);
+        Ok(next)
+    }
+
+    /// Declare war between `a` and `b`, breaking any treaty or alliance.
+    ///
+    /// # Errors
+    /// Returns a [`DiplomacyError`] (leaving the stance unchanged) if the
+    /// species are the same, have not met, or are already at war.
+    pub fn declare_war(&mut self, a: &SpeciesId, b: &SpeciesId) -> Result<(), DiplomacyError> {
+        if self.check_pair(a, b)? == DiplomaticStance::War {
+            return Err(DiplomacyError::AlreadyAtWar(a.0.clone(), b.0.clone()));
+        }
+        self.stances.insert(pair_key(a, b), DiplomaticStance::War);
+        Ok(())
+    }
+
+    /// Worsen relations between `a` and `b` by one step.
+    ///
+    /// Allies fall back to a treaty and treaty partners to neutral;
+    /// neutral species and species at war keep their stance.
+    ///
+    /// # Errors
+    /// Returns a [`DiplomacyError`] (leaving the stance unchanged) if the
+    /// species are the same or have not met.
+    pub fn worsen_relations(
+        &mut self,
+        a: &SpeciesId,
+        b: &SpeciesId,
+    ) -> Result<DiplomaticStance, DiplomacyError> {
+        let next = match self.check_pair(a, b)? {
+            DiplomaticStance::Alliance => DiplomaticStance::Treaty,
+            DiplomaticStance::Treaty => DiplomaticStance::Neutral,
+            stance => stance,
+        };
+        self.stances.insert(pair_key(a, b), next);
+        Ok(next)
+    }
+
+    /// Whether `species` is at war with anyone.
+    pub fn is_at_war(&self, species: &SpeciesId) -> bool {
+        self.stances.iter().any(|((a, b), stance)| {
+            *stance == DiplomaticStance::War && (a == species || b == species)
+        })
+    }
+
+    /// Stance of a pair that is allowed to negotiate.
+    fn check_pair(&self, a: &SpeciesId, b: &SpeciesId) -> Result<DiplomaticStance, DiplomacyError> {
+        if a == b {
+            return Err(DiplomacyError::SameSpecies(a.0.clone()));
+        }
+        match self.stance(a, b) {
+            DiplomaticStance::Unknown => {
+                Err(DiplomacyError::NotContacted(a.0.clone(), b.0.clone()))
+            }
+            stance => Ok(stance),
+        }
+    }
+}
+
+/// Species id of the player.
+pub fn player_species_id() -> SpeciesId {
+    SpeciesId::from(PLAYER_SPECIES_ID)
+}
+
+/// Normalize an unordered pair so `(a, b)` and `(b, a)` share one entry.
+fn pair_key(a: &SpeciesId, b: &SpeciesId) -> (SpeciesId, SpeciesId) {
+    if a <= b {
+        (a.clone(), b.clone())
+    } else {
+        (b.clone(), a.clone())
+    }
+}
+
+/// Create relations for a new game once the AI species exist.
+///
+/// Sessions already in progress keep their existing [`DiplomacyState`].
+fn setup_diplomacy(mut diplomacy: ResMut<DiplomacyState>, ai_state: Res<AiState>) {
+    if !diplomacy.stances.is_empty() || ai_state.species.is_empty() {
+        return;
+    }
+
+    let player = player_species_id();
+    let mut species = vec![player.clone()];
+    species.extend(ai_state.species.keys().cloned());
+    *diplomacy = DiplomacyState::new(&species);
+
+    // No scanners yet: the player knows every rival from the start.
+    for ai in ai_state.species.keys() {
+        diplomacy.make_contact(&player, ai);
+    }
+}
@@ -0,0 +1,349 @@
+//! Diplomacy panel listing known species and their stances.
+//!
+//! Opened from the "Species" button in the planet view bottom bar. Each
+//! known species gets a row with its current stance and buttons to propose
+//! a treaty, declare war, send spies, or (with allies) research jointly.
+//! Joint projects are listed below with a shared progress bar. The panel is rebuilt whenever relations change.
+
+use bevy::prelude::*;
+
+use crate::ai::AiState;
+use crate::data_types::{GameData, SpeciesId};
+use crate::diplomacy::espionage::{EspionageButton, SPY_INDUSTRY_COST};
+use crate::diplomacy::joint_research::{
+    CancelJointResearchButton, JointResearchButton, JointResearchProject,
+};
+use crate::diplomacy::{DiplomacyState, DiplomaticStance, player_species_id};
+use crate::planet_view::types::PlanetViewRoot;
+
+/// Whether the diplomacy panel is shown.
+#[derive(Resource, Debug, Default)]
+pub struct DiplomacyPanelState {
+    /// `true` while the panel is open.
+    pub open: bool,
+}
+
+/// Marker component for the diplomacy panel root entity.
+#[derive(Component)]
+pub struct DiplomacyPanelRoot;
+
+/// Diplomatic action performed by a panel button.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum DiplomacyAction {
+    /// Improve relations by one step.
+    ProposeTreaty,
+    /// Declare war.
+    DeclareWar,
+}
+
+/// Component attached to the action buttons of a species row.
+#[derive(Component)]
+pub struct DiplomacyButton {
+    /// Species the action targets.
+    pub target: SpeciesId,
+    /// Action to perform.
+    pub action: DiplomacyAction,
+}
+
+/// Marker component for the panel's close button.
+#[derive(Component)]
+pub struct DiplomacyPanelClose;
+
+/// Show, hide, or rebuild the panel to match [`DiplomacyPanelState`] and
+/// the current [`DiplomacyState`].
+pub fn update_diplomacy_panel(
+    mut commands: Commands,
+    panel_state: Res<DiplomacyPanelState>,
+    diplomacy: Res<DiplomacyState>,
+    ai_state: Res<AiState>,
+    game_data: Res<GameData>,
+    panel_query: Query<Entity, With<DiplomacyPanelRoot>>,
+) {
+    let has_panel = !panel_query.is_empty();
+    let stale = panel_state.is_changed() || diplomacy.is_changed();
+    if has_panel == panel_state.open && !(panel_state.open && stale) {
+        return;
+    }
+
+    for entity in &panel_query {
+        commands.entity(entity).despawn();
+    }
+    if panel_state.open {
+        spawn_diplomacy_panel(&mut commands, &diplomacy, &ai_state, &game_data);
+    }
+}
+
+/// Handle the action and close buttons of the panel.
+pub fn diplomacy_panel_interaction(
+    button_query: Query<(&Interaction, &DiplomacyButton), Changed<Interaction>>,
+    close_query: Query<&Interaction, (Changed<Interaction>, With<DiplomacyPanelClose>)>,
+    mut diplomacy: ResMut<DiplomacyState>,
+    mut panel_state: ResMut<DiplomacyPanelState>,
+) {
+    let player = player_species_id();
+
+    for (interaction, button) in &button_query {
+        if *interaction != Interaction::Pressed {
+            continue;
+        }
+        let result = match button.action {
+            DiplomacyAction::ProposeTreaty => diplomacy
+                .propose_treaty(&player, &button.target)
+                .map(|_| ()),
+            DiplomacyAction::DeclareWar => diplomacy.declare_war(&player, &button.target),
+        };
+        match result {
+            Ok(()) => info!(
+                "Relations with {} are now {}",
+                button.target.as_str(),
+                diplomacy.stance(&player, &button.target).label()
+            ),
+            Err(err) => warn!("Diplomatic action refused: {err}"),
+        }
+    }
+
+    for interaction in &close_query {
+        if *interaction == Interaction::Pressed {
+            panel_state.open = false;
+        }
+    }
+}
+
+/// Close the panel when leaving the planet view.
+pub fn close_diplomacy_panel(mut panel_state: ResMut<DiplomacyPanelState>) {
+    panel_state.open = false;
+}
+
+/// Spawn the panel with one row per AI species, followed by the player's
+/// joint research projects.
+fn spawn_diplomacy_panel(
+    commands: &mut Commands,
+    diplomacy: &DiplomacyState,
+    ai_state: &AiState,
+    game_data: &GameData,
+) {
+    let player = player_species_id();
+
+    commands
+        .spawn((
+            Node {
+                position_type: PositionType::Absolute,
+                left: Val::Percent(25.0),
+                top: Val::Percent(20.0),
+                width: Val::Percent(50.0),
+                flex_direction: FlexDirection::Column,
+                padding: UiRect::all(Val::Px(10.0)),
+                border: UiRect::all(Val::Px(2.0)),
+                row_gap: Val::Px(8.0),
+                ..default()
+            },
+            BackgroundColor(Color::BLACK.with_alpha(0.9)),
+            BorderColor::all(Color::WHITE),
+            GlobalZIndex(10),
+            DiplomacyPanelRoot,
+            PlanetViewRoot,
+        ))
+        .with_children(|parent| {
+            parent.spawn((
+                Text::new("Species"),
+                TextFont {
+                    font_size: 24.0,
+                    ..default()
+                },
+                TextColor(Color::WHITE),
+            ));
+
+            for (id, ai) in &ai_state.species {
+                let stance = diplomacy.stance(&player, id);
+                if stance == DiplomaticStance::Unknown {
+                    continue;
+                }
+                spawn_species_row(parent, id, &ai.name, stance);
+            }
+
+            for (index, project) in diplomacy.joint_projects.iter().enumerate() {
+                if !project.includes(&player) {
+                    continue;
+                }
+                let partners: Vec<&str> = project
+                    .participants
+                    .iter()
+                    .filter_map(|id| ai_state.species.get(id))
+                    .map(|ai| ai.name.as_str())
+                    .collect();
+                let tech = game_data
+                    .technologies()
+                    .iter()
+                    .find(|tech| tech.id == project.tech.as_str())
+                    .map_or(project.tech.as_str(), |tech| tech.name_en.as_str());
+                let label = format!("Joint: {tech} with {}", partners.join(", "));
+                spawn_joint_project_row(parent, index, project, &label);
+            }
+
+            parent
+                .spawn((
+                    Button,
+                    Node {
+                        height: Val::Px(36.0),
+                        margin: UiRect::top(Val::Px(12.0)),
+                        justify_content: JustifyContent::Center,
+                        align_items: AlignItems::Center,
+                        ..default()
+                    },
+                    BackgroundColor(Color::srgb(0.5, 0.0, 0.0)),
+                    DiplomacyPanelClose,
+                ))
+                .with_children(|btn| {
+                    btn.spawn((Text::new("Close"), TextColor(Color::WHITE)));
+                });
+        });
+}
+
+/// Name, stance, and action buttons for one species.
+fn spawn_species_row(
+    parent: &mut ChildSpawnerCommands,
+    id: &SpeciesId,
+    name: &str,
+    stance: DiplomaticStance,
+) {
+    parent
+        .spawn(Node {
+            align_items: AlignItems::Center,
+            column_gap: Val::Px(10.0),
+            ..default()
+        })
+        .with_children(|row| {
+            row.spawn((
+                Text::new(format!("{name}: {}", stance.label())),
+                TextColor(stance_color(stance)),
+                Node {
+                    flex_grow: 1.0,
+                    ..default()
+                },
+            ));
+
+            for (action, label) in [
+                (DiplomacyAction::ProposeTreaty, "Propose Treaty"),
+                (DiplomacyAction::DeclareWar, "Declare War"),
+            ] {
+                row.spawn((
+                    Button,
+                    Node {
+                        padding: UiRect::all(Val::Px(6.0)),
+                        ..default()
+                    },
+                    BackgroundColor(Color::srgb(0.2, 0.2, 0.2)),
+                    DiplomacyButton {
+                        target: id.clone(),
+                        action,
+                    },
+                ))
+                .with_children(|btn| {
+                    btn.spawn((Text::new(label), TextColor(Color::WHITE)));
+                });
+            }
+
+            if stance == DiplomaticStance::Alliance {
+                row.spawn((
+                    Button,
+                    Node {
+                        padding: UiRect::all(Val::Px(6.0)),
+                        ..default()
+                    },
+                    BackgroundColor(Color::srgb(0.2, 0.2, 0.2)),
+                    JointResearchButton { target: id.clone() },
+                ))
+                .with_children(|btn| {
+                    btn.spawn((Text::new("Joint Research"), TextColor(Color::WHITE)));
+                });
+            }
+
+            row.spawn((
+                Button,
+                Node {
+                    padding: UiRect::all(Val::Px(6.0)),
+                    ..default()
+                },
+                BackgroundColor(Color::srgb(0.2, 0.2, 0.2)),
+                EspionageButton { target: id.clone() },
+            ))
+            .with_children(|btn| {
+                btn.spawn((
+                    Text::new(format!("Spy ({SPY_INDUSTRY_COST} prod)")),
+                    TextColor(Color::WHITE),
+                ));
+            });
+        });
+}
+
+/// Label, shared progress bar, and cancel button for one joint project.
+fn spawn_joint_project_row(
+    parent: &mut ChildSpawnerCommands,
+    index: usize,
+    project: &JointResearchProject,
+    label: &str,
+) {
+    parent
+        .spawn(Node {
+            align_items: AlignItems::Center,
+            column_gap: Val::Px(10.0),
+            ..default()
+        })
+        .with_children(|row| {
+            row.spawn((
+                Text::new(format!(
+                    "{label}: {}/{}",
+                    project.pooled_science, project.cost
+                )),
+                TextColor(stance_color(DiplomaticStance::Alliance)),
+                Node {
+                    flex_grow: 1.0,
+                    ..default()
+                },
+            ));
+
+            row.spawn((
+                Node {
+                    width: Val::Px(120.0),
+                    height: Val::Px(10.0),
+                    border: UiRect::all(Val::Px(1.0)),
+                    ..default()
+                },
+                BackgroundColor(Color::srgb(0.15, 0.15, 0.15)),
+                BorderColor::all(Color::srgb(0.5, 0.5, 0.5)),
+            ))
+            .with_children(|bar| {
+                bar.spawn((
+                    Node {
+                        width: Val::Percent(project.progress() * 100.0),
+                        height: Val::Percent(100.0),
+                        ..default()
+                    },
+                    BackgroundColor(stance_color(DiplomaticStance::Alliance)),
+                ));
+            });
+
+            row.spawn((
+                Button,
+                Node {
+                    padding: UiRect::all(Val::Px(6.0)),
+                    ..default()
+                },
+                BackgroundColor(Color::srgb(0.2, 0.2, 0.2)),
+                CancelJointResearchButton { index },
+            ))
+            .with_children(|btn| {
+                btn.spawn((Text::new("Cancel"), TextColor(Color::WHITE)));
+            });
+        });
+}
+
+/// Text color used for a stance.
+fn stance_color(stance: DiplomaticStance) -> Color {
+    match stance {
+        DiplomaticStance::Unknown | DiplomaticStance::Neutral => Color::srgb(0.8, 0.8, 0.8),
+        DiplomaticStance::Treaty => Color::srgb(0.5, 0.8, 1.0),
+        DiplomaticStance::Alliance => Color::srgb(0.3, 0.9, 0.3),
+        DiplomaticStance::War => Color::srgb(0.9, 0.2, 0.2),
+    }
+}
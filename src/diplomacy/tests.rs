@@ -0,0 +1,431 @@
+use std::collections::BTreeMap;
+use std::path::PathBuf;
+
+use rand::SeedableRng;
+use rand::rngs::StdRng;
+
+use super::espionage::{
+    EspionageAction, EspionageError, EspionageOutcome, MAX_FAILED_SPY_ATTEMPTS, attempt_espionage,
+    stealable_tech,
+};
+use super::joint_research::{
+    JointResearchError, advance_joint_research, cancel_joint_research, start_joint_research,
+};
+use super::{DiplomacyError, DiplomacyState, DiplomaticStance};
+use crate::data_types::{GameData, SpeciesId, Technology, TechnologyId, load_game_data};
+use crate::planet_view::types::PlanetViewState;
+use crate::research::ResearchQueue;
+
+fn species() -> (SpeciesId, SpeciesId, SpeciesId) {
+    (
+        SpeciesId::from("player"),
+        SpeciesId::from("ai_1"),
+        SpeciesId::from("ai_2"),
+    )
+}
+
+#[test]
+fn all_pairs_start_unknown() {
+    let (player, ai_1, ai_2) = species();
+    let state = DiplomacyState::new(&[player.clone(), ai_1.clone(), ai_2.clone()]);
+
+    assert_eq!(state.stances.len(), 3);
+    assert_eq!(state.stance(&player, &ai_1), DiplomaticStance::Unknown);
+    assert_eq!(state.stance(&ai_2, &ai_1), DiplomaticStance::Unknown);
+}
+
+#[test]
+fn stances_are_symmetric() {
+    let (player, ai_1, _) = species();
+    let mut state = DiplomacyState::new(&[player.clone(), ai_1.clone()]);
+
+    state.make_contact(&ai_1, &player);
+    assert_eq!(state.stance(&player, &ai_1), DiplomaticStance::Neutral);
+
+    state.declare_war(&player, &ai_1).unwrap();
+    assert_eq!(state.stance(&ai_1, &player), DiplomaticStance::War);
+    assert_eq!(state.stances.len(), 1);
+    assert!(state.is_at_war(&ai_1));
+}
+
+#[test]
+fn treaties_step_up_to_alliance_and_war_resets_to_neutral() {
+    let (player, ai_1, _) = species();
+    let mut state = DiplomacyState::new(&[player.clone(), ai_1.clone()]);
+
+    assert_eq!(
+        state.propose_treaty(&player, &ai_1),
+        Err(DiplomacyError::NotContacted("player".into(), "ai_1".into()))
+    );
+
+    state.make_contact(&player, &ai_1);
+    assert_eq!(
+        state.propose_treaty(&player, &ai_1),
+        Ok(DiplomaticStance::Treaty)
+    );
+    assert_eq!(
+        state.propose_treaty(&ai_1, &player),
+        Ok(DiplomaticStance::Alliance)
+    );
+    assert!(matches!(
+        state.propose_treaty(&player, &ai_1),
+        Err(DiplomacyError::AlreadyAllied(..))
+    ));
+
+    state.declare_war(&player, &ai_1).unwrap();
+    assert!(matches!(
+        state.declare_war(&ai_1, &player),
+        Err(DiplomacyError::AlreadyAtWar(..))
+    ));
+    assert_eq!(
+        state.propose_treaty(&player, &ai_1),
+        Ok(DiplomaticStance::Neutral)
+    );
+    assert!(!state.is_at_war(&player));
+
+    // Contact never downgrades an established stance.
+    state.declare_war(&player, &ai_1).unwrap();
+    state.make_contact(&player, &ai_1);
+    assert_eq!(state.stance(&player, &ai_1), DiplomaticStance::War);
+}
+
+#[test]
+fn species_cannot_negotiate_with_themselves() {
+    let (player, _, _) = species();
+    let mut state = DiplomacyState::new(&[player.clone()]);
+
+    assert_eq!(
+        state.declare_war(&player, &player),
+        Err(DiplomacyError::SameSpecies("player".into()))
+    );
+}
+
+#[test]
+fn worsening_relations_steps_down_to_neutral() {
+    let (player, ai_1, _) = species();
+    let mut state = DiplomacyState::new(&[player.clone(), ai_1.clone()]);
+    assert!(matches!(
+        state.worsen_relations(&player, &ai_1),
+        Err(DiplomacyError::NotContacted(..))
+    ));
+
+    state.make_contact(&player, &ai_1);
+    state.propose_treaty(&player, &ai_1).unwrap();
+    state.propose_treaty(&player, &ai_1).unwrap();
+
+    assert_eq!(
+        state.worsen_relations(&ai_1, &player),
+        Ok(DiplomaticStance::Treaty)
+    );
+    assert_eq!(
+        state.worsen_relations(&player, &ai_1),
+        Ok(DiplomaticStance::Neutral)
+    );
+    assert_eq!(
+        state.worsen_relations(&player, &ai_1),
+        Ok(DiplomaticStance::Neutral)
+    );
+}
+
+fn tech(id: &str, science_cost: i32, prerequisites: &[&str]) -> Technology {
+    Technology {
+        id: id.to_string(),
+        name_en: id.to_string(),
+        science_cost,
+        description_en: String::new(),
+        prerequisites: prerequisites.iter().map(|p| p.to_string()).collect(),
+        accident_chance: 0.0,
+    }
+}
+
+/// Game data with a small tech tree: `a` leads to `b` and the pricier `c`.
+fn spy_data() -> GameData {
+    let (mut data, _) = load_game_data(PathBuf::from("assets/data")).expect("data loads");
+    data.technologies = vec![
+        tech("a", 100, &[]),
+        tech("b", 200, &["a"]),
+        tech("c", 300, &["a"]),
+        tech("d", 400, &["c"]),
+    ];
+    data
+}
+
+fn researched(ids: &[&str]) -> ResearchQueue {
+    ResearchQueue {
+        researched: ids.iter().map(|id| TechnologyId::from(*id)).collect(),
+        ..Default::default()
+    }
+}
+
+#[test]
+fn spies_steal_the_most_expensive_reachable_tech() {
+    let data = spy_data();
+    let target = researched(&["a", "b", "c", "d"]);
+
+    assert_eq!(
+        stealable_tech(&researched(&[]), &target, &data),
+        Some(TechnologyId::from("a"))
+    );
+    // `d` needs `c`, so the player can only take `c` for now.
+    assert_eq!(
+        stealable_tech(&researched(&["a"]), &target, &data),
+        Some(TechnologyId::from("c"))
+    );
+    assert_eq!(
+        stealable_tech(&researched(&["a", "b", "c", "d"]), &target, &data),
+        None
+    );
+}
+
+#[test]
+fn successful_espionage_unlocks_the_tech_and_costs_production() {
+    let data = spy_data();
+    let (player, ai_1, _) = species();
+    let mut diplomacy = DiplomacyState::new(&[player.clone(), ai_1.clone()]);
+    diplomacy.make_contact(&player, &ai_1);
+    let mut planet = PlanetViewState {
+        production: 80,
+        ..Default::default()
+    };
+    let mut research = researched(&["a"]);
+    research.active = Some(TechnologyId::from("c"));
+    research.queued.push_back(TechnologyId::from("b"));
+    let action = EspionageAction {
+        success_chance: 1.0,
+        ..EspionageAction::against(ai_1.clone())
+    };
+
+    let outcome = attempt_espionage(
+        &action,
+        &mut planet,
+        &mut research,
+        &researched(&["a", "c"]),
+        &mut diplomacy,
+        &data,
+        &mut StdRng::seed_from_u64(1),
+    );
+
+    assert_eq!(
+        outcome,
+        Ok(EspionageOutcome::Stolen(TechnologyId::from("c")))
+    );
+    assert_eq!(planet.production, 30);
+    assert!(research.is_researched(&TechnologyId::from("c")));
+    assert_eq!(research.active, Some(TechnologyId::from("b")));
+    assert!(research.queued.is_empty());
+    assert_eq!(
+        attempt_espionage(
+            &action,
+            &mut planet,
+            &mut research,
+            &researched(&["a", "c"]),
+            &mut diplomacy,
+            &data,
+            &mut StdRng::seed_from_u64(1),
+        ),
+        Err(EspionageError::NothingToSteal("ai_1".into()))
+    );
+}
+
+#[test]
+fn repeated_failed_espionage_leads_to_war() {
+    let data = spy_data();
+    let (player, ai_1, _) = species();
+    let mut diplomacy = DiplomacyState::new(&[player.clone(), ai_1.clone()]);
+    diplomacy.make_contact(&player, &ai_1);
+    diplomacy.propose_treaty(&player, &ai_1).unwrap();
+    let mut planet = PlanetViewState {
+        production: 1000,
+        ..Default::default()
+    };
+    let mut research = ResearchQueue::default();
+    let action = EspionageAction {
+        success_chance: 0.0,
+        ..EspionageAction::against(ai_1.clone())
+    };
+    let mut rng = StdRng::seed_from_u64(1);
+
+    let mut stances = Vec::new();
+    for _ in 0..MAX_FAILED_SPY_ATTEMPTS {
+        let outcome = attempt_espionage(
+            &action,
+            &mut planet,
+            &mut research,
+            &researched(&["a"]),
+            &mut diplomacy,
+            &data,
+            &mut rng,
+        );
+        stances.push(outcome.unwrap());
+    }
+
+    assert_eq!(
+        stances,
+        [
+            EspionageOutcome::Caught(DiplomaticStance::Neutral),
+            EspionageOutcome::Caught(DiplomaticStance::Neutral),
+            EspionageOutcome::Caught(DiplomaticStance::War),
+        ]
+    );
+    assert_eq!(diplomacy.stance(&player, &ai_1), DiplomaticStance::War);
+    assert!(research.researched.is_empty());
+}
+
+#[test]
+fn espionage_needs_contact_and_production() {
+    let data = spy_data();
+    let (player, ai_1, _) = species();
+    let mut diplomacy = DiplomacyState::new(&[player.clone(), ai_1.clone()]);
+    let mut planet = PlanetViewState {
+        production: 10,
+        ..Default::default()
+    };
+    let mut research = ResearchQueue::default();
+    let action = EspionageAction::against(ai_1.clone());
+    let mut attempt = |diplomacy: &mut DiplomacyState| {
+        attempt_espionage(
+            &action,
+            &mut planet,
+            &mut research,
+            &researched(&["a"]),
+            diplomacy,
+            &data,
+            &mut StdRng::seed_from_u64(1),
+        )
+    };
+
+    assert_eq!(
+        attempt(&mut diplomacy),
+        Err(EspionageError::NotContacted("ai_1".into()))
+    );
+    diplomacy.make_contact(&player, &ai_1);
+    assert_eq!(
+        attempt(&mut diplomacy),
+        Err(EspionageError::NotEnoughProduction {
+            needed: 50,
+            available: 10,
+        })
+    );
+}
+
+/// Diplomacy with the player allied to `ai_1` and neutral towards `ai_2`.
+fn alliance() -> DiplomacyState {
+    let (player, ai_1, ai_2) = species();
+    let mut state = DiplomacyState::new(&[player.clone(), ai_1.clone(), ai_2.clone()]);
+    state.make_contact(&player, &ai_1);
+    state.make_contact(&player, &ai_2);
+    state.propose_treaty(&player, &ai_1).unwrap();
+    state.propose_treaty(&player, &ai_1).unwrap();
+    state
+}
+
+#[test]
+fn joint_research_needs_allies_without_the_tech() {
+    let data = spy_data();
+    let (player, ai_1, ai_2) = species();
+    let mut state = alliance();
+    let none = ResearchQueue::default();
+    let tech = TechnologyId::from("a");
+
+    assert_eq!(
+        start_joint_research(
+            &mut state,
+            (&player, &none),
+            (&ai_2, &none),
+            tech.clone(),
+            &data
+        ),
+        Err(JointResearchError::NotAllied(
+            "player".into(),
+            "ai_2".into()
+        ))
+    );
+    assert_eq!(
+        start_joint_research(
+            &mut state,
+            (&player, &none),
+            (&ai_1, &researched(&["a"])),
+            tech.clone(),
+            &data
+        ),
+        Err(JointResearchError::AlreadyResearched(
+            "ai_1".into(),
+            "a".into()
+        ))
+    );
+    assert_eq!(
+        start_joint_research(
+            &mut state,
+            (&player, &none),
+            (&ai_1, &none),
+            TechnologyId::from("missing"),
+            &data
+        ),
+        Err(JointResearchError::UnknownTech("missing".into()))
+    );
+
+    start_joint_research(
+        &mut state,
+        (&player, &none),
+        (&ai_1, &none),
+        tech.clone(),
+        &data,
+    )
+    .unwrap();
+    assert_eq!(state.joint_projects[0].cost, 100);
+    assert!(matches!(
+        start_joint_research(&mut state, (&ai_1, &none), (&player, &none), tech, &data),
+        Err(JointResearchError::AlreadyInProgress(..))
+    ));
+}
+
+#[test]
+fn joint_research_pools_full_yields_until_complete() {
+    let data = spy_data();
+    let (player, ai_1, ai_2) = species();
+    let mut state = alliance();
+    let none = ResearchQueue::default();
+    start_joint_research(
+        &mut state,
+        (&player, &none),
+        (&ai_1, &none),
+        TechnologyId::from("b"),
+        &data,
+    )
+    .unwrap();
+    let science = BTreeMap::from([(player.clone(), 60), (ai_1.clone(), 40), (ai_2, 500)]);
+
+    assert!(advance_joint_research(&mut state, &science).is_empty());
+    assert_eq!(state.joint_projects[0].pooled_science, 100);
+    assert_eq!(state.joint_projects[0].progress(), 0.5);
+
+    let complete = advance_joint_research(&mut state, &science);
+    assert_eq!(complete.len(), 1);
+    assert_eq!(complete[0].tech, TechnologyId::from("b"));
+    assert!(state.joint_projects.is_empty());
+}
+
+#[test]
+fn cancelling_joint_research_refunds_each_contribution() {
+    let data = spy_data();
+    let (player, ai_1, _) = species();
+    let mut state = alliance();
+    let none = ResearchQueue::default();
+    start_joint_research(
+        &mut state,
+        (&player, &none),
+        (&ai_1, &none),
+        TechnologyId::from("d"),
+        &data,
+    )
+    .unwrap();
+    let science = BTreeMap::from([(player.clone(), 30), (ai_1.clone(), 10)]);
+    advance_joint_research(&mut state, &science);
+    advance_joint_research(&mut state, &science);
+
+    assert!(cancel_joint_research(&mut state, 1).is_none());
+    let project = cancel_joint_research(&mut state, 0).unwrap();
+    let refunds: Vec<_> = project.refunds().collect();
+    assert_eq!(refunds, [(&player, 60), (&ai_1, 20)]);
+    assert!(state.joint_projects.is_empty());
+}
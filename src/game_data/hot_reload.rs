@@ -1,3 +1,8 @@
+use std::collections::{BTreeSet, HashMap};
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::time::SystemTime;
+
 use bevy::{
     asset::{AssetEvent, LoadedFolder},
     ecs::message::MessageReader,
@@ -5,14 +10,30 @@ use bevy::{
     prelude::*,
 };
 
-use crate::data_types::{GameData, GameRegistry, load_game_data};
+use crate::data_types::{
+    DATA_FILES, DataLoadError, DisabledMods, GameData, GameRegistry, LoadedMods, MOD_MANIFEST_FILE,
+    ModLoadWarnings, load_game_data_with_mods, reload_data_file,
+};
 
-use super::GameDataSource;
+use super::{GameDataSource, mods_path};
 
 #[derive(Resource, Default)]
 pub struct DataHotReload {
     pub base_handle: Option<Handle<LoadedFolder>>,
     pub mods_handle: Option<Handle<LoadedFolder>>,
+    /// Last seen modification time of every data and mod file.
+    pub file_timestamps: HashMap<PathBuf, SystemTime>,
+}
+
+/// What needs to be re-parsed after the data files changed on disk.
+#[derive(Debug, PartialEq, Eq)]
+pub enum ReloadScope {
+    /// No file changed.
+    Nothing,
+    /// Only these data files (e.g. `technologies.ron`) changed.
+    Files(BTreeSet<&'static str>),
+    /// Files were added or removed, or a mod manifest changed.
+    Everything,
 }
 
 impl DataHotReload {
@@ -25,20 +46,146 @@ impl DataHotReload {
                 || event.is_removed(handle.id())
         })
     }
+
+    /// Record the current modification times of all data files.
+    pub fn record_timestamps(&mut self, data_dir: &Path, mods_dir: &Path) {
+        self.file_timestamps = scan_timestamps(data_dir, mods_dir);
+    }
+
+    /// Compare the files on disk against the recorded timestamps and
+    /// remember the new ones.
+    pub fn take_changes(&mut self, data_dir: &Path, mods_dir: &Path) -> ReloadScope {
+        let current = scan_timestamps(data_dir, mods_dir);
+        let previous = std::mem::replace(&mut self.file_timestamps, current);
+        let current = &self.file_timestamps;
+
+        if previous.len() != current.len() || previous.keys().any(|p| !current.contains_key(p)) {
+            return ReloadScope::Everything;
+        }
+
+        let mut files = BTreeSet::new();
+        for (path, modified) in current {
+            if previous.get(path) == Some(modified) {
+                continue;
+            }
+            let name = path.file_name().and_then(|name| name.to_str());
+            match DATA_FILES.iter().find(|&&file| Some(file) == name) {
+                Some(file) => {
+                    files.insert(*file);
+                }
+                None => return ReloadScope::Everything,
+            }
+        }
+
+        if files.is_empty() {
+            ReloadScope::Nothing
+        } else {
+            ReloadScope::Files(files)
+        }
+    }
+}
+
+/// Modification times of the `.ron` files in `data_dir` and in every mod
+/// directory under `mods_dir`.
+fn scan_timestamps(data_dir: &Path, mods_dir: &Path) -> HashMap<PathBuf, SystemTime> {
+    let mut dirs = vec![data_dir.to_path_buf()];
+    if let Ok(entries) = fs::read_dir(mods_dir) {
+        dirs.extend(
+            entries
+                .flatten()
+                .map(|entry| entry.path())
+                .filter(|path| path.is_dir()),
+        );
+    }
+
+    dirs.iter()
+        .filter_map(|dir| fs::read_dir(dir).ok())
+        .flat_map(|entries| entries.flatten())
+        .map(|entry| entry.path())
+        .filter(|path| path.extension().is_some_and(|ext| ext == "ron"))
+        .filter_map(|path| {
+            let modified = fs::metadata(&path).and_then(|meta| meta.modified()).ok()?;
+            Some((path, modified))
+        })
+        .collect()
 }
 
+/// Number of times the game data has been swapped by a reload.
+///
+/// Starts at 0 and increases whenever new [`GameData`] and
+/// [`GameRegistry`] resources replace the old ones, so systems caching
+/// values derived from the data can tell when to recompute them.
+#[derive(Resource, Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub struct GameDataVersion(pub u32);
+
+/// Replace the [`GameData`] and [`GameRegistry`] resources and bump the
+/// [`GameDataVersion`].
+///
+/// All three are inserted through the same `commands`, so no system ever
+/// sees the new data with the old registry or the other way round. They
+/// take effect when the commands are applied, i.e. by the next frame.
+pub fn swap_game_data(
+    commands: &mut Commands,
+    version: GameDataVersion,
+    data: GameData,
+    registry: GameRegistry,
+) {
+    commands.insert_resource(data);
+    commands.insert_resource(registry);
+    commands.insert_resource(GameDataVersion(version.0.wrapping_add(1)));
+}
+
+/// Message asking for the game data and every enabled mod to be loaded
+/// again, e.g. after mods were enabled or disabled.
+#[derive(Message, Debug, Clone, Copy)]
+pub struct ReloadGameData;
+
 #[derive(SystemParam)]
-pub struct HotReloadTargets<'w> {
-    game_data: ResMut<'w, GameData>,
-    registry: ResMut<'w, GameRegistry>,
+pub struct HotReloadTargets<'w, 's> {
+    commands: Commands<'w, 's>,
+    game_data: Res<'w, GameData>,
+    version: Res<'w, GameDataVersion>,
+    mod_warnings: ResMut<'w, ModLoadWarnings>,
+    loaded_mods: ResMut<'w, LoadedMods>,
+    disabled_mods: Res<'w, DisabledMods>,
+}
+
+impl HotReloadTargets<'_, '_> {
+    /// Load the data and mods from scratch, keeping the current data if
+    /// that fails.
+    fn reload_everything(&mut self, data_path: &str) {
+        match load_game_data_with_mods(data_path, mods_path(data_path), &self.disabled_mods) {
+            Ok((new_data, new_registry, new_mods, new_warnings)) => {
+                swap_game_data(&mut self.commands, *self.version, new_data, new_registry);
+                *self.loaded_mods = new_mods;
+                *self.mod_warnings = new_warnings;
+
+                info!("Reloaded game data from {data_path}");
+            }
+            Err(err) => {
+                warn!("Failed to reload game data: {}", err.report());
+            }
+        }
+    }
+}
+
+/// Reload all game data when a [`ReloadGameData`] message arrives.
+pub fn reload_game_data_on_request(
+    mut requests: MessageReader<ReloadGameData>,
+    source: Res<GameDataSource>,
+    mut targets: HotReloadTargets,
+) {
+    if requests.read().count() > 0 {
+        targets.reload_everything(&source.data_path);
+    }
 }
 
 pub fn hot_reload_game_data(
     asset_server: Res<AssetServer>,
     source: Res<GameDataSource>,
-    watchers: Res<DataHotReload>,
+    mut watchers: ResMut<DataHotReload>,
     mut events: MessageReader<AssetEvent<LoadedFolder>>,
-    targets: HotReloadTargets,
+    mut targets: HotReloadTargets,
 ) {
     if !asset_server.watching_for_changes() {
         return;
@@ -56,20 +203,67 @@ pub fn hot_reload_game_data(
         return;
     }
 
-    let HotReloadTargets {
-        mut game_data,
-        mut registry,
-    } = targets;
+    let data_dir = Path::new(&source.data_path);
+    let mods_dir = mods_path(&source.data_path);
 
-    match load_game_data(&source.data_path) {
-        Ok((new_data, new_registry)) => {
-            *game_data = new_data;
-            *registry = new_registry;
+    match watchers.take_changes(data_dir, &mods_dir) {
+        ReloadScope::Nothing => {}
+        ReloadScope::Files(files) => {
+            match reload_changed_files(&mut targets, data_dir, &mods_dir, &files) {
+                Ok((new_data, new_registry, new_warnings)) => {
+                    swap_game_data(
+                        &mut targets.commands,
+                        *targets.version,
+                        new_data,
+                        new_registry,
+                    );
+                    targets.mod_warnings.warnings.retain(|warning| {
+                        !files
+                            .iter()
+                            .any(|file| warning.contains(&format!("skipped {file}")))
+                    });
+                    targets.mod_warnings.warnings.extend(new_warnings.warnings);
 
-            info!("Hot reloaded game data from {}", source.data_path);
-        }
-        Err(err) => {
-            warn!("Failed to hot reload game data: {err}");
+                    let files: Vec<_> = files.into_iter().collect();
+                    info!("Hot reloaded {}", files.join(", "));
+                }
+                Err(err) => {
+                    warn!(
+                        "Failed to hot reload game data, keeping previous data: {}",
+                        err.report()
+                    );
+                }
+            }
         }
+        ReloadScope::Everything => targets.reload_everything(&source.data_path),
     }
 }
+
+/// Re-parse `files` on top of a copy of `game_data` and build a registry
+/// for the result.
+///
+/// Nothing is swapped here, so on error the current data and registry
+/// stay in place.
+fn reload_changed_files(
+    targets: &mut HotReloadTargets,
+    data_dir: &Path,
+    mods_dir: &Path,
+    files: &BTreeSet<&'static str>,
+) -> Result<(GameData, GameRegistry, ModLoadWarnings), DataLoadError> {
+    let mut new_data = targets.game_data.clone();
+    let mut warnings = ModLoadWarnings::default();
+    for file in files {
+        let file_warnings = reload_data_file(
+            &mut new_data,
+            data_dir,
+            mods_dir,
+            &targets.disabled_mods,
+            file,
+        )?;
+        warnings.warnings.extend(file_warnings.warnings);
+    }
+
+    new_data.validate()?;
+    let registry = GameRegistry::from_game_data(&new_data)?;
+    Ok((new_data, registry, warnings))
+}
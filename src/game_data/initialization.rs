@@ -4,6 +4,7 @@ use std::path::Path;
 use crate::data_types::{GameData, GameRegistry};
 
 use super::hot_reload::DataHotReload;
+use super::mods_path;
 
 fn asset_relative_path(path: impl AsRef<Path>) -> Option<String> {
     let path = path.as_ref();
@@ -39,10 +40,8 @@ pub fn initialize_game_resources(
     if let Some(asset_server) = app.world().get_resource::<AssetServer>().cloned() {
         let mut watchers = app.world_mut().resource_mut::<DataHotReload>();
         let base_path = asset_relative_path(data_path);
-        let mods_path = Path::new(data_path)
-            .parent()
-            .unwrap_or_else(|| Path::new("assets"))
-            .join("mods");
+        let mods_path = mods_path(data_path);
+        watchers.record_timestamps(Path::new(data_path), &mods_path);
         watchers.base_handle = base_path.map(|path| asset_server.load_folder(path));
 
         if mods_path.exists() {
@@ -6,32 +6,44 @@
 //! - [`initialization`] - Creates `GameData` and `GameRegistry` resources
 //! - [`hot_reload`] - File watching for development-time data updates
 //! - [`loader`] - Bevy asset loader for RON files
+//! - [`validate`] - The `--validate-data` command-line check
 //!
 //! # Usage
 //! Add `GameDataPlugin` to your Bevy app to automatically load
-//! all game data from `assets/data/` at startup.
+//! all game data from `assets/data/` at startup, with any mods from
+//! `assets/mods/` applied on top.
 
 pub mod hot_reload;
 pub mod initialization;
 mod loader;
+#[cfg(test)]
+mod tests;
+pub mod validate;
+
+use std::path::{Path, PathBuf};
 
 use bevy::prelude::*;
 
-use crate::data_types::load_game_data;
+use crate::data_types::{DISABLED_MODS_PATH, load_disabled_mods, load_game_data_with_mods};
 
-use self::hot_reload::{DataHotReload, hot_reload_game_data};
+use self::hot_reload::{DataHotReload, hot_reload_game_data, reload_game_data_on_request};
+pub use self::hot_reload::{GameDataVersion, ReloadGameData};
 use self::initialization::initialize_game_resources;
 use self::loader::{RonAsset, RonLoader};
+use self::validate::{check_strict_mods, load_logging_all_errors, strict_mods_requested};
 
 /// Plugin that loads game data from RON files and registers it as a resource.
 ///
 /// # Startup Behavior
-/// 1. Calls `load_game_data()` to parse all RON files
-/// 2. Creates `GameData` and `GameRegistry` resources
+/// 1. Calls `load_game_data_with_mods()` to parse all RON files and mods
+/// 2. Creates `GameData`, `GameRegistry`, `LoadedMods`, and `ModLoadWarnings` resources
 /// 3. Sets up hot-reload file watching (if enabled)
 ///
 /// # Panics
-/// Panics at startup if game data cannot be loaded (invalid RON, missing files, etc.).
+/// Panics at startup if game data cannot be loaded (invalid RON, missing files,
+/// broken mod manifests, conflicting mods with `--strict-mods`, etc.). Mod
+/// data files that fail to parse only produce warnings. Debug builds log
+/// every validation error before panicking.
 pub struct GameDataPlugin {
     /// Path to the directory containing the RON data files.
     pub data_path: String,
@@ -45,6 +57,15 @@ impl Default for GameDataPlugin {
     }
 }
 
+/// Directory holding mods for the data directory `data_path`
+/// (a `mods` directory next to it, e.g. `assets/mods`).
+pub fn mods_path(data_path: &str) -> PathBuf {
+    Path::new(data_path)
+        .parent()
+        .unwrap_or_else(|| Path::new("assets"))
+        .join("mods")
+}
+
 /// Resource storing the path to game data files.
 ///
 /// Used by the hot-reload system to know which directory to watch.
@@ -65,16 +86,36 @@ impl Plugin for GameDataPlugin {
             data_path: self.data_path.clone(),
         });
         app.insert_resource(DataHotReload::default());
+        app.init_resource::<GameDataVersion>();
+        app.add_message::<ReloadGameData>();
+        // Mods the player disabled in an earlier session stay disabled
+        let disabled = load_disabled_mods(Path::new(DISABLED_MODS_PATH));
+        app.insert_resource(disabled.clone());
 
         // Load game data synchronously at startup
-        match load_game_data(&self.data_path) {
-            Ok((game_data, registry)) => {
+        let loaded = if cfg!(debug_assertions) {
+            load_logging_all_errors(&self.data_path, &disabled)
+        } else {
+            load_game_data_with_mods(&self.data_path, mods_path(&self.data_path), &disabled)
+        };
+        let loaded = loaded.and_then(|loaded| {
+            check_strict_mods(&loaded.3, strict_mods_requested())?;
+            Ok(loaded)
+        });
+        match loaded {
+            Ok((game_data, registry, loaded_mods, mod_warnings)) => {
                 info!("Loaded game data from {}", self.data_path);
+                app.insert_resource(loaded_mods);
+                app.insert_resource(mod_warnings);
                 initialize_game_resources(app, game_data, registry, &self.data_path);
-                app.add_systems(Update, hot_reload_game_data);
+                app.add_systems(Update, (hot_reload_game_data, reload_game_data_on_request));
             }
             Err(err) => {
-                error!("Failed to load game data from {}: {}", self.data_path, err);
+                error!(
+                    "Failed to load game data from {}: {}",
+                    self.data_path,
+                    err.report()
+                );
                 panic!("Failed to load game data; see error log for details");
             }
         }
@@ -0,0 +1,51 @@
+use std::path::PathBuf;
+
+use bevy::prelude::*;
+
+use super::hot_reload::{GameDataVersion, swap_game_data};
+use crate::data_types::{GameData, GameRegistry, load_game_data};
+
+/// What the observing system saw each frame: data version and technology
+/// count.
+#[derive(Resource, Default)]
+struct Seen(Vec<(u32, usize)>);
+
+/// New data waiting to be swapped in.
+#[derive(Resource)]
+struct Pending(Option<(GameData, GameRegistry)>);
+
+fn observe(version: Res<GameDataVersion>, data: Res<GameData>, mut seen: ResMut<Seen>) {
+    seen.0.push((version.0, data.technologies().len()));
+}
+
+fn swap(mut commands: Commands, version: Res<GameDataVersion>, mut pending: ResMut<Pending>) {
+    if let Some((data, registry)) = pending.0.take() {
+        swap_game_data(&mut commands, *version, data, registry);
+    }
+}
+
+#[test]
+fn swapped_data_is_seen_on_the_next_frame() {
+    let (data, registry) = load_game_data(PathBuf::from("assets/data")).expect("data loads");
+    let mut new_data = data.clone();
+    new_data.technologies.clear();
+    let new_registry = GameRegistry::from_game_data(&new_data).expect("registry builds");
+    let old_count = data.technologies().len();
+    assert!(old_count > 0);
+
+    let mut app = App::new();
+    app.insert_resource(data)
+        .insert_resource(registry)
+        .init_resource::<GameDataVersion>()
+        .init_resource::<Seen>()
+        .insert_resource(Pending(Some((new_data, new_registry))))
+        .add_systems(Update, (observe, swap).chain());
+
+    app.update();
+    app.update();
+
+    let seen = &app.world().resource::<Seen>().0;
+    assert_eq!(seen, &[(0, old_count), (1, 0)]);
+    let registry = app.world().resource::<GameRegistry>();
+    assert!(registry.technology_id_at(0).is_none());
+}
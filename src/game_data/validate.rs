@@ -0,0 +1,109 @@
+//! Command-line check of the game data and mods.
+//!
+//! Running the game with [`VALIDATE_DATA_FLAG`] loads the data, prints every
+//! problem found by [`GameData::validate_all`] and exits without opening a
+//! window, so mod authors can fix all their mistakes in one pass.
+//!
+//! With [`STRICT_MODS_FLAG`] on the command line, or [`STRICT_MODS_ENV`]
+//! set, conflicts between mods are errors instead of warnings, which suits
+//! CI pipelines checking a mod collection.
+
+use std::path::Path;
+
+use bevy::log::error;
+
+use crate::data_types::{
+    DataLoadError, DisabledMods, GameData, GameRegistry, LoadedMods, ModLoadWarnings,
+    load_unvalidated_game_data,
+};
+
+use super::mods_path;
+
+/// Command-line flag that runs [`run_validate_data`] instead of the game.
+pub const VALIDATE_DATA_FLAG: &str = "--validate-data";
+
+/// Command-line flag that makes conflicts between mods fatal.
+pub const STRICT_MODS_FLAG: &str = "--strict-mods";
+
+/// Environment variable that makes conflicts between mods fatal when set
+/// to anything but `0`.
+pub const STRICT_MODS_ENV: &str = "ASCENORIA_STRICT_MODS";
+
+/// Whether conflicts between mods should stop the data from loading.
+pub fn strict_mods_requested() -> bool {
+    std::env::args().any(|arg| arg == STRICT_MODS_FLAG)
+        || std::env::var(STRICT_MODS_ENV).is_ok_and(|value| value != "0")
+}
+
+/// In strict mode, turn the first conflict between mods into an error.
+///
+/// # Errors
+/// Returns the conflict as [`DataLoadError::DuplicateId`].
+pub fn check_strict_mods(warnings: &ModLoadWarnings, strict: bool) -> Result<(), DataLoadError> {
+    match warnings.conflicts.first() {
+        Some(conflict) if strict => Err(conflict.to_error()),
+        _ => Ok(()),
+    }
+}
+
+/// Load the data in `data_path` with its mods, and print every error and
+/// warning.
+///
+/// Returns the process exit code: 1 if there are errors, 0 otherwise (even
+/// with warnings).
+pub fn run_validate_data(data_path: &str) -> i32 {
+    let disabled = DisabledMods::default();
+    let errors = match load_unvalidated_game_data(data_path, mods_path(data_path), &disabled) {
+        Ok((game_data, _, warnings)) => {
+            print_warnings(&warnings);
+            let mut errors = game_data.validate_all();
+            if strict_mods_requested() {
+                errors.extend(warnings.conflict_errors());
+            }
+            errors
+        }
+        Err(err) => vec![err],
+    };
+
+    for err in &errors {
+        eprintln!("error: {err}");
+        if let Some(suggestion) = err.suggestion() {
+            eprintln!("    suggestion: {suggestion}");
+        }
+    }
+
+    if errors.is_empty() {
+        println!("{}: game data is valid", Path::new(data_path).display());
+        0
+    } else {
+        eprintln!("{} error(s) found", errors.len());
+        1
+    }
+}
+
+/// Report problems with mods that did not stop the data from loading.
+fn print_warnings(warnings: &ModLoadWarnings) {
+    for warning in &warnings.warnings {
+        eprintln!("warning: {warning}");
+    }
+}
+
+/// Load the data like [`crate::data_types::load_game_data_with_mods`], but
+/// log every validation error before failing instead of only the first.
+pub(super) fn load_logging_all_errors(
+    data_path: &str,
+    disabled: &DisabledMods,
+) -> Result<(GameData, GameRegistry, LoadedMods, ModLoadWarnings), DataLoadError> {
+    let (game_data, loaded, warnings) =
+        load_unvalidated_game_data(data_path, mods_path(data_path), disabled)?;
+    let mut errors = game_data.validate_all();
+    for err in &errors {
+        error!("Invalid game data: {}", err.report());
+    }
+    if !errors.is_empty() {
+        return Err(errors.swap_remove(0));
+    }
+
+    let registry = GameRegistry::from_game_data(&game_data)?;
+    Ok((game_data, registry, loaded, warnings))
+}
@@ -0,0 +1,202 @@
+//! Remappable keyboard shortcuts.
+//!
+//! Systems look keys up by action name in [`KeyBindings`] instead of
+//! hard-coding a [`KeyCode`]. Defaults come from [`DEFAULT_BINDINGS_PATH`];
+//! keys the player rebinds in the main menu's Controls panel are written to
+//! [`USER_BINDINGS_PATH`] and loaded on top of the defaults.
+//!
+//! # Example
+//! ```ignore
+//! if bindings.just_pressed(&keyboard, actions::BACK) {
+//!     next_state.set(GameState::MainMenu);
+//! }
+//! ```
+
+use std::collections::{BTreeMap, HashMap};
+use std::fs;
+use std::path::Path;
+
+use bevy::prelude::*;
+use serde::{Deserialize, Serialize};
+
+use crate::data_types::{DataLoadError, load_ron_file};
+
+#[cfg(test)]
+mod tests;
+
+/// Default key bindings shipped with the game.
+pub const DEFAULT_BINDINGS_PATH: &str = "assets/data/keybindings.ron";
+
+/// Key bindings changed by the player.
+pub const USER_BINDINGS_PATH: &str = "settings/keybindings.ron";
+
+/// Action names used as [`KeyBindings`] keys.
+pub mod actions {
+    /// Leave the current screen (planet view to menu, tech tree to planet).
+    pub const BACK: &str = "back";
+    /// Quit the game from the main menu (together with Alt).
+    pub const QUIT: &str = "quit";
+    /// Pan the tech tree left.
+    pub const PAN_LEFT: &str = "pan_left";
+    /// Pan the tech tree right.
+    pub const PAN_RIGHT: &str = "pan_right";
+    /// Pan the tech tree up.
+    pub const PAN_UP: &str = "pan_up";
+    /// Pan the tech tree down.
+    pub const PAN_DOWN: &str = "pan_down";
+    /// Go to the next tutorial step.
+    pub const TUTORIAL_NEXT: &str = "tutorial_next";
+    /// Open or close the notification log in the planet view.
+    pub const NOTIFICATION_LOG: &str = "notification_log";
+}
+
+/// Keys assigned to each action.
+#[derive(Resource, Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct KeyBindings {
+    /// Key for each action name (see [`actions`]).
+    pub bindings: HashMap<String, KeyCode>,
+}
+
+impl Default for KeyBindings {
+    /// Built-in bindings, used when [`DEFAULT_BINDINGS_PATH`] cannot be read.
+    fn default() -> Self {
+        let bindings = [
+            (actions::BACK, KeyCode::Escape),
+            (actions::QUIT, KeyCode::KeyX),
+            (actions::PAN_LEFT, KeyCode::ArrowLeft),
+            (actions::PAN_RIGHT, KeyCode::ArrowRight),
+            (actions::PAN_UP, KeyCode::ArrowUp),
+            (actions::PAN_DOWN, KeyCode::ArrowDown),
+            (actions::TUTORIAL_NEXT, KeyCode::Space),
+            (actions::NOTIFICATION_LOG, KeyCode::KeyL),
+        ];
+        Self {
+            bindings: bindings
+                .into_iter()
+                .map(|(action, key)| (action.to_string(), key))
+                .collect(),
+        }
+    }
+}
+
+impl KeyBindings {
+    /// Key bound to `action`.
+    pub fn key(&self, action: &str) -> Option<KeyCode> {
+        self.bindings.get(action).copied()
+    }
+
+    /// Whether the key for `action` is held down.
+    pub fn pressed(&self, keyboard: &ButtonInput<KeyCode>, action: &str) -> bool {
+        self.key(action).is_some_and(|key| keyboard.pressed(key))
+    }
+
+    /// Whether the key for `action` was pressed this frame.
+    pub fn just_pressed(&self, keyboard: &ButtonInput<KeyCode>, action: &str) -> bool {
+        self.key(action)
+            .is_some_and(|key| keyboard.just_pressed(key))
+    }
+
+    /// Bind `action` to `key`, replacing its previous key.
+    pub fn rebind(&mut self, action: &str, key: KeyCode) {
+        self.bindings.insert(action.to_string(), key);
+    }
+
+    /// Keys bound to more than one action, with those actions (sorted).
+    pub fn duplicates(&self) -> Vec<(KeyCode, Vec<String>)> {
+        let mut by_key: HashMap<KeyCode, Vec<String>> = HashMap::new();
+        for (action, key) in &self.bindings {
+            by_key.entry(*key).or_default().push(action.clone());
+        }
+
+        let mut duplicates: Vec<_> = by_key
+            .into_iter()
+            .filter(|(_, actions)| actions.len() > 1)
+            .map(|(key, mut actions)| {
+                actions.sort();
+                (key, actions)
+            })
+            .collect();
+        duplicates.sort_by(|a, b| a.1.cmp(&b.1));
+        duplicates
+    }
+
+    /// Log a warning for every key bound to several actions.
+    pub fn warn_duplicates(&self) {
+        for (key, actions) in self.duplicates() {
+            warn!(
+                "Key {key:?} is bound to several actions: {}",
+                actions.join(", ")
+            );
+        }
+    }
+}
+
+/// Short display name of a key, e.g. `X` for [`KeyCode::KeyX`].
+pub fn key_label(key: KeyCode) -> String {
+    let name = format!("{key:?}");
+    ["Key", "Digit"]
+        .iter()
+        .find_map(|prefix| name.strip_prefix(prefix))
+        .filter(|rest| !rest.is_empty())
+        .map_or_else(|| name.clone(), str::to_string)
+}
+
+/// Load the default bindings and apply the player's overrides.
+///
+/// A missing or broken defaults file falls back to
+/// [`KeyBindings::default`]; a missing overrides file is normal.
+pub fn load_key_bindings(defaults_path: &Path, user_path: &Path) -> KeyBindings {
+    let mut bindings = load_ron_file::<KeyBindings>(defaults_path).unwrap_or_else(|err| {
+        warn!("Using built-in key bindings: {err}");
+        KeyBindings::default()
+    });
+
+    if user_path.exists() {
+        match load_ron_file::<KeyBindings>(user_path) {
+            Ok(user) => bindings.bindings.extend(user.bindings),
+            Err(err) => warn!("Ignoring custom key bindings: {err}"),
+        }
+    }
+
+    bindings.warn_duplicates();
+    bindings
+}
+
+/// Write `bindings` to `path`, creating parent directories as needed.
+///
+/// # Errors
+/// Returns an error if the file cannot be written.
+pub fn save_key_bindings(path: &Path, bindings: &KeyBindings) -> Result<(), DataLoadError> {
+    let io_error = |source| DataLoadError::io(source, path.display().to_string());
+
+    // Sort actions so the file is stable between saves.
+    let sorted: BTreeMap<&String, &KeyCode> = bindings.bindings.iter().collect();
+    let content = ron::ser::to_string_pretty(
+        &KeyBindingsFile { bindings: sorted },
+        ron::ser::PrettyConfig::default(),
+    )
+    .map_err(|err| io_error(std::io::Error::other(err)))?;
+
+    if let Some(parent) = path.parent().filter(|p| !p.as_os_str().is_empty()) {
+        fs::create_dir_all(parent).map_err(io_error)?;
+    }
+    fs::write(path, content).map_err(io_error)
+}
+
+/// Serialized form of [`KeyBindings`] with actions in name order.
+#[derive(Serialize)]
+struct KeyBindingsFile<'a> {
+    bindings: BTreeMap<&'a String, &'a KeyCode>,
+}
+
+/// Plugin that loads [`KeyBindings`] at startup.
+pub struct KeyBindingsPlugin;
+
+impl Plugin for KeyBindingsPlugin {
+    fn build(&self, app: &mut App) {
+        app.insert_resource(load_key_bindings(
+            Path::new(DEFAULT_BINDINGS_PATH),
+            Path::new(USER_BINDINGS_PATH),
+        ));
+    }
+}
@@ -0,0 +1,68 @@
+use std::path::{Path, PathBuf};
+
+use bevy::prelude::KeyCode;
+
+use super::{
+    DEFAULT_BINDINGS_PATH, KeyBindings, actions, key_label, load_key_bindings, save_key_bindings,
+};
+
+fn temp_path(name: &str) -> PathBuf {
+    std::env::temp_dir()
+        .join(format!("ascenoria_keys_{}", std::process::id()))
+        .join(name)
+}
+
+#[test]
+fn bundled_defaults_match_built_in_bindings() {
+    let bindings = load_key_bindings(Path::new(DEFAULT_BINDINGS_PATH), &temp_path("missing.ron"));
+
+    assert_eq!(bindings, KeyBindings::default());
+    assert!(bindings.duplicates().is_empty());
+}
+
+#[test]
+fn user_bindings_override_defaults() {
+    let mut custom = KeyBindings::default();
+    custom.bindings.clear();
+    custom.rebind(actions::BACK, KeyCode::Backspace);
+    let path = temp_path("override.ron");
+    save_key_bindings(&path, &custom).expect("save bindings");
+
+    let bindings = load_key_bindings(Path::new(DEFAULT_BINDINGS_PATH), &path);
+
+    assert_eq!(bindings.key(actions::BACK), Some(KeyCode::Backspace));
+    assert_eq!(bindings.key(actions::PAN_LEFT), Some(KeyCode::ArrowLeft));
+}
+
+#[test]
+fn round_trips_through_disk() {
+    let mut bindings = KeyBindings::default();
+    bindings.rebind(actions::TUTORIAL_NEXT, KeyCode::Enter);
+    let path = temp_path("round_trip.ron");
+
+    save_key_bindings(&path, &bindings).expect("save bindings");
+    let loaded = load_key_bindings(&temp_path("no_defaults.ron"), &path);
+
+    assert_eq!(loaded, bindings);
+}
+
+#[test]
+fn detects_keys_shared_by_several_actions() {
+    let mut bindings = KeyBindings::default();
+    bindings.rebind(actions::PAN_UP, KeyCode::Escape);
+
+    assert_eq!(
+        bindings.duplicates(),
+        vec![(
+            KeyCode::Escape,
+            vec![actions::BACK.to_string(), actions::PAN_UP.to_string()]
+        )]
+    );
+}
+
+#[test]
+fn key_labels_drop_type_prefixes() {
+    assert_eq!(key_label(KeyCode::KeyX), "X");
+    assert_eq!(key_label(KeyCode::Digit3), "3");
+    assert_eq!(key_label(KeyCode::Escape), "Escape");
+}
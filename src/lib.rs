@@ -10,14 +10,42 @@
 //! - [`game_data`] - Bevy plugin for loading game data at startup
 //!
 //! ## Game Logic
+//! - [`ai`] - AI species turn processing and build heuristics
 //! - [`planet_data`] - Planet surface generation and tile types
+//! - [`research`] - Technology graph and research queue
+//! - [`save_load`] - Save game serialization to RON
 //!
 //! ## Presentation Layer
+//! - [`defeat_screen`] - Defeat summary screen and game statistics
+//! - [`keybindings`] - Remappable keyboard shortcuts
+//! - [`settings`] - Persistent player settings (UI size, volume)
+//! - [`sfx`] - Sound effect hooks for UI and game events
 //! - [`main_menu`] - Main menu screen and game state machine
+//! - [`mod_manager`] - Mod list, conflicts, and enabling/disabling mods
 //! - [`planet_view`] - Planet surface management screen (3D + UI)
+//! - [`screen_transition`] - Fade between screens on state changes
+//! - [`tech_tree_view`] - Technology tree screen
+//! - [`tooltip`] - Hover tooltips for UI entities
+//! - [`tutorial`] - First-run tutorial overlay
+//! - [`ui_layout`] - UI sizes that scale with the window resolution
 
+pub mod ai;
 pub mod data_types;
+pub mod defeat_screen;
+pub mod diplomacy;
 pub mod game_data;
+pub mod keybindings;
 pub mod main_menu;
+pub mod mod_manager;
 pub mod planet_data;
 pub mod planet_view;
+pub mod research;
+pub mod save_load;
+pub mod screen_transition;
+pub mod settings;
+pub mod sfx;
+pub mod tech_tree_view;
+pub mod tooltip;
+pub mod tutorial;
+pub mod ui_layout;
+pub mod victory;
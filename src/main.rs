@@ -7,20 +7,56 @@
 //! The game is organized as a collection of Bevy plugins:
 //!
 //! - [`GameDataPlugin`] - Loads RON data files and creates the `GameData` and `GameRegistry` resources
+//! - [`KeyBindingsPlugin`] - Loads remappable keyboard shortcuts
+//! - [`SettingsPlugin`] - Loads, applies, and saves the player's settings
 //! - [`MainMenuPlugin`] - Main menu screen and `GameState` state machine
 //! - [`PlanetViewPlugin`] - Planet surface management screen
+//! - [`TechTreePlugin`] - Technology tree and research queueing
+//! - [`ModManagerPlugin`] - Mod list with conflicts and enabling/disabling mods
+//! - [`TooltipPlugin`] - Hover tooltips for buttons and tech nodes
+//! - [`SfxPlugin`] - Sound effects for UI and game events
+//! - [`AiPlugin`] - AI species that take their turns after the player
+//! - [`DiplomacyPlugin`] - Stances between species and the diplomacy panel
+//! - [`VictoryPlugin`] - Checks victory conditions at the end of every turn
+//! - [`DefeatPlugin`] - Tracks game statistics and shows the defeat screen
+//! - [`TutorialPlugin`] - Step-by-step tutorial shown on the first run
+//! - [`AutoSavePlugin`] - Saves the game automatically at the end of a turn
+//! - [`ScreenTransitionPlugin`] - Fades to black and back between screens
+//! - [`LayoutPlugin`] - Scales panel widths, buttons, and HUD text with the window size
+//!
+//! Running with `--validate-data` checks the game data and mods, prints
+//! every problem found, and exits instead of starting the game.
 //!
 //! # State Machine
 //!
 //! Game flow is controlled by the `GameState` enum:
 //! - `MainMenu` → `PlanetView` (when player starts game)
 //! - `PlanetView` → `MainMenu` (when player presses ESC)
+//! - `PlanetView` ⇄ `TechTree` (Research button / ESC)
+//! - `MainMenu` ⇄ `ModManager` (Mods button / ESC)
+//! - `PlanetView` → `DefeatScreen` (game lost) → `PlanetView` / `MainMenu`
 
 use bevy::{asset::AssetPlugin, prelude::*};
 
+use ascenoria::ai::AiPlugin;
+use ascenoria::defeat_screen::DefeatPlugin;
+use ascenoria::diplomacy::DiplomacyPlugin;
 use ascenoria::game_data::GameDataPlugin;
+use ascenoria::game_data::validate::{VALIDATE_DATA_FLAG, run_validate_data};
+use ascenoria::keybindings::{KeyBindings, KeyBindingsPlugin, actions};
 use ascenoria::main_menu::{GameState, MainMenuPlugin};
+use ascenoria::mod_manager::ModManagerPlugin;
 use ascenoria::planet_view::PlanetViewPlugin;
+use ascenoria::planet_view::ui::context_menu::ContextMenuState;
+use ascenoria::save_load::AutoSavePlugin;
+use ascenoria::screen_transition::{ScreenTransitionPlugin, TransitionToState};
+use ascenoria::settings::SettingsPlugin;
+use ascenoria::sfx::SfxPlugin;
+use ascenoria::tech_tree_view::TechTreePlugin;
+use ascenoria::tooltip::TooltipPlugin;
+use ascenoria::tutorial::TutorialPlugin;
+use ascenoria::ui_layout::LayoutPlugin;
+use ascenoria::victory::VictoryPlugin;
 
 /// Application entry point.
 ///
@@ -29,6 +65,10 @@ use ascenoria::planet_view::PlanetViewPlugin;
 /// - Asset hot-reloading enabled for development
 /// - Game-specific plugins for data, menus, and gameplay
 fn main() {
+    if std::env::args().any(|arg| arg == VALIDATE_DATA_FLAG) {
+        std::process::exit(run_validate_data(&GameDataPlugin::default().data_path));
+    }
+
     App::new()
         .add_plugins((
             DefaultPlugins.set(AssetPlugin {
@@ -37,8 +77,25 @@ fn main() {
                 ..default()
             }),
             GameDataPlugin::default(),
+            KeyBindingsPlugin,
             MainMenuPlugin,
             PlanetViewPlugin,
+            TechTreePlugin,
+            ModManagerPlugin,
+            TooltipPlugin,
+            SfxPlugin,
+            AiPlugin,
+            DiplomacyPlugin,
+            VictoryPlugin,
+            TutorialPlugin,
+            AutoSavePlugin,
+        ))
+        // Bevy accepts at most 15 plugins per tuple.
+        .add_plugins((
+            LayoutPlugin,
+            SettingsPlugin,
+            ScreenTransitionPlugin,
+            DefeatPlugin,
         ))
         .add_systems(
             Update,
@@ -47,15 +104,23 @@ fn main() {
         .run();
 }
 
-/// Handle ESC key to return to main menu from planet view.
+/// Handle the Back key (ESC by default) to return to main menu from planet view.
 ///
 /// This system runs only when in `GameState::PlanetView` and allows
-/// the player to exit back to the main menu at any time.
+/// the player to exit back to the main menu at any time. An open tile
+/// context menu is closed first instead.
 fn return_to_menu_input(
     keyboard: Res<ButtonInput<KeyCode>>,
-    mut next_state: ResMut<NextState<GameState>>,
+    bindings: Res<KeyBindings>,
+    mut context_menu: ResMut<ContextMenuState>,
+    mut transitions: MessageWriter<TransitionToState>,
 ) {
-    if keyboard.just_pressed(KeyCode::Escape) {
-        next_state.set(GameState::MainMenu);
+    if !bindings.just_pressed(&keyboard, actions::BACK) {
+        return;
+    }
+    if context_menu.is_open() {
+        context_menu.close();
+    } else {
+        transitions.write(TransitionToState(GameState::MainMenu));
     }
 }
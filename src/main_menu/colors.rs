@@ -18,3 +18,5 @@ pub const BACKGROUND_DARK: Color = Color::srgb(0.45, 0.25, 0.12);
 pub const TITLE_TEXT: Color = Color::srgb(0.95, 0.75, 0.35);
 /// Subtitle/version text.
 pub const SUBTITLE_TEXT: Color = Color::srgb(0.7, 0.5, 0.25);
+/// Warning text (e.g. mods that failed to load) - deep red.
+pub const WARNING_TEXT: Color = Color::srgb(0.6, 0.1, 0.05);
@@ -1,3 +1,5 @@
+use std::path::PathBuf;
+
 use bevy::prelude::*;
 
 /// Marker component for all main menu UI entities.
@@ -8,5 +10,71 @@ pub struct MainMenuRoot;
 #[derive(Component, Clone, Copy)]
 pub enum MenuButton {
     NewGame,
+    SaveGame,
+    LoadGame,
+    Controls,
+    Settings,
+    Mods,
     Exit,
 }
+
+/// Marker for the message modal overlay.
+#[derive(Component)]
+pub struct MenuModal;
+
+/// Marker for the message modal's OK button.
+#[derive(Component)]
+pub struct MenuModalButton;
+
+/// Marker for the key bindings panel.
+#[derive(Component)]
+pub struct ControlsPanel;
+
+/// Button showing the key bound to an action; click to rebind it.
+#[derive(Component)]
+pub struct ControlsBindingButton {
+    /// Action name in [`crate::keybindings::KeyBindings`].
+    pub action: String,
+}
+
+/// Marker for the key bindings panel's Back button.
+#[derive(Component)]
+pub struct ControlsCloseButton;
+
+/// Marker for the Settings panel.
+#[derive(Component)]
+pub struct SettingsPanel;
+
+/// Action performed by a Settings panel button.
+#[derive(Component, Clone, Copy)]
+pub enum SettingsButton {
+    /// Make the UI smaller.
+    FontSmaller,
+    /// Make the UI larger.
+    FontLarger,
+    /// Lower the sound volume.
+    VolumeDown,
+    /// Raise the sound volume.
+    VolumeUp,
+    /// Close the panel.
+    Close,
+}
+
+/// Marker for the Load Game save browser.
+#[derive(Component)]
+pub struct SaveBrowserPanel;
+
+/// Action performed by a save browser button.
+#[derive(Component, Clone)]
+pub enum SaveBrowserButton {
+    /// Load the save at this path.
+    Load(PathBuf),
+    /// Ask to delete the save at this path.
+    Delete(PathBuf),
+    /// Delete the save waiting for confirmation.
+    ConfirmDelete,
+    /// Keep the save waiting for confirmation.
+    CancelDelete,
+    /// Close the browser.
+    Close,
+}
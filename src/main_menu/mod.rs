@@ -5,15 +5,32 @@
 //! # Module Structure
 //! - [`colors`] - Color palette for the menu UI
 //! - [`components`] - Marker components for menu entities
+//! - [`state`] - Message modal, Controls and Settings panels, and save browser state
 //! - [`systems`] - Setup, interaction, and cleanup systems
 
 use bevy::prelude::*;
+use serde::Deserialize;
 
 mod colors;
 mod components;
+mod state;
 mod systems;
 
-use systems::{button_system, cleanup_main_menu, menu_action_system, setup_main_menu};
+use crate::ai::AiState;
+use crate::defeat_screen::types::GameStatsTracker;
+use crate::diplomacy::DiplomacyState;
+use crate::planet_view::types::PlanetViewState;
+use crate::planet_view::ui::notification::NotificationQueue;
+use crate::planet_view::ui::notification_log::NotificationLog;
+use crate::research::ResearchQueue;
+
+use state::{ControlsPanelState, MenuModalState, SaveBrowserState, SettingsPanelState};
+use systems::{
+    button_system, cleanup_main_menu, controls_button_system, controls_capture_system,
+    controls_panel_system, menu_action_system, menu_modal_button_system, menu_modal_system,
+    save_browser_button_system, save_browser_panel_system, settings_button_system,
+    settings_panel_system, setup_main_menu,
+};
 
 /// Plugin that manages the main menu screen.
 ///
@@ -21,17 +38,40 @@ use systems::{button_system, cleanup_main_menu, menu_action_system, setup_main_m
 /// - `setup_main_menu` - Spawns UI on `OnEnter(GameState::MainMenu)`
 /// - `cleanup_main_menu` - Despawns UI on `OnExit(GameState::MainMenu)`
 /// - `button_system` - Handles hover highlighting
-/// - `menu_action_system` - Handles button clicks to navigate or exit
+/// - `menu_action_system` - Handles button clicks to navigate, save, load, or exit
+/// - `menu_modal_system` - Shows save/load errors in a modal
+/// - `controls_panel_system` - Lists key bindings and rebinds them on click
+/// - `save_browser_panel_system` - Lists the saves in `saves/` to load or delete
+/// - `settings_panel_system` - Adjusts UI size and volume in [`GameSettings`]
+///
+/// [`GameSettings`]: crate::settings::GameSettings
 pub struct MainMenuPlugin;
 
 impl Plugin for MainMenuPlugin {
     fn build(&self, app: &mut App) {
         app.init_state::<GameState>()
+            .init_resource::<MenuModalState>()
+            .init_resource::<ControlsPanelState>()
+            .init_resource::<SaveBrowserState>()
+            .init_resource::<SettingsPanelState>()
             .add_systems(OnEnter(GameState::MainMenu), setup_main_menu)
             .add_systems(OnExit(GameState::MainMenu), cleanup_main_menu)
             .add_systems(
                 Update,
-                (button_system, menu_action_system).run_if(in_state(GameState::MainMenu)),
+                (
+                    button_system,
+                    menu_action_system,
+                    menu_modal_system,
+                    menu_modal_button_system,
+                    controls_button_system,
+                    controls_capture_system,
+                    controls_panel_system,
+                    save_browser_panel_system,
+                    save_browser_button_system,
+                    settings_panel_system,
+                    settings_button_system,
+                )
+                    .run_if(in_state(GameState::MainMenu)),
             );
     }
 }
@@ -44,15 +84,40 @@ impl Plugin for MainMenuPlugin {
 /// # States
 /// - `MainMenu` - Initial state, shows title and menu buttons
 /// - `PlanetView` - Planet surface management screen
+/// - `TechTree` - Technology tree and research queue
+/// - `ModManager` - Installed mods and their conflicts
+/// - `DefeatScreen` - Summary of a lost game
 ///
 /// # Transitions
-/// - `MainMenu` → `PlanetView`: Player clicks "New Game"
+/// - `MainMenu` → `PlanetView`: Player clicks "New Game" or "Load Game"
 /// - `PlanetView` → `MainMenu`: Player presses ESC
-#[derive(States, Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+/// - `PlanetView` ⇄ `TechTree`: Player clicks "Research" / presses ESC or "Back"
+/// - `MainMenu` ⇄ `ModManager`: Player clicks "Mods" / presses ESC or "Back"
+/// - `PlanetView` → `DefeatScreen`: The game is lost
+/// - `DefeatScreen` → `PlanetView` / `MainMenu`: Player clicks "Retry" / "Main Menu"
+#[derive(States, Debug, Clone, Copy, PartialEq, Eq, Hash, Default, Deserialize)]
 pub enum GameState {
     /// Main menu screen (default starting state).
     #[default]
     MainMenu,
     /// Planet surface management screen.
     PlanetView,
+    /// Technology tree screen.
+    TechTree,
+    /// Mod manager screen.
+    ModManager,
+    /// Defeat summary screen.
+    DefeatScreen,
+}
+
+/// Drop the current session so that entering the planet view starts a new
+/// game.
+pub fn reset_session(commands: &mut Commands) {
+    commands.insert_resource(PlanetViewState::default());
+    commands.insert_resource(AiState::default());
+    commands.insert_resource(DiplomacyState::default());
+    commands.insert_resource(ResearchQueue::default());
+    commands.insert_resource(NotificationQueue::default());
+    commands.insert_resource(NotificationLog::default());
+    commands.insert_resource(GameStatsTracker::default());
 }
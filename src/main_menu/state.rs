@@ -0,0 +1,51 @@
+use std::path::PathBuf;
+
+use bevy::prelude::*;
+
+use crate::save_load::SaveSlot;
+
+/// State for the main menu message modal (e.g. save/load errors).
+#[derive(Resource, Default)]
+pub struct MenuModalState {
+    /// Message to display; `None` hides the modal.
+    pub message: Option<String>,
+}
+
+impl MenuModalState {
+    /// Show the modal with the given message.
+    pub fn show(&mut self, message: impl Into<String>) {
+        self.message = Some(message.into());
+    }
+
+    /// Hide the modal.
+    pub fn hide(&mut self) {
+        self.message = None;
+    }
+}
+
+/// State of the key bindings (Controls) panel.
+#[derive(Resource, Default)]
+pub struct ControlsPanelState {
+    /// Whether the panel is shown.
+    pub open: bool,
+    /// Action waiting for its new key, if any.
+    pub capturing: Option<String>,
+}
+
+/// State of the Settings panel.
+#[derive(Resource, Default)]
+pub struct SettingsPanelState {
+    /// Whether the panel is shown.
+    pub open: bool,
+}
+
+/// State of the Load Game save browser.
+#[derive(Resource, Default)]
+pub struct SaveBrowserState {
+    /// Whether the browser is shown.
+    pub open: bool,
+    /// Saves found when the browser was opened, newest first.
+    pub slots: Vec<SaveSlot>,
+    /// Save waiting for the player to confirm its deletion, if any.
+    pub confirm_delete: Option<PathBuf>,
+}
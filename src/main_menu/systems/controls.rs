@@ -0,0 +1,218 @@
+use std::path::Path;
+
+use bevy::{ecs::hierarchy::ChildSpawnerCommands, prelude::*};
+
+use crate::keybindings::{KeyBindings, USER_BINDINGS_PATH, key_label, save_key_bindings};
+use crate::main_menu::colors;
+use crate::main_menu::components::{
+    ControlsBindingButton, ControlsCloseButton, ControlsPanel, MainMenuRoot,
+};
+use crate::main_menu::state::ControlsPanelState;
+
+/// Spawn, rebuild, or despawn the Controls panel to match
+/// [`ControlsPanelState`] and the current [`KeyBindings`].
+pub fn controls_panel_system(
+    mut commands: Commands,
+    panel_state: Res<ControlsPanelState>,
+    bindings: Res<KeyBindings>,
+    panel_query: Query<Entity, With<ControlsPanel>>,
+) {
+    if !panel_state.is_changed() && !bindings.is_changed() {
+        return;
+    }
+
+    for entity in &panel_query {
+        commands.entity(entity).despawn();
+    }
+
+    if !panel_state.open {
+        return;
+    }
+
+    let duplicated: Vec<String> = bindings
+        .duplicates()
+        .into_iter()
+        .flat_map(|(_, actions)| actions)
+        .collect();
+    let mut actions: Vec<(&String, KeyCode)> = bindings
+        .bindings
+        .iter()
+        .map(|(action, key)| (action, *key))
+        .collect();
+    actions.sort_by(|a, b| a.0.cmp(b.0));
+
+    commands
+        .spawn((
+            Node {
+                width: Val::Percent(100.0),
+                height: Val::Percent(100.0),
+                position_type: PositionType::Absolute,
+                justify_content: JustifyContent::Center,
+                align_items: AlignItems::Center,
+                ..default()
+            },
+            BackgroundColor(Color::srgba(0.0, 0.0, 0.0, 0.5)),
+            GlobalZIndex(100),
+            ControlsPanel,
+            MainMenuRoot,
+        ))
+        .with_children(|overlay| {
+            overlay
+                .spawn((
+                    Node {
+                        width: Val::Px(460.0),
+                        flex_direction: FlexDirection::Column,
+                        align_items: AlignItems::Center,
+                        padding: UiRect::all(Val::Px(24.0)),
+                        border: UiRect::all(Val::Px(3.0)),
+                        row_gap: Val::Px(8.0),
+                        ..default()
+                    },
+                    BackgroundColor(colors::BACKGROUND_DARK),
+                    BorderColor::all(colors::BUTTON_BORDER),
+                ))
+                .with_children(|panel| {
+                    panel.spawn((
+                        Text::new("Controls"),
+                        TextFont {
+                            font_size: 26.0,
+                            ..default()
+                        },
+                        TextColor(colors::TITLE_TEXT),
+                    ));
+
+                    for (action, key) in actions {
+                        let capturing = panel_state.capturing.as_ref() == Some(action);
+                        let label = if capturing {
+                            "Press a key...".to_string()
+                        } else {
+                            key_label(key)
+                        };
+                        spawn_binding_row(panel, action, label, duplicated.contains(action));
+                    }
+
+                    if !duplicated.is_empty() {
+                        panel.spawn((
+                            Text::new("Some keys are bound to several actions"),
+                            TextFont {
+                                font_size: 14.0,
+                                ..default()
+                            },
+                            TextColor(colors::WARNING_TEXT),
+                        ));
+                    }
+
+                    spawn_panel_button(panel, "Back", ControlsCloseButton);
+                });
+        });
+}
+
+fn spawn_binding_row(
+    parent: &mut ChildSpawnerCommands,
+    action: &str,
+    label: String,
+    duplicated: bool,
+) {
+    let text_color = if duplicated {
+        colors::WARNING_TEXT
+    } else {
+        colors::BUTTON_TEXT
+    };
+
+    parent
+        .spawn(Node {
+            width: Val::Percent(100.0),
+            justify_content: JustifyContent::SpaceBetween,
+            align_items: AlignItems::Center,
+            ..default()
+        })
+        .with_children(|row| {
+            row.spawn((
+                Text::new(action.replace('_', " ")),
+                TextFont {
+                    font_size: 18.0,
+                    ..default()
+                },
+                TextColor(text_color),
+            ));
+            spawn_panel_button(
+                row,
+                &label,
+                ControlsBindingButton {
+                    action: action.to_string(),
+                },
+            );
+        });
+}
+
+fn spawn_panel_button(parent: &mut ChildSpawnerCommands, label: &str, marker: impl Bundle) {
+    parent
+        .spawn((
+            Button,
+            Node {
+                width: Val::Px(160.0),
+                height: Val::Px(34.0),
+                border: UiRect::all(Val::Px(2.0)),
+                justify_content: JustifyContent::Center,
+                align_items: AlignItems::Center,
+                ..default()
+            },
+            BackgroundColor(colors::BUTTON_NORMAL),
+            BorderColor::all(colors::BUTTON_BORDER),
+            marker,
+        ))
+        .with_children(|button| {
+            button.spawn((
+                Text::new(label),
+                TextFont {
+                    font_size: 16.0,
+                    ..default()
+                },
+                TextColor(colors::BUTTON_TEXT),
+            ));
+        });
+}
+
+/// Start capturing a new key for the clicked action, or close the panel.
+pub fn controls_button_system(
+    binding_query: Query<(&Interaction, &ControlsBindingButton), Changed<Interaction>>,
+    close_query: Query<&Interaction, (Changed<Interaction>, With<ControlsCloseButton>)>,
+    mut panel_state: ResMut<ControlsPanelState>,
+) {
+    for (interaction, button) in &binding_query {
+        if *interaction == Interaction::Pressed {
+            panel_state.capturing = Some(button.action.clone());
+        }
+    }
+
+    for interaction in &close_query {
+        if *interaction == Interaction::Pressed {
+            panel_state.open = false;
+            panel_state.capturing = None;
+        }
+    }
+}
+
+/// Bind the next pressed key to the action being captured and save the
+/// bindings to [`USER_BINDINGS_PATH`].
+pub fn controls_capture_system(
+    keyboard: Res<ButtonInput<KeyCode>>,
+    mut panel_state: ResMut<ControlsPanelState>,
+    mut bindings: ResMut<KeyBindings>,
+) {
+    let Some(action) = panel_state.capturing.clone() else {
+        return;
+    };
+    let Some(&key) = keyboard.get_just_pressed().next() else {
+        return;
+    };
+
+    bindings.rebind(&action, key);
+    bindings.warn_duplicates();
+    panel_state.capturing = None;
+
+    match save_key_bindings(Path::new(USER_BINDINGS_PATH), &bindings) {
+        Ok(()) => info!("Bound {action} to {key:?}"),
+        Err(err) => warn!("Failed to save key bindings: {err}"),
+    }
+}
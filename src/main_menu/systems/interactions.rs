@@ -1,8 +1,16 @@
+use std::path::Path;
+
 use bevy::{ecs::message::MessageWriter, prelude::*};
 
-use crate::main_menu::GameState;
+use crate::keybindings::{KeyBindings, actions};
 use crate::main_menu::colors;
 use crate::main_menu::components::MenuButton;
+use crate::main_menu::state::{
+    ControlsPanelState, MenuModalState, SaveBrowserState, SettingsPanelState,
+};
+use crate::main_menu::{GameState, reset_session};
+use crate::save_load::{DEFAULT_SAVE_PATH, SAVES_DIR, list_saves, save_game};
+use crate::screen_transition::TransitionToState;
 
 /// Handles button interaction visual feedback.
 pub fn button_system(
@@ -33,14 +41,17 @@ pub fn button_system(
 pub fn menu_action_system(
     interaction_query: Query<(&Interaction, &MenuButton), (Changed<Interaction>, With<Button>)>,
     keyboard: Res<ButtonInput<KeyCode>>,
-    mut next_state: ResMut<NextState<GameState>>,
+    bindings: Res<KeyBindings>,
+    mut controls: ResMut<ControlsPanelState>,
+    mut transitions: MessageWriter<TransitionToState>,
     mut exit_events: MessageWriter<AppExit>,
+    mut commands: Commands,
 ) {
     // Keyboard shortcuts
     let alt_pressed = keyboard.pressed(KeyCode::AltLeft) || keyboard.pressed(KeyCode::AltRight);
 
-    if alt_pressed {
-        if keyboard.just_pressed(KeyCode::KeyX) {
+    if alt_pressed && controls.capturing.is_none() {
+        if bindings.just_pressed(&keyboard, actions::QUIT) {
             exit_events.write(AppExit::Success);
         }
     }
@@ -51,7 +62,41 @@ pub fn menu_action_system(
             match button {
                 MenuButton::NewGame => {
                     info!("Starting new game...");
-                    next_state.set(GameState::PlanetView);
+                    // Drop the previous session; the planet view generates a new one.
+                    reset_session(&mut commands);
+                    transitions.write(TransitionToState(GameState::PlanetView));
+                }
+                MenuButton::SaveGame => {
+                    // Saving needs read access to every game resource.
+                    commands.queue(|world: &mut World| {
+                        let path = Path::new(DEFAULT_SAVE_PATH);
+                        match save_game(path, world) {
+                            Ok(()) => info!("Game saved to {}", path.display()),
+                            Err(err) => {
+                                warn!("Failed to save game: {err}");
+                                world
+                                    .resource_mut::<MenuModalState>()
+                                    .show(format!("Could not save the game.\n{err}"));
+                            }
+                        }
+                    });
+                }
+                MenuButton::LoadGame => {
+                    // Rescan on every open so new and deleted saves show up.
+                    commands.insert_resource(SaveBrowserState {
+                        open: true,
+                        slots: list_saves(Path::new(SAVES_DIR)),
+                        confirm_delete: None,
+                    });
+                }
+                MenuButton::Controls => {
+                    controls.open = true;
+                }
+                MenuButton::Settings => {
+                    commands.insert_resource(SettingsPanelState { open: true });
+                }
+                MenuButton::Mods => {
+                    transitions.write(TransitionToState(GameState::ModManager));
                 }
                 MenuButton::Exit => {
                     exit_events.write(AppExit::Success);
@@ -2,10 +2,21 @@ use bevy::core_pipeline::core_2d::graph::Core2d;
 use bevy::render::camera::CameraRenderGraph;
 use bevy::{ecs::hierarchy::ChildSpawnerCommands, prelude::*};
 
+use crate::data_types::ModLoadWarnings;
+use crate::keybindings::{KeyBindings, actions, key_label};
 use crate::main_menu::colors;
 use crate::main_menu::components::{MainMenuRoot, MenuButton};
 
-pub fn setup_main_menu(mut commands: Commands) {
+pub fn setup_main_menu(
+    mut commands: Commands,
+    mod_warnings: Option<Res<ModLoadWarnings>>,
+    bindings: Res<KeyBindings>,
+) {
+    let mods_had_errors = mod_warnings.is_some_and(|warnings| warnings.has_warnings());
+    let quit_shortcut = bindings
+        .key(actions::QUIT)
+        .map(|key| format!("Alt-{}", key_label(key)));
+
     // Camera for the menu
     commands.spawn((
         Camera2d::default(),
@@ -74,7 +85,12 @@ pub fn setup_main_menu(mut commands: Commands) {
                 ))
                 .with_children(|menu| {
                     spawn_menu_button(menu, "New Game", MenuButton::NewGame, None);
-                    spawn_menu_button(menu, "Exit", MenuButton::Exit, Some("Alt-X"));
+                    spawn_menu_button(menu, "Save Game", MenuButton::SaveGame, None);
+                    spawn_menu_button(menu, "Load Game", MenuButton::LoadGame, None);
+                    spawn_menu_button(menu, "Controls", MenuButton::Controls, None);
+                    spawn_menu_button(menu, "Settings", MenuButton::Settings, None);
+                    spawn_menu_button(menu, "Mods", MenuButton::Mods, None);
+                    spawn_menu_button(menu, "Exit", MenuButton::Exit, quit_shortcut.as_deref());
                 });
 
             // Version info at bottom
@@ -91,6 +107,22 @@ pub fn setup_main_menu(mut commands: Commands) {
                     ..default()
                 },
             ));
+
+            if mods_had_errors {
+                parent.spawn((
+                    Text::new("Some mods had errors (see log)"),
+                    TextFont {
+                        font_size: 16.0,
+                        ..default()
+                    },
+                    TextColor(colors::WARNING_TEXT),
+                    Node {
+                        position_type: PositionType::Absolute,
+                        bottom: Val::Px(44.0),
+                        ..default()
+                    },
+                ));
+            }
         });
 }
 
@@ -2,11 +2,19 @@ use bevy::prelude::*;
 
 use super::components::MainMenuRoot;
 
+mod controls;
 mod interactions;
 mod layout;
+mod modal;
+mod save_browser;
+mod settings;
 
+pub use controls::{controls_button_system, controls_capture_system, controls_panel_system};
 pub use interactions::{button_system, menu_action_system};
 pub use layout::setup_main_menu;
+pub use modal::{menu_modal_button_system, menu_modal_system};
+pub use save_browser::{save_browser_button_system, save_browser_panel_system};
+pub use settings::{settings_button_system, settings_panel_system};
 
 pub fn cleanup_main_menu(mut commands: Commands, query: Query<Entity, With<MainMenuRoot>>) {
     for entity in &query {
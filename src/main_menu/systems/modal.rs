@@ -0,0 +1,104 @@
+use bevy::prelude::*;
+
+use crate::main_menu::colors;
+use crate::main_menu::components::{MainMenuRoot, MenuModal, MenuModalButton};
+use crate::main_menu::state::MenuModalState;
+
+/// Spawn or despawn the message modal based on [`MenuModalState`].
+pub fn menu_modal_system(
+    mut commands: Commands,
+    modal_state: Res<MenuModalState>,
+    modal_query: Query<Entity, With<MenuModal>>,
+) {
+    if !modal_state.is_changed() {
+        return;
+    }
+
+    for entity in &modal_query {
+        commands.entity(entity).despawn();
+    }
+
+    let Some(message) = &modal_state.message else {
+        return;
+    };
+
+    commands
+        .spawn((
+            Node {
+                width: Val::Percent(100.0),
+                height: Val::Percent(100.0),
+                position_type: PositionType::Absolute,
+                justify_content: JustifyContent::Center,
+                align_items: AlignItems::Center,
+                ..default()
+            },
+            BackgroundColor(Color::srgba(0.0, 0.0, 0.0, 0.5)),
+            GlobalZIndex(100),
+            MenuModal,
+            MainMenuRoot,
+        ))
+        .with_children(|overlay| {
+            overlay
+                .spawn((
+                    Node {
+                        width: Val::Px(420.0),
+                        flex_direction: FlexDirection::Column,
+                        align_items: AlignItems::Center,
+                        padding: UiRect::all(Val::Px(24.0)),
+                        border: UiRect::all(Val::Px(3.0)),
+                        row_gap: Val::Px(20.0),
+                        ..default()
+                    },
+                    BackgroundColor(colors::BACKGROUND_DARK),
+                    BorderColor::all(colors::BUTTON_BORDER),
+                ))
+                .with_children(|panel| {
+                    panel.spawn((
+                        Text::new(message.clone()),
+                        TextFont {
+                            font_size: 18.0,
+                            ..default()
+                        },
+                        TextColor(colors::BUTTON_TEXT),
+                    ));
+
+                    panel
+                        .spawn((
+                            Button,
+                            Node {
+                                width: Val::Px(120.0),
+                                height: Val::Px(40.0),
+                                border: UiRect::all(Val::Px(2.0)),
+                                justify_content: JustifyContent::Center,
+                                align_items: AlignItems::Center,
+                                ..default()
+                            },
+                            BackgroundColor(colors::BUTTON_NORMAL),
+                            BorderColor::all(colors::BUTTON_BORDER),
+                            MenuModalButton,
+                        ))
+                        .with_children(|button| {
+                            button.spawn((
+                                Text::new("OK"),
+                                TextFont {
+                                    font_size: 18.0,
+                                    ..default()
+                                },
+                                TextColor(colors::BUTTON_TEXT),
+                            ));
+                        });
+                });
+        });
+}
+
+/// Close the message modal when its OK button is pressed.
+pub fn menu_modal_button_system(
+    interaction_query: Query<&Interaction, (Changed<Interaction>, With<MenuModalButton>)>,
+    mut modal_state: ResMut<MenuModalState>,
+) {
+    for interaction in &interaction_query {
+        if *interaction == Interaction::Pressed {
+            modal_state.hide();
+        }
+    }
+}
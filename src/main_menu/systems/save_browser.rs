@@ -0,0 +1,284 @@
+use std::path::Path;
+
+use bevy::{ecs::hierarchy::ChildSpawnerCommands, prelude::*};
+
+use crate::main_menu::colors;
+use crate::main_menu::components::{MainMenuRoot, SaveBrowserButton, SaveBrowserPanel};
+use crate::main_menu::state::{MenuModalState, SaveBrowserState};
+use crate::save_load::{SAVES_DIR, SaveSlot, delete_save, list_saves, load_game};
+
+/// Spawn, rebuild, or despawn the save browser to match [`SaveBrowserState`].
+pub fn save_browser_panel_system(
+    mut commands: Commands,
+    browser: Res<SaveBrowserState>,
+    panel_query: Query<Entity, With<SaveBrowserPanel>>,
+) {
+    if !browser.is_changed() {
+        return;
+    }
+
+    for entity in &panel_query {
+        commands.entity(entity).despawn();
+    }
+
+    if !browser.open {
+        return;
+    }
+
+    commands
+        .spawn((
+            Node {
+                width: Val::Percent(100.0),
+                height: Val::Percent(100.0),
+                position_type: PositionType::Absolute,
+                justify_content: JustifyContent::Center,
+                align_items: AlignItems::Center,
+                ..default()
+            },
+            BackgroundColor(Color::srgba(0.0, 0.0, 0.0, 0.5)),
+            // Below the message modal so load errors show on top.
+            GlobalZIndex(90),
+            SaveBrowserPanel,
+            MainMenuRoot,
+        ))
+        .with_children(|overlay| {
+            overlay
+                .spawn((
+                    Node {
+                        width: Val::Px(620.0),
+                        flex_direction: FlexDirection::Column,
+                        align_items: AlignItems::Center,
+                        padding: UiRect::all(Val::Px(24.0)),
+                        border: UiRect::all(Val::Px(3.0)),
+                        row_gap: Val::Px(8.0),
+                        ..default()
+                    },
+                    BackgroundColor(colors::BACKGROUND_DARK),
+                    BorderColor::all(colors::BUTTON_BORDER),
+                ))
+                .with_children(|panel| {
+                    panel.spawn((
+                        Text::new("Load Game"),
+                        TextFont {
+                            font_size: 26.0,
+                            ..default()
+                        },
+                        TextColor(colors::TITLE_TEXT),
+                    ));
+
+                    if browser.slots.is_empty() {
+                        spawn_section_label(panel, "No saved games");
+                    }
+
+                    // The autosave gets its own section above the player's saves.
+                    let (autosaves, saves): (Vec<&SaveSlot>, Vec<&SaveSlot>) =
+                        browser.slots.iter().partition(|slot| slot.is_autosave());
+                    for (label, slots) in [("Autosave", autosaves), ("Saved games", saves)] {
+                        if slots.is_empty() {
+                            continue;
+                        }
+                        spawn_section_label(panel, label);
+                        for slot in slots {
+                            if browser.confirm_delete.as_ref() == Some(&slot.path) {
+                                spawn_delete_confirmation(panel, slot);
+                            } else {
+                                spawn_slot_row(panel, slot);
+                            }
+                        }
+                    }
+
+                    spawn_browser_button(panel, "Back", 160.0, SaveBrowserButton::Close);
+                });
+        });
+}
+
+fn spawn_section_label(parent: &mut ChildSpawnerCommands, label: &str) {
+    parent.spawn((
+        Text::new(label),
+        TextFont {
+            font_size: 18.0,
+            ..default()
+        },
+        TextColor(colors::BUTTON_TEXT),
+    ));
+}
+
+fn spawn_slot_row(parent: &mut ChildSpawnerCommands, slot: &SaveSlot) {
+    let metadata = &slot.metadata;
+    let species = if metadata.species_name.is_empty() {
+        "Unknown species"
+    } else {
+        metadata.species_name.as_str()
+    };
+    let date = if metadata.timestamp.is_empty() {
+        "Unknown date"
+    } else {
+        metadata.timestamp.as_str()
+    };
+
+    parent
+        .spawn(Node {
+            width: Val::Percent(100.0),
+            align_items: AlignItems::Center,
+            column_gap: Val::Px(12.0),
+            ..default()
+        })
+        .with_children(|row| {
+            // Placeholder until saves carry a screenshot.
+            row.spawn((
+                Node {
+                    width: Val::Px(48.0),
+                    height: Val::Px(36.0),
+                    border: UiRect::all(Val::Px(2.0)),
+                    ..default()
+                },
+                BackgroundColor(colors::BACKGROUND),
+                BorderColor::all(colors::BUTTON_BORDER),
+            ));
+            row.spawn(Node {
+                flex_grow: 1.0,
+                flex_direction: FlexDirection::Column,
+                ..default()
+            })
+            .with_children(|details| {
+                details.spawn((
+                    Text::new(format!("{} - {species}", slot.name())),
+                    TextFont {
+                        font_size: 18.0,
+                        ..default()
+                    },
+                    TextColor(colors::BUTTON_TEXT),
+                ));
+                details.spawn((
+                    Text::new(format!("Turn {} - {date}", metadata.turn)),
+                    TextFont {
+                        font_size: 14.0,
+                        ..default()
+                    },
+                    TextColor(colors::SUBTITLE_TEXT),
+                ));
+            });
+            spawn_browser_button(
+                row,
+                "Load",
+                90.0,
+                SaveBrowserButton::Load(slot.path.clone()),
+            );
+            spawn_browser_button(
+                row,
+                "Delete",
+                90.0,
+                SaveBrowserButton::Delete(slot.path.clone()),
+            );
+        });
+}
+
+fn spawn_delete_confirmation(parent: &mut ChildSpawnerCommands, slot: &SaveSlot) {
+    parent
+        .spawn(Node {
+            width: Val::Percent(100.0),
+            align_items: AlignItems::Center,
+            column_gap: Val::Px(12.0),
+            ..default()
+        })
+        .with_children(|row| {
+            row.spawn((
+                Node {
+                    flex_grow: 1.0,
+                    ..default()
+                },
+                Text::new(format!("Delete {}?", slot.name())),
+                TextFont {
+                    font_size: 18.0,
+                    ..default()
+                },
+                TextColor(colors::WARNING_TEXT),
+            ));
+            spawn_browser_button(row, "Delete", 90.0, SaveBrowserButton::ConfirmDelete);
+            spawn_browser_button(row, "Cancel", 90.0, SaveBrowserButton::CancelDelete);
+        });
+}
+
+fn spawn_browser_button(
+    parent: &mut ChildSpawnerCommands,
+    label: &str,
+    width: f32,
+    action: SaveBrowserButton,
+) {
+    parent
+        .spawn((
+            Button,
+            Node {
+                width: Val::Px(width),
+                height: Val::Px(34.0),
+                border: UiRect::all(Val::Px(2.0)),
+                justify_content: JustifyContent::Center,
+                align_items: AlignItems::Center,
+                ..default()
+            },
+            BackgroundColor(colors::BUTTON_NORMAL),
+            BorderColor::all(colors::BUTTON_BORDER),
+            action,
+        ))
+        .with_children(|button| {
+            button.spawn((
+                Text::new(label),
+                TextFont {
+                    font_size: 16.0,
+                    ..default()
+                },
+                TextColor(colors::BUTTON_TEXT),
+            ));
+        });
+}
+
+/// Load, delete, or close in response to the save browser buttons.
+pub fn save_browser_button_system(
+    interaction_query: Query<(&Interaction, &SaveBrowserButton), Changed<Interaction>>,
+    mut browser: ResMut<SaveBrowserState>,
+    mut modal_state: ResMut<MenuModalState>,
+    mut commands: Commands,
+) {
+    for (interaction, button) in &interaction_query {
+        if *interaction != Interaction::Pressed {
+            continue;
+        }
+        match button {
+            SaveBrowserButton::Load(path) => match load_game(path) {
+                Ok(save) => {
+                    info!("Loading game from {}", path.display());
+                    browser.open = false;
+                    browser.confirm_delete = None;
+                    commands.queue(move |world: &mut World| save.restore(world));
+                }
+                Err(err) => {
+                    warn!("Failed to load game: {err}");
+                    modal_state.show(format!("Could not load the game.\n{err}"));
+                }
+            },
+            SaveBrowserButton::Delete(path) => {
+                browser.confirm_delete = Some(path.clone());
+            }
+            SaveBrowserButton::ConfirmDelete => {
+                let Some(path) = browser.confirm_delete.take() else {
+                    continue;
+                };
+                match delete_save(&path) {
+                    Ok(()) => info!("Deleted save {}", path.display()),
+                    Err(err) => {
+                        warn!("Failed to delete save: {err}");
+                        modal_state.show(format!("Could not delete the save.\n{err}"));
+                    }
+                }
+                browser.slots = list_saves(Path::new(SAVES_DIR));
+            }
+            SaveBrowserButton::CancelDelete => {
+                browser.confirm_delete = None;
+            }
+            SaveBrowserButton::Close => {
+                browser.open = false;
+                browser.confirm_delete = None;
+            }
+        }
+    }
+}
@@ -0,0 +1,187 @@
+use bevy::{ecs::hierarchy::ChildSpawnerCommands, prelude::*};
+
+use crate::main_menu::colors;
+use crate::main_menu::components::{MainMenuRoot, SettingsButton, SettingsPanel};
+use crate::main_menu::state::SettingsPanelState;
+use crate::settings::GameSettings;
+
+/// Change of the UI size per button press.
+const FONT_SIZE_STEP: f32 = 0.25;
+/// Change of the volume per button press.
+const VOLUME_STEP: f32 = 0.1;
+
+/// Spawn, rebuild, or despawn the Settings panel to match
+/// [`SettingsPanelState`] and the current [`GameSettings`].
+pub fn settings_panel_system(
+    mut commands: Commands,
+    panel_state: Res<SettingsPanelState>,
+    settings: Res<GameSettings>,
+    panel_query: Query<Entity, With<SettingsPanel>>,
+) {
+    if !panel_state.is_changed() && !settings.is_changed() {
+        return;
+    }
+
+    for entity in &panel_query {
+        commands.entity(entity).despawn();
+    }
+
+    if !panel_state.open {
+        return;
+    }
+
+    commands
+        .spawn((
+            Node {
+                width: Val::Percent(100.0),
+                height: Val::Percent(100.0),
+                position_type: PositionType::Absolute,
+                justify_content: JustifyContent::Center,
+                align_items: AlignItems::Center,
+                ..default()
+            },
+            BackgroundColor(Color::srgba(0.0, 0.0, 0.0, 0.5)),
+            GlobalZIndex(100),
+            SettingsPanel,
+            MainMenuRoot,
+        ))
+        .with_children(|overlay| {
+            overlay
+                .spawn((
+                    Node {
+                        width: Val::Px(460.0),
+                        flex_direction: FlexDirection::Column,
+                        align_items: AlignItems::Center,
+                        padding: UiRect::all(Val::Px(24.0)),
+                        border: UiRect::all(Val::Px(3.0)),
+                        row_gap: Val::Px(8.0),
+                        ..default()
+                    },
+                    BackgroundColor(colors::BACKGROUND_DARK),
+                    BorderColor::all(colors::BUTTON_BORDER),
+                ))
+                .with_children(|panel| {
+                    panel.spawn((
+                        Text::new("Settings"),
+                        TextFont {
+                            font_size: 26.0,
+                            ..default()
+                        },
+                        TextColor(colors::TITLE_TEXT),
+                    ));
+
+                    spawn_setting_row(
+                        panel,
+                        format!("UI size: {}%", percent(settings.font_size_multiplier)),
+                        SettingsButton::FontSmaller,
+                        SettingsButton::FontLarger,
+                    );
+                    spawn_setting_row(
+                        panel,
+                        format!("Volume: {}%", percent(settings.audio_volume)),
+                        SettingsButton::VolumeDown,
+                        SettingsButton::VolumeUp,
+                    );
+
+                    spawn_panel_button(panel, "Back", 160.0, SettingsButton::Close);
+                });
+        });
+}
+
+fn percent(value: f32) -> i32 {
+    (value * 100.0).round() as i32
+}
+
+/// A label with "-" and "+" buttons to adjust it.
+fn spawn_setting_row(
+    parent: &mut ChildSpawnerCommands,
+    label: String,
+    decrease: SettingsButton,
+    increase: SettingsButton,
+) {
+    parent
+        .spawn(Node {
+            width: Val::Percent(100.0),
+            justify_content: JustifyContent::SpaceBetween,
+            align_items: AlignItems::Center,
+            column_gap: Val::Px(8.0),
+            ..default()
+        })
+        .with_children(|row| {
+            row.spawn((
+                Node {
+                    flex_grow: 1.0,
+                    ..default()
+                },
+                Text::new(label),
+                TextFont {
+                    font_size: 18.0,
+                    ..default()
+                },
+                TextColor(colors::BUTTON_TEXT),
+            ));
+            spawn_panel_button(row, "-", 44.0, decrease);
+            spawn_panel_button(row, "+", 44.0, increase);
+        });
+}
+
+fn spawn_panel_button(
+    parent: &mut ChildSpawnerCommands,
+    label: &str,
+    width: f32,
+    action: SettingsButton,
+) {
+    parent
+        .spawn((
+            Button,
+            Node {
+                width: Val::Px(width),
+                height: Val::Px(34.0),
+                border: UiRect::all(Val::Px(2.0)),
+                justify_content: JustifyContent::Center,
+                align_items: AlignItems::Center,
+                ..default()
+            },
+            BackgroundColor(colors::BUTTON_NORMAL),
+            BorderColor::all(colors::BUTTON_BORDER),
+            action,
+        ))
+        .with_children(|button| {
+            button.spawn((
+                Text::new(label),
+                TextFont {
+                    font_size: 16.0,
+                    ..default()
+                },
+                TextColor(colors::BUTTON_TEXT),
+            ));
+        });
+}
+
+/// Adjust [`GameSettings`] or close the panel in response to its buttons.
+///
+/// The settings plugin applies and saves every change.
+pub fn settings_button_system(
+    interaction_query: Query<(&Interaction, &SettingsButton), Changed<Interaction>>,
+    mut panel_state: ResMut<SettingsPanelState>,
+    mut settings: ResMut<GameSettings>,
+) {
+    for (interaction, button) in &interaction_query {
+        if *interaction != Interaction::Pressed {
+            continue;
+        }
+        let mut adjusted = *settings;
+        match button {
+            SettingsButton::FontSmaller => adjusted.font_size_multiplier -= FONT_SIZE_STEP,
+            SettingsButton::FontLarger => adjusted.font_size_multiplier += FONT_SIZE_STEP,
+            SettingsButton::VolumeDown => adjusted.audio_volume -= VOLUME_STEP,
+            SettingsButton::VolumeUp => adjusted.audio_volume += VOLUME_STEP,
+            SettingsButton::Close => {
+                panel_state.open = false;
+                continue;
+            }
+        }
+        // Pressing "+" at the maximum is not a change worth saving.
+        settings.set_if_neq(adjusted.clamped());
+    }
+}
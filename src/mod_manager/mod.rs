@@ -0,0 +1,50 @@
+//! Mod manager screen.
+//!
+//! Lists every mod found in `assets/mods/` (see [`LoadedMods`]) with what
+//! it adds and overrides, marking mods that conflict with each other. A
+//! second tab lists the conflicts and other problems from
+//! [`ModLoadWarnings`]. Mods can be disabled or re-enabled; "Apply &
+//! Reload" then loads the game data again without them.
+//!
+//! # Module Structure
+//!
+//! - [`setup`] - UI spawning/cleanup and rebuilding of the mod list
+//! - [`systems`] - Button handling and scrolling
+//! - [`types`] - Components and resources
+//!
+//! [`LoadedMods`]: crate::data_types::LoadedMods
+//! [`ModLoadWarnings`]: crate::data_types::ModLoadWarnings
+
+mod setup;
+mod systems;
+pub mod types;
+
+use bevy::prelude::*;
+
+use crate::main_menu::GameState;
+use crate::mod_manager::types::ModManagerTab;
+
+/// Plugin that manages the mod manager screen.
+///
+/// Systems only run when the game is in `GameState::ModManager`.
+pub struct ModManagerPlugin;
+
+impl Plugin for ModManagerPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<ModManagerTab>()
+            .add_systems(OnEnter(GameState::ModManager), setup::setup_mod_manager)
+            .add_systems(OnExit(GameState::ModManager), setup::cleanup_mod_manager)
+            .add_systems(
+                Update,
+                (
+                    systems::mod_manager_action_system,
+                    systems::mod_manager_button_style_system,
+                    systems::mod_manager_scroll_system,
+                    systems::mod_manager_keyboard_system,
+                    setup::update_mod_manager_content,
+                )
+                    .chain()
+                    .run_if(in_state(GameState::ModManager)),
+            );
+    }
+}
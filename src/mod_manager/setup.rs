@@ -0,0 +1,287 @@
+//! Spawning and despawning of the mod manager screen.
+
+use bevy::{ecs::hierarchy::ChildSpawnerCommands, prelude::*};
+
+use crate::data_types::{DisabledMods, LoadedMods, ModLoadWarnings, ModMetadata};
+use crate::mod_manager::types::{
+    ModManagerAction, ModManagerContent, ModManagerRoot, ModManagerScroll, ModManagerTab,
+};
+
+/// Screen background.
+const BACKGROUND: Color = Color::srgb(0.06, 0.08, 0.12);
+/// Background of rows and panels.
+pub(super) const PANEL_BG: Color = Color::srgb(0.1, 0.13, 0.19);
+/// Button background.
+pub(super) const BUTTON_NORMAL: Color = Color::srgb(0.08, 0.12, 0.20);
+/// Button background on hover.
+pub(super) const BUTTON_HOVERED: Color = Color::srgb(0.12, 0.18, 0.28);
+/// Button background while pressed, and of the selected tab.
+pub(super) const BUTTON_PRESSED: Color = Color::srgb(0.16, 0.24, 0.36);
+/// Borders.
+const BORDER: Color = Color::srgb(0.2, 0.5, 0.6);
+/// Regular text.
+const TEXT: Color = Color::srgb(0.7, 0.85, 0.9);
+/// Secondary text.
+const DIM_TEXT: Color = Color::srgb(0.45, 0.55, 0.6);
+/// Status icon of a mod that loaded cleanly.
+const OK_COLOR: Color = Color::srgb(0.2, 0.75, 0.3);
+/// Status icon of a mod with conflicts, and warning text.
+const WARNING_COLOR: Color = Color::srgb(0.85, 0.25, 0.2);
+
+/// Spawn the mod manager screen.
+///
+/// # Layout
+/// ```text
+/// ┌──────────────────────────────────────────────┐
+/// │ Mods                     [Mods] [Conflicts]  │
+/// │ ┌──────────────────────────────────────────┐ │
+/// │ │ [OK] Better Farms 1.0  +3 / ~1 [Disable] │ │
+/// │ │ [!]  Big Techs 0.2     +5 / ~2 [Disable] │ │
+/// │ └──────────────────────────────────────────┘ │
+/// │               [Apply & Reload] [Back]        │
+/// └──────────────────────────────────────────────┘
+/// ```
+///
+/// The list itself is filled in by [`update_mod_manager_content`].
+pub fn setup_mod_manager(mut commands: Commands, mut tab: ResMut<ModManagerTab>) {
+    // Also marks the tab as changed so the list is built on the first frame.
+    *tab = ModManagerTab::Mods;
+
+    commands.spawn((Camera2d::default(), ModManagerRoot));
+
+    commands
+        .spawn((
+            Node {
+                width: Val::Percent(100.0),
+                height: Val::Percent(100.0),
+                flex_direction: FlexDirection::Column,
+                padding: UiRect::all(Val::Px(30.0)),
+                row_gap: Val::Px(16.0),
+                ..default()
+            },
+            BackgroundColor(BACKGROUND),
+            ModManagerRoot,
+        ))
+        .with_children(|root| {
+            root.spawn(Node {
+                justify_content: JustifyContent::SpaceBetween,
+                align_items: AlignItems::Center,
+                ..default()
+            })
+            .with_children(|header| {
+                header.spawn((
+                    Text::new("Mods"),
+                    TextFont {
+                        font_size: 32.0,
+                        ..default()
+                    },
+                    TextColor(TEXT),
+                ));
+                header
+                    .spawn(Node {
+                        column_gap: Val::Px(8.0),
+                        ..default()
+                    })
+                    .with_children(|tabs| {
+                        spawn_button(tabs, "Mods", ModManagerAction::ShowMods);
+                        spawn_button(tabs, "Conflicts", ModManagerAction::ShowConflicts);
+                    });
+            });
+
+            root.spawn((
+                Node {
+                    flex_grow: 1.0,
+                    flex_direction: FlexDirection::Column,
+                    border: UiRect::all(Val::Px(2.0)),
+                    overflow: Overflow::scroll_y(),
+                    padding: UiRect::all(Val::Px(12.0)),
+                    row_gap: Val::Px(8.0),
+                    ..default()
+                },
+                BorderColor::all(BORDER),
+                ScrollPosition::default(),
+                ModManagerContent,
+                ModManagerScroll,
+            ));
+
+            root.spawn(Node {
+                justify_content: JustifyContent::FlexEnd,
+                column_gap: Val::Px(8.0),
+                ..default()
+            })
+            .with_children(|footer| {
+                spawn_button(footer, "Apply & Reload", ModManagerAction::ApplyAndReload);
+                spawn_button(footer, "Back", ModManagerAction::Back);
+            });
+        });
+}
+
+/// Despawn every entity of the mod manager screen.
+pub fn cleanup_mod_manager(mut commands: Commands, query: Query<Entity, With<ModManagerRoot>>) {
+    for entity in &query {
+        commands.entity(entity).despawn();
+    }
+}
+
+/// Rebuild the list when the tab, the loaded mods or their warnings, or
+/// the selection of disabled mods changes.
+pub fn update_mod_manager_content(
+    mut commands: Commands,
+    tab: Res<ModManagerTab>,
+    loaded: Res<LoadedMods>,
+    disabled: Res<DisabledMods>,
+    warnings: Res<ModLoadWarnings>,
+    content_query: Query<Entity, With<ModManagerContent>>,
+) {
+    if !tab.is_changed() && !loaded.is_changed() && !disabled.is_changed() && !warnings.is_changed()
+    {
+        return;
+    }
+    let Ok(content) = content_query.single() else {
+        return;
+    };
+
+    commands
+        .entity(content)
+        .despawn_related::<Children>()
+        .with_children(|list| match *tab {
+            ModManagerTab::Mods => {
+                if loaded.mods.is_empty() {
+                    spawn_line(list, "No mods installed in assets/mods/", DIM_TEXT);
+                }
+                for metadata in &loaded.mods {
+                    let pending_disable = disabled.disabled_mods.contains(&metadata.name);
+                    spawn_mod_row(list, metadata, pending_disable);
+                }
+                if loaded
+                    .mods
+                    .iter()
+                    .any(|m| m.disabled != disabled.disabled_mods.contains(&m.name))
+                {
+                    spawn_line(list, "Apply & Reload to use the new selection.", TEXT);
+                }
+            }
+            ModManagerTab::Conflicts => {
+                if !warnings.has_warnings() {
+                    spawn_line(list, "No conflicts", DIM_TEXT);
+                }
+                for conflict in &warnings.conflicts {
+                    let line = format!(
+                        "{} '{}': '{}' replaces '{}'",
+                        conflict.kind, conflict.entity_id, conflict.second_mod, conflict.first_mod
+                    );
+                    spawn_line(list, &line, WARNING_COLOR);
+                }
+                for warning in warnings.other_warnings() {
+                    spawn_line(list, warning, WARNING_COLOR);
+                }
+            }
+        });
+}
+
+/// One mod: status icon, name, what it changes, and the Disable toggle.
+fn spawn_mod_row(parent: &mut ChildSpawnerCommands, metadata: &ModMetadata, pending_disable: bool) {
+    let (icon, icon_color) = if metadata.disabled {
+        ("-", DIM_TEXT)
+    } else if metadata.has_conflicts || !metadata.missing_dependencies.is_empty() {
+        ("!", WARNING_COLOR)
+    } else {
+        ("OK", OK_COLOR)
+    };
+    let details = if metadata.disabled {
+        format!("#{} - disabled", metadata.priority + 1)
+    } else if !metadata.missing_dependencies.is_empty() {
+        format!(
+            "#{} - skipped, needs {}",
+            metadata.priority + 1,
+            metadata.missing_dependencies.join(", ")
+        )
+    } else {
+        format!(
+            "#{} - {} added, {} overridden",
+            metadata.priority + 1,
+            metadata.entities_added,
+            metadata.entities_overridden
+        )
+    };
+
+    parent
+        .spawn((
+            Node {
+                align_items: AlignItems::Center,
+                column_gap: Val::Px(12.0),
+                padding: UiRect::all(Val::Px(8.0)),
+                ..default()
+            },
+            BackgroundColor(PANEL_BG),
+        ))
+        .with_children(|row| {
+            row.spawn((
+                Node {
+                    width: Val::Px(36.0),
+                    height: Val::Px(28.0),
+                    justify_content: JustifyContent::Center,
+                    align_items: AlignItems::Center,
+                    border: UiRect::all(Val::Px(2.0)),
+                    ..default()
+                },
+                BorderColor::all(icon_color),
+            ))
+            .with_children(|badge| {
+                badge.spawn((Text::new(icon), TextColor(icon_color)));
+            });
+
+            row.spawn(Node {
+                flex_grow: 1.0,
+                flex_direction: FlexDirection::Column,
+                ..default()
+            })
+            .with_children(|text| {
+                text.spawn((
+                    Text::new(format!("{} {}", metadata.name, metadata.version)),
+                    TextFont {
+                        font_size: 18.0,
+                        ..default()
+                    },
+                    TextColor(TEXT),
+                ));
+                text.spawn((
+                    Text::new(details),
+                    TextFont {
+                        font_size: 14.0,
+                        ..default()
+                    },
+                    TextColor(DIM_TEXT),
+                ));
+            });
+
+            let label = if pending_disable { "Enable" } else { "Disable" };
+            spawn_button(
+                row,
+                label,
+                ModManagerAction::ToggleMod(metadata.name.clone()),
+            );
+        });
+}
+
+fn spawn_line(parent: &mut ChildSpawnerCommands, text: &str, color: Color) {
+    parent.spawn((Text::new(text), TextColor(color)));
+}
+
+fn spawn_button(parent: &mut ChildSpawnerCommands, label: &str, action: ModManagerAction) {
+    parent
+        .spawn((
+            Button,
+            Node {
+                padding: UiRect::axes(Val::Px(16.0), Val::Px(8.0)),
+                border: UiRect::all(Val::Px(2.0)),
+                ..default()
+            },
+            BackgroundColor(BUTTON_NORMAL),
+            BorderColor::all(BORDER),
+            action,
+        ))
+        .with_children(|button| {
+            button.spawn((Text::new(label), TextColor(TEXT)));
+        });
+}
@@ -0,0 +1,101 @@
+//! Button handling, styling, and scrolling for the mod manager screen.
+
+use std::path::Path;
+
+use bevy::input::mouse::{AccumulatedMouseScroll, MouseScrollUnit};
+use bevy::prelude::*;
+
+use crate::data_types::{DISABLED_MODS_PATH, DisabledMods, save_disabled_mods};
+use crate::game_data::ReloadGameData;
+use crate::keybindings::{KeyBindings, actions};
+use crate::main_menu::GameState;
+use crate::mod_manager::setup::{BUTTON_HOVERED, BUTTON_NORMAL, BUTTON_PRESSED};
+use crate::mod_manager::types::{ModManagerAction, ModManagerScroll, ModManagerTab};
+use crate::screen_transition::TransitionToState;
+
+/// Pixels scrolled per mouse wheel line.
+const SCROLL_LINE_PIXELS: f32 = 40.0;
+
+/// Handle the tab, toggle, Apply & Reload, and Back buttons.
+///
+/// Toggling a mod saves the disabled mods to [`DISABLED_MODS_PATH`] right
+/// away, so the choice also applies to the next launch.
+pub fn mod_manager_action_system(
+    query: Query<(&Interaction, &ModManagerAction), (Changed<Interaction>, With<Button>)>,
+    mut tab: ResMut<ModManagerTab>,
+    mut disabled: ResMut<DisabledMods>,
+    mut reload: MessageWriter<ReloadGameData>,
+    mut transitions: MessageWriter<TransitionToState>,
+) {
+    for (interaction, action) in &query {
+        if *interaction != Interaction::Pressed {
+            continue;
+        }
+        match action {
+            ModManagerAction::ShowMods => *tab = ModManagerTab::Mods,
+            ModManagerAction::ShowConflicts => *tab = ModManagerTab::Conflicts,
+            ModManagerAction::ToggleMod(name) => {
+                if !disabled.disabled_mods.remove(name) {
+                    disabled.disabled_mods.insert(name.clone());
+                }
+                if let Err(err) = save_disabled_mods(Path::new(DISABLED_MODS_PATH), &disabled) {
+                    warn!("Failed to save disabled mods: {err}");
+                }
+            }
+            ModManagerAction::ApplyAndReload => {
+                info!("Reloading game data with the selected mods");
+                reload.write(ReloadGameData);
+            }
+            ModManagerAction::Back => transitions.write(TransitionToState(GameState::MainMenu)),
+        }
+    }
+}
+
+/// Highlight hovered and pressed buttons and the selected tab.
+pub fn mod_manager_button_style_system(
+    mut query: Query<(&Interaction, &ModManagerAction, &mut BackgroundColor), With<Button>>,
+    tab: Res<ModManagerTab>,
+) {
+    for (interaction, action, mut bg_color) in &mut query {
+        let selected_tab = matches!(
+            (action, *tab),
+            (ModManagerAction::ShowMods, ModManagerTab::Mods)
+                | (ModManagerAction::ShowConflicts, ModManagerTab::Conflicts)
+        );
+        *bg_color = BackgroundColor(match interaction {
+            Interaction::Pressed => BUTTON_PRESSED,
+            _ if selected_tab => BUTTON_PRESSED,
+            Interaction::Hovered => BUTTON_HOVERED,
+            Interaction::None => BUTTON_NORMAL,
+        });
+    }
+}
+
+/// Scroll the list with the mouse wheel.
+pub fn mod_manager_scroll_system(
+    mouse_scroll: Res<AccumulatedMouseScroll>,
+    mut query: Query<&mut ScrollPosition, With<ModManagerScroll>>,
+) {
+    let delta = match mouse_scroll.unit {
+        MouseScrollUnit::Line => mouse_scroll.delta.y * SCROLL_LINE_PIXELS,
+        MouseScrollUnit::Pixel => mouse_scroll.delta.y,
+    };
+    if delta == 0.0 {
+        return;
+    }
+    for mut position in &mut query {
+        // Bevy clamps the offset to the content size during layout.
+        position.y = (position.y - delta).max(0.0);
+    }
+}
+
+/// Return to the main menu when the Back key (ESC by default) is pressed.
+pub fn mod_manager_keyboard_system(
+    keyboard: Res<ButtonInput<KeyCode>>,
+    bindings: Res<KeyBindings>,
+    mut transitions: MessageWriter<TransitionToState>,
+) {
+    if bindings.just_pressed(&keyboard, actions::BACK) {
+        transitions.write(TransitionToState(GameState::MainMenu));
+    }
+}
@@ -0,0 +1,42 @@
+//! Components and resources for the mod manager screen.
+
+use bevy::prelude::*;
+
+/// Marker component for all entities belonging to the mod manager screen.
+///
+/// Used for cleanup when leaving the screen.
+#[derive(Component)]
+pub struct ModManagerRoot;
+
+/// Container whose children are rebuilt when the mod list or tab changes.
+#[derive(Component)]
+pub struct ModManagerContent;
+
+/// Scrollable list inside [`ModManagerContent`].
+#[derive(Component)]
+pub struct ModManagerScroll;
+
+/// Buttons on the mod manager screen.
+#[derive(Component, Debug, Clone, PartialEq, Eq)]
+pub enum ModManagerAction {
+    /// Show the installed mods.
+    ShowMods,
+    /// Show the conflicts between mods.
+    ShowConflicts,
+    /// Enable or disable the mod with this name.
+    ToggleMod(String),
+    /// Reload the game data with the current selection of mods.
+    ApplyAndReload,
+    /// Return to the main menu.
+    Back,
+}
+
+/// Tab shown on the mod manager screen.
+#[derive(Resource, Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum ModManagerTab {
+    /// Installed mods with their status.
+    #[default]
+    Mods,
+    /// Conflicts and other mod loading problems.
+    Conflicts,
+}
@@ -4,8 +4,19 @@
 //! The generation is deterministic based on a seed value, allowing reproducible
 //! results for testing and saved games.
 
-use super::types::{BuildingType, PlanetSurface, TileColor};
+use super::types::{BuildingType, PlanetSurface, ResourceDepositKind, TileColor};
+use crate::data_types::{GenerationMode, Scenario};
 use rand::prelude::*;
+use std::collections::VecDeque;
+
+#[cfg(test)]
+mod tests;
+
+/// Extra attempts [`generate_planet`] makes to find a single-island surface.
+pub const MAX_ISLAND_RETRIES: u64 = 10;
+
+/// Chance that a tile hides a resource deposit.
+pub const DEPOSIT_CHANCE: f64 = 0.05;
 
 /// Generate a random planet surface for the MVP.
 ///
@@ -18,15 +29,41 @@ use rand::prelude::*;
 /// 2. Randomly assign each tile as White or Black (50/50 chance)
 /// 3. Ensure at least one White tile exists
 /// 4. Place Base building on a random White tile
+/// 5. Hide resource deposits under [`DEPOSIT_CHANCE`] of the tiles
 ///
 /// # Arguments
 ///
 /// * `seed` - Random seed for deterministic generation
+/// * `single_island` - Retry with different sub-seeds (up to
+///   [`MAX_ISLAND_RETRIES`] times) until the White tiles form one island;
+///   if none does, the surface with the fewest islands is used
 ///
 /// # Returns
 ///
 /// A fully initialized `PlanetSurface` ready for gameplay.
-pub fn generate_planet(seed: u64) -> PlanetSurface {
+pub fn generate_planet(seed: u64, single_island: bool) -> PlanetSurface {
+    let mut surface = generate_random_surface(seed);
+    if !single_island {
+        return surface;
+    }
+
+    let mut islands = label_connected_components(&surface).len();
+    for attempt in 1..=MAX_ISLAND_RETRIES {
+        if islands <= 1 {
+            break;
+        }
+        let candidate = generate_random_surface(seed.wrapping_add(attempt));
+        let candidate_islands = label_connected_components(&candidate).len();
+        if candidate_islands < islands {
+            surface = candidate;
+            islands = candidate_islands;
+        }
+    }
+    surface
+}
+
+/// One attempt of [`generate_planet`].
+fn generate_random_surface(seed: u64) -> PlanetSurface {
     let mut rng = StdRng::seed_from_u64(seed);
     let width = 10;
     let height = 10;
@@ -47,7 +84,130 @@ pub fn generate_planet(seed: u64) -> PlanetSurface {
         surface.tiles[0].color = TileColor::White;
     }
 
-    // Place Base on a random White tile
+    place_start_building(&mut surface, BuildingType::Base, &mut rng);
+    place_deposits(&mut surface, &mut rng);
+
+    surface
+}
+
+/// Generate a planet surface from a scenario's map parameters.
+///
+/// The grid is `grid_width` x `grid_height` tiles. With
+/// [`GenerationMode::RandomWhiteBlack`], exactly `black_ratio` of the tiles
+/// (rounded) are black and the rest white, shuffled with the seeded RNG,
+/// so the distribution matches the scenario on every seed. The scenario's
+/// start building (Base if unknown) is placed on a random white tile, and
+/// resource deposits are hidden as in [`generate_planet`].
+pub fn generate_scenario_planet(seed: u64, scenario: &Scenario) -> PlanetSurface {
+    let mut rng = StdRng::seed_from_u64(seed);
+    let width = scenario.grid_width.max(1) as usize;
+    let height = scenario.grid_height.max(1) as usize;
+    let tile_count = width * height;
+
+    let mut surface = PlanetSurface::new(width, height);
+
+    match scenario.generation_mode {
+        GenerationMode::RandomWhiteBlack => {
+            let ratio = scenario.black_ratio.clamp(0.0, 1.0);
+            // Keep at least one White tile for the start building
+            let black_count = ((tile_count as f32 * ratio).round() as usize).min(tile_count - 1);
+
+            let mut colors: Vec<TileColor> = (0..tile_count)
+                .map(|i| {
+                    if i < black_count {
+                        TileColor::Black
+                    } else {
+                        TileColor::White
+                    }
+                })
+                .collect();
+            colors.shuffle(&mut rng);
+
+            for (tile, color) in surface.tiles.iter_mut().zip(colors) {
+                tile.color = color;
+            }
+        }
+    }
+
+    let start_building =
+        BuildingType::from_id(&scenario.start_building_id).unwrap_or(BuildingType::Base);
+    place_start_building(&mut surface, start_building, &mut rng);
+    place_deposits(&mut surface, &mut rng);
+
+    surface
+}
+
+/// Hide a deposit under each tile with [`DEPOSIT_CHANCE`], worth 1-3 of its
+/// resource per turn.
+///
+/// Runs after every other roll, so adding deposits keeps the tile colors
+/// and start position of existing seeds.
+fn place_deposits(surface: &mut PlanetSurface, rng: &mut StdRng) {
+    for tile in &mut surface.tiles {
+        if !rng.gen_bool(DEPOSIT_CHANCE) {
+            continue;
+        }
+        let amount = rng.gen_range(1..=3);
+        tile.deposit = Some(match rng.gen_range(0..3) {
+            0 => ResourceDepositKind::IronOre(amount),
+            1 => ResourceDepositKind::RareEarths(amount),
+            _ => ResourceDepositKind::FertileLand(amount),
+        });
+    }
+}
+
+/// Group the White tiles into islands of orthogonally adjacent tiles.
+///
+/// Each island lists its tiles as `(x, y)` in the order a breadth-first
+/// flood fill reaches them; islands are ordered by their first tile in
+/// row-major order. Black tiles separate islands, so the Base's power
+/// grid can never reach another island without terraforming.
+pub fn label_connected_components(surface: &PlanetSurface) -> Vec<Vec<(usize, usize)>> {
+    let width = surface.row_width;
+    if width == 0 {
+        return Vec::new();
+    }
+    let height = surface.height();
+    let is_white = |x: usize, y: usize| {
+        surface
+            .get(x, y)
+            .is_some_and(|tile| tile.color == TileColor::White)
+    };
+
+    let mut visited = vec![false; surface.tiles.len()];
+    let mut components = Vec::new();
+    for start in 0..surface.tiles.len() {
+        let (x, y) = (start % width, start / width);
+        if visited[start] || !is_white(x, y) {
+            continue;
+        }
+
+        let mut component = Vec::new();
+        let mut queue = VecDeque::from([(x, y)]);
+        visited[start] = true;
+        while let Some((x, y)) = queue.pop_front() {
+            component.push((x, y));
+            let neighbors = [
+                (x.wrapping_sub(1), y),
+                (x + 1, y),
+                (x, y.wrapping_sub(1)),
+                (x, y + 1),
+            ];
+            for (nx, ny) in neighbors {
+                if nx >= width || ny >= height || visited[ny * width + nx] || !is_white(nx, ny) {
+                    continue;
+                }
+                visited[ny * width + nx] = true;
+                queue.push_back((nx, ny));
+            }
+        }
+        components.push(component);
+    }
+    components
+}
+
+/// Place `building` on a random White tile.
+fn place_start_building(surface: &mut PlanetSurface, building: BuildingType, rng: &mut StdRng) {
     let white_indices: Vec<usize> = surface
         .tiles
         .iter()
@@ -56,9 +216,7 @@ pub fn generate_planet(seed: u64) -> PlanetSurface {
         .map(|(i, _)| i)
         .collect();
 
-    if let Some(&idx) = white_indices.choose(&mut rng) {
-        surface.tiles[idx].building = Some(BuildingType::Base);
+    if let Some(&idx) = white_indices.choose(rng) {
+        surface.tiles[idx].building = Some(building);
     }
-
-    surface
 }
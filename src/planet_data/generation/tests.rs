@@ -0,0 +1,143 @@
+use super::{
+    DEPOSIT_CHANCE, generate_planet, generate_scenario_planet, label_connected_components,
+};
+use crate::data_types::{GenerationMode, Scenario};
+use crate::planet_data::{BuildingType, PlanetSurface, TileColor};
+
+fn scenario(width: u32, height: u32, black_ratio: f32) -> Scenario {
+    Scenario {
+        id: "scenario_test".to_string(),
+        name_en: "Test".to_string(),
+        grid_width: width,
+        grid_height: height,
+        start_building_id: "building_base".to_string(),
+        generation_mode: GenerationMode::RandomWhiteBlack,
+        black_ratio,
+        victory_condition_id: "victory_cover_planet".to_string(),
+        max_turns: None,
+    }
+}
+
+fn black_share(seed: u64, scenario: &Scenario) -> f32 {
+    let surface = generate_scenario_planet(seed, scenario);
+    let black = surface
+        .tiles
+        .iter()
+        .filter(|tile| tile.color == TileColor::Black)
+        .count();
+    black as f32 / surface.tiles.len() as f32
+}
+
+#[test]
+fn scenario_planets_follow_black_ratio() {
+    for (width, height) in [(10, 10), (10, 5), (16, 12)] {
+        for ratio in [0.0, 0.2, 0.3, 0.5, 0.8] {
+            let scenario = scenario(width, height, ratio);
+            for seed in [1, 7, 42, 12345, 99999] {
+                let share = black_share(seed, &scenario);
+                assert!(
+                    (share - ratio).abs() <= 0.1,
+                    "{width}x{height} seed {seed}: {share} black, expected {ratio}"
+                );
+            }
+        }
+    }
+}
+
+#[test]
+fn scenario_planets_use_grid_size_and_start_building() {
+    let surface = generate_scenario_planet(3, &scenario(12, 8, 0.3));
+
+    assert_eq!(surface.row_width, 12);
+    assert_eq!(surface.height(), 8);
+    let bases: Vec<_> = surface
+        .tiles
+        .iter()
+        .filter(|tile| tile.building == Some(BuildingType::Base))
+        .collect();
+    assert_eq!(bases.len(), 1);
+    assert_eq!(bases[0].color, TileColor::White);
+}
+
+#[test]
+fn fully_black_scenario_keeps_a_tile_for_the_base() {
+    let surface = generate_scenario_planet(5, &scenario(10, 10, 1.0));
+
+    assert!(
+        surface
+            .tiles
+            .iter()
+            .any(|tile| tile.building == Some(BuildingType::Base))
+    );
+}
+
+#[test]
+fn generation_is_deterministic() {
+    let scenario = scenario(10, 10, 0.3);
+    assert_eq!(
+        generate_scenario_planet(42, &scenario),
+        generate_scenario_planet(42, &scenario)
+    );
+    assert_eq!(generate_planet(42, false), generate_planet(42, false));
+}
+
+#[test]
+fn some_tiles_hide_deposits() {
+    let tiles: Vec<_> = (0..20)
+        .flat_map(|seed| generate_planet(seed, false).tiles)
+        .collect();
+    let share =
+        tiles.iter().filter(|tile| tile.deposit.is_some()).count() as f64 / tiles.len() as f64;
+
+    assert!(
+        (share - DEPOSIT_CHANCE).abs() < 0.02,
+        "deposit share {share}"
+    );
+    assert!(tiles.iter().all(|tile| !tile.deposit_revealed));
+}
+
+/// Surface drawn row by row, `#` for White tiles and `.` for Black ones.
+fn surface_from_rows(rows: &[&str]) -> PlanetSurface {
+    let mut surface = PlanetSurface::new(rows[0].len(), rows.len());
+    for (y, row) in rows.iter().enumerate() {
+        for (x, c) in row.chars().enumerate() {
+            if c == '#' {
+                surface.get_mut(x, y).unwrap().color = TileColor::White;
+            }
+        }
+    }
+    surface
+}
+
+#[test]
+fn connected_white_tiles_form_one_island() {
+    let surface = surface_from_rows(&["##.", ".#.", ".##"]);
+
+    let islands = label_connected_components(&surface);
+
+    assert_eq!(islands.len(), 1);
+    assert_eq!(islands[0], vec![(0, 0), (1, 0), (1, 1), (1, 2), (2, 2)]);
+}
+
+#[test]
+fn black_tiles_and_diagonals_separate_islands() {
+    let surface = surface_from_rows(&["#.#", ".#.", "##."]);
+
+    let islands = label_connected_components(&surface);
+
+    assert_eq!(
+        islands,
+        vec![vec![(0, 0)], vec![(2, 0)], vec![(1, 1), (1, 2), (0, 2)]]
+    );
+    assert!(label_connected_components(&surface_from_rows(&["...", "..."])).is_empty());
+}
+
+#[test]
+fn single_island_generation_never_adds_islands() {
+    for seed in [1, 7, 42, 12345] {
+        let plain = label_connected_components(&generate_planet(seed, false)).len();
+        let single = label_connected_components(&generate_planet(seed, true)).len();
+        assert!(single <= plain, "seed {seed}: {single} > {plain} islands");
+        assert!(single >= 1);
+    }
+}
@@ -19,5 +19,8 @@
 mod generation;
 mod types;
 
-pub use generation::generate_planet;
+pub use generation::{
+    DEPOSIT_CHANCE, MAX_ISLAND_RETRIES, generate_planet, generate_scenario_planet,
+    label_connected_components,
+};
 pub use types::*;
@@ -13,13 +13,14 @@
 //! This is inspired by Ascendancy's planet management system.
 
 use bevy::prelude::*;
+use serde::{Deserialize, Serialize};
 
 /// Tile color determines what can be built on it.
 ///
 /// In Ascendancy-style gameplay:
 /// - **White tiles**: Can have buildings placed directly
 /// - **Black tiles**: Require terraforming before building
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
 pub enum TileColor {
     /// Unbuildable tile (requires terraforming).
     Black,
@@ -31,7 +32,7 @@ pub enum TileColor {
 ///
 /// Each building type has a corresponding ID string used to look up
 /// its full definition (yields, cost, color) in the game data files.
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
 pub enum BuildingType {
     /// Starting building, provides all resource types.
     Base,
@@ -47,9 +48,76 @@ pub enum BuildingType {
     Passage,
     /// Converts black tiles to white tiles.
     Terraformer,
+    /// Raises the food stockpile cap.
+    Warehouse,
+    /// Trains ground troops to defend the colony.
+    Barracks,
+    /// Upgraded farm.
+    Farm2,
+    /// Upgraded habitat.
+    Habitat2,
+    /// Upgraded factory.
+    Factory2,
+    /// Upgraded laboratory.
+    Laboratory2,
+}
+
+/// Broad role of a building, shared by all its upgrade levels.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum BuildingKind {
+    /// The colony Base.
+    Base,
+    /// Farms.
+    Food,
+    /// Habitats.
+    Housing,
+    /// Factories.
+    Industry,
+    /// Laboratories.
+    Science,
+    /// Passages that only extend the power grid.
+    Connector,
+    /// Terraformers.
+    Terraforming,
+    /// Warehouses.
+    Storage,
+    /// Barracks.
+    Military,
+}
+
+impl BuildingKind {
+    /// Display name, e.g. `Industry`.
+    pub fn label(&self) -> &'static str {
+        match self {
+            BuildingKind::Base => "Base",
+            BuildingKind::Food => "Food",
+            BuildingKind::Housing => "Housing",
+            BuildingKind::Industry => "Industry",
+            BuildingKind::Science => "Science",
+            BuildingKind::Connector => "Connector",
+            BuildingKind::Terraforming => "Terraforming",
+            BuildingKind::Storage => "Storage",
+            BuildingKind::Military => "Military",
+        }
+    }
 }
 
 impl BuildingType {
+    /// Role of this building (upgrades share the kind of their base level).
+    pub fn kind(&self) -> BuildingKind {
+        match self {
+            BuildingType::Base => BuildingKind::Base,
+            BuildingType::Farm | BuildingType::Farm2 => BuildingKind::Food,
+            BuildingType::Habitat | BuildingType::Habitat2 => BuildingKind::Housing,
+            BuildingType::Factory | BuildingType::Factory2 => BuildingKind::Industry,
+            BuildingType::Laboratory | BuildingType::Laboratory2 => BuildingKind::Science,
+            BuildingType::Passage => BuildingKind::Connector,
+            BuildingType::Terraformer => BuildingKind::Terraforming,
+            BuildingType::Warehouse => BuildingKind::Storage,
+            BuildingType::Barracks => BuildingKind::Military,
+        }
+    }
+
     /// Get the string ID used to look up this building in game data.
     ///
     /// These IDs correspond to entries in `surface_buildings.ron`.
@@ -62,6 +130,59 @@ impl BuildingType {
             BuildingType::Laboratory => "building_laboratory_1",
             BuildingType::Passage => "building_passage",
             BuildingType::Terraformer => "building_terraformer",
+            BuildingType::Warehouse => "building_warehouse",
+            BuildingType::Barracks => "building_barracks",
+            BuildingType::Farm2 => "building_farm_2",
+            BuildingType::Habitat2 => "building_habitat_2",
+            BuildingType::Factory2 => "building_factory_2",
+            BuildingType::Laboratory2 => "building_laboratory_2",
+        }
+    }
+
+    /// Every building type, in declaration order.
+    pub const ALL: [BuildingType; 13] = [
+        BuildingType::Base,
+        BuildingType::Farm,
+        BuildingType::Habitat,
+        BuildingType::Factory,
+        BuildingType::Laboratory,
+        BuildingType::Passage,
+        BuildingType::Terraformer,
+        BuildingType::Warehouse,
+        BuildingType::Barracks,
+        BuildingType::Farm2,
+        BuildingType::Habitat2,
+        BuildingType::Factory2,
+        BuildingType::Laboratory2,
+    ];
+
+    /// Find the building type whose [`id`](Self::id) is `id`.
+    pub fn from_id(id: &str) -> Option<Self> {
+        Self::ALL.into_iter().find(|building| building.id() == id)
+    }
+}
+
+/// A resource deposit hidden under a tile, with its bonus yield per turn.
+///
+/// Deposits are placed during generation and revealed the first time their
+/// tile is connected to the power grid.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum ResourceDepositKind {
+    /// Extra production per turn.
+    IronOre(i32),
+    /// Extra science per turn.
+    RareEarths(i32),
+    /// Extra food per turn.
+    FertileLand(i32),
+}
+
+impl ResourceDepositKind {
+    /// Display name of the deposit.
+    pub fn label(self) -> &'static str {
+        match self {
+            ResourceDepositKind::IronOre(_) => "Iron Ore",
+            ResourceDepositKind::RareEarths(_) => "Rare Earths",
+            ResourceDepositKind::FertileLand(_) => "Fertile Land",
         }
     }
 }
@@ -72,7 +193,8 @@ impl BuildingType {
 /// - A base color determining buildability
 /// - An optional building
 /// - A connectivity flag for the power grid
-#[derive(Debug, Clone, PartialEq, Eq)]
+/// - An optional hidden resource deposit
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
 pub struct SurfaceTile {
     /// Base tile color derived from the surface type distribution.
     pub color: TileColor,
@@ -81,6 +203,12 @@ pub struct SurfaceTile {
     /// Whether this tile is connected to the base (power/logistics).
     /// Only connected tiles can have new buildings placed on them.
     pub connected: bool,
+    /// Resource deposit under the tile, if any.
+    #[serde(default)]
+    pub deposit: Option<ResourceDepositKind>,
+    /// Whether the deposit has been discovered (its tile was connected).
+    #[serde(default)]
+    pub deposit_revealed: bool,
 }
 
 /// The complete surface grid of a planet.
@@ -94,7 +222,7 @@ pub struct SurfaceTile {
 /// let surface = PlanetSurface::new(10, 10); // 10x10 grid
 /// let tile = surface.get(5, 3); // Get tile at column 5, row 3
 /// ```
-#[derive(Debug, Clone, PartialEq, Eq, Resource)]
+#[derive(Debug, Clone, PartialEq, Eq, Resource, Serialize, Deserialize)]
 pub struct PlanetSurface {
     /// Flat vector of tiles in row-major order.
     /// Index = y * row_width + x
@@ -119,6 +247,8 @@ impl PlanetSurface {
                 color: TileColor::Black,
                 building: None,
                 connected: false,
+                deposit: None,
+                deposit_revealed: false,
             };
             width * height
         ];
@@ -150,6 +280,22 @@ impl PlanetSurface {
         self.tiles.get_mut(idx)
     }
 
+    /// Indices of the orthogonal neighbors (left, right, up, down) of the
+    /// tile at `index` that lie inside the grid.
+    pub fn neighbors_4(&self, index: usize) -> impl Iterator<Item = usize> {
+        let width = self.row_width;
+        let (x, y) = (index % width, index / width);
+        let height = self.height();
+        [
+            (x > 0).then(|| index - 1),
+            (x + 1 < width).then(|| index + 1),
+            (y > 0).then(|| index - width),
+            (y + 1 < height).then(|| index + width),
+        ]
+        .into_iter()
+        .flatten()
+    }
+
     /// Calculate the height (number of rows) of the grid.
     pub fn height(&self) -> usize {
         if self.row_width == 0 {
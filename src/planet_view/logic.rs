@@ -9,11 +9,412 @@
 //! The main feature here is the tile connectivity algorithm, which determines
 //! which tiles are "powered" by being connected to the base through a chain
 //! of buildings. This is inspired by Ascendancy's adjacency mechanics.
+//!
+//! # Turn Economy
+//!
+//! [`apply_turn_production`] resolves one turn of yields, construction and
+//! research for a single colony. It is shared by the player's End Turn
+//! button and the AI species.
 
 use crate::data_types::GameData;
 use crate::data_types::GameRegistry;
+use crate::data_types::TechnologyId;
+use crate::data_types::{ResourceKind, SpecialBehavior};
 use crate::planet_data::{BuildingType, PlanetSurface};
+use crate::planet_view::types::{PlanetViewState, ProjectType};
+use crate::research::ResearchQueue;
+use bevy::log::info;
 use std::collections::{HashSet, VecDeque};
+use thiserror::Error;
+
+mod accidents;
+mod adjacency;
+mod deposits;
+mod events;
+mod specialization;
+mod troops;
+mod yields;
+
+pub use accidents::{
+    ACCIDENT_PENALTY_TURNS, ACCIDENT_PROGRESS_LOSS_PERCENT, ACCIDENT_SCIENCE_PENALTY_PERCENT,
+    apply_research_accident, apply_science_penalty, research_accident_rng, roll_research_accident,
+};
+pub use adjacency::{YieldTotals, calculate_adjacency_bonuses, placement_adjacency_bonus};
+pub use deposits::{deposit_bonus, reveal_deposits};
+pub use events::{apply_planet_event, planet_event_rng, roll_planet_events};
+pub use specialization::{
+    SPECIALIZATION_BONUS_PERCENT, breaks_specialization, specialization, specialization_bonus,
+};
+pub use troops::{
+    GROUND_TROOPS_PER_BARRACKS, GROUND_TROOPS_PER_HOUSING, ground_troop_cap, train_ground_troops,
+};
+pub use yields::calculate_planet_yield;
+
+#[cfg(test)]
+mod tests;
+
+/// Share of the production yield lost while population exceeds housing
+/// (rounded down).
+pub const OVERCROWDING_PENALTY_PERCENT: i32 = 20;
+
+/// Stockpile cap of food, production, and science before storage bonuses.
+pub const BASE_STOCKPILE_CAP: u32 = 200;
+
+/// Outcome of a single call to [`apply_turn_production`].
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
+pub struct TurnReport {
+    /// Tile indices where construction finished this turn.
+    pub completed_tiles: Vec<usize>,
+    /// Technologies researched this turn.
+    pub unlocked_techs: Vec<TechnologyId>,
+    /// Tile indices whose resource deposit was discovered this turn.
+    pub discovered_deposits: Vec<usize>,
+}
+
+/// Reasons a building cannot be upgraded.
+#[derive(Debug, Error, PartialEq, Eq)]
+pub enum UpgradeError {
+    /// The tile has no building.
+    #[error("Tile {0} has no building to upgrade")]
+    NoBuilding(usize),
+    /// The building has no upgrade (or the upgrade is not a known building type).
+    #[error("{0} cannot be upgraded")]
+    NoUpgrade(String),
+    /// The production stockpile cannot cover the upgrade.
+    #[error("Upgrade needs {needed} production, only {available} available")]
+    NotEnoughProduction {
+        /// Production cost of the upgrade.
+        needed: u32,
+        /// Production in the stockpile.
+        available: u32,
+    },
+}
+
+/// Reasons a building cannot be demolished.
+#[derive(Debug, Error, PartialEq, Eq)]
+pub enum DemolishError {
+    /// The tile has no building.
+    #[error("Tile {0} has no building to demolish")]
+    NoBuilding(usize),
+    /// The Base anchors the power grid and must stay.
+    #[error("The Base cannot be demolished")]
+    CannotDemolishBase,
+}
+
+/// Effect of demolishing a building (see [`demolish_preview`]).
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DemolishPreview {
+    /// Building that would be removed.
+    pub building: BuildingType,
+    /// Production returned to the stockpile (half the construction cost).
+    pub refund: u32,
+    /// Tiles that are connected now but would lose their connection.
+    pub disconnected_tiles: Vec<usize>,
+}
+
+/// Resolve one turn of the colony economy.
+///
+/// 1. **Turn Counter**: Increment the turn number
+/// 2. **Resource Yields**: Sum up yields from all connected buildings with
+///    [`calculate_planet_yield`] (data-driven), including the adjacency
+///    bonuses of connected buildings (see
+///    [`calculate_adjacency_bonuses`]), the [`specialization_bonus`] of a
+///    colony with only one kind of building, and revealed resource deposits
+///    (see [`deposit_bonus`]); housing sets the population capacity and
+///    the ground troop cap (see [`train_ground_troops`]), an overcrowded
+///    colony loses [`OVERCROWDING_PENALTY_PERCENT`] of its production
+///    yield, and a
+///    [`SciencePenalty`](crate::planet_view::types::SciencePenalty) left by
+///    a research accident reduces the science yield
+/// 3. **Stockpile Caps**: Add yields to the stockpiles, discarding anything
+///    above the caps set by [`update_stockpile_caps`]
+/// 4. **Population**: Grow by one with a food surplus (up to capacity),
+///    shrink by one with a food deficit
+/// 5. **Production Queue**: Apply production to the first project in queue
+/// 6. **Construction Completion**: Place buildings when projects finish
+/// 7. **Research Progress**: Spend science on the [`ResearchQueue`]
+///
+/// Building yields are read from `GameData.surface_buildings` rather than
+/// being hardcoded, allowing easy balancing via RON files. Disconnected
+/// buildings yield nothing.
+pub fn apply_turn_production(
+    state: &mut PlanetViewState,
+    research: &mut ResearchQueue,
+    game_data: &GameData,
+    registry: &GameRegistry,
+) -> TurnReport {
+    let mut report = TurnReport::default();
+    state.turn += 1;
+
+    // Calculate yields
+    let yields = state
+        .surface
+        .as_ref()
+        .map(|surface| calculate_planet_yield(surface, game_data, registry))
+        .unwrap_or_default();
+    let (food, housing, mut production, science) = (
+        yields.food,
+        yields.housing,
+        yields.production,
+        yields.science,
+    );
+
+    // Housing is a capacity rather than a stockpile
+    state.housing = housing.max(0) as u32;
+    train_ground_troops(state);
+    if state.current_population > state.housing {
+        production -= production * OVERCROWDING_PENALTY_PERCENT / 100;
+    }
+    let science = apply_science_penalty(state, science);
+    state.science_yield = science.max(0) as u32;
+
+    update_stockpile_caps(state, game_data);
+    state.food = ((state.food as i32 + food).max(0) as u32).min(state.max_food);
+    state.production =
+        ((state.production as i32 + production).max(0) as u32).min(state.max_production);
+    state.science = ((state.science as i32 + science).max(0) as u32).min(state.max_science);
+
+    // Population grows with a food surplus and starves with a deficit
+    if food > 0 && state.current_population < state.housing {
+        state.current_population += 1;
+    } else if food < 0 {
+        state.current_population = state.current_population.saturating_sub(1);
+    }
+
+    // Process Production Queue (production is a stockpile, not spent)
+    if let Some(project) = state.production_queue.front_mut() {
+        let needed = project.total_cost.saturating_sub(project.progress);
+        let amount = std::cmp::min(needed, state.production);
+        project.progress += amount;
+
+        if project.progress >= project.total_cost {
+            let finished_project = state.production_queue.pop_front().unwrap();
+            match finished_project.project_type {
+                ProjectType::Building(b_type) => {
+                    if let Some(surface) = &mut state.surface {
+                        if let Some(tile) =
+                            surface.tiles.get_mut(finished_project.target_tile_index)
+                        {
+                            tile.building = Some(b_type);
+                            info!("Construction Complete: {:?}", b_type);
+                            report
+                                .discovered_deposits
+                                .extend(update_connectivity(surface, game_data, registry));
+                            report
+                                .completed_tiles
+                                .push(finished_project.target_tile_index);
+                        }
+                    }
+                }
+            }
+        }
+    }
+
+    // Research receives this turn's yield; the stockpile is not spent
+    report.unlocked_techs = research.advance_with_science(science, game_data).0;
+
+    report
+}
+
+/// Building the one on `tile_index` upgrades into, with the production cost
+/// of the upgrade (the difference between both construction costs).
+pub fn upgrade_option(
+    state: &PlanetViewState,
+    tile_index: usize,
+    game_data: &GameData,
+    registry: &GameRegistry,
+) -> Result<(BuildingType, u32), UpgradeError> {
+    let current = state
+        .surface
+        .as_ref()
+        .and_then(|surface| surface.tiles.get(tile_index))
+        .and_then(|tile| tile.building)
+        .ok_or(UpgradeError::NoBuilding(tile_index))?;
+    let no_upgrade = || UpgradeError::NoUpgrade(current.id().to_string());
+
+    let chain = registry.building_upgrade_chain(game_data, &current.id().into());
+    let (Some(from), Some(to)) = (chain.first(), chain.get(1)) else {
+        return Err(no_upgrade());
+    };
+    let target = BuildingType::from_id(&to.id).ok_or_else(no_upgrade)?;
+
+    Ok((
+        target,
+        to.production_cost.saturating_sub(from.production_cost),
+    ))
+}
+
+/// Upgrade the building on `tile_index`, paying the cost from the
+/// production stockpile.
+///
+/// Connectivity and stockpile caps are recomputed for the new building.
+///
+/// # Errors
+/// Returns an [`UpgradeError`] (leaving the colony unchanged) if there is
+/// nothing to upgrade or not enough production.
+pub fn upgrade_building(
+    state: &mut PlanetViewState,
+    tile_index: usize,
+    game_data: &GameData,
+    registry: &GameRegistry,
+) -> Result<BuildingType, UpgradeError> {
+    let (target, cost) = upgrade_option(state, tile_index, game_data, registry)?;
+    if state.production < cost {
+        return Err(UpgradeError::NotEnoughProduction {
+            needed: cost,
+            available: state.production,
+        });
+    }
+
+    state.production -= cost;
+    if let Some(tile) = state
+        .surface
+        .as_mut()
+        .and_then(|surface| surface.tiles.get_mut(tile_index))
+    {
+        tile.building = Some(target);
+    }
+    if let Some(surface) = &mut state.surface {
+        update_connectivity(surface, game_data, registry);
+    }
+    update_stockpile_caps(state, game_data);
+    info!("Upgrade Complete: {:?}", target);
+    Ok(target)
+}
+
+/// Work out what demolishing the building on `tile_index` would do,
+/// without changing the colony.
+///
+/// Connectivity is recomputed on a copy of the surface to find the tiles
+/// that would lose power.
+///
+/// # Errors
+/// Returns a [`DemolishError`] if the tile is empty or holds the Base.
+pub fn demolish_preview(
+    state: &PlanetViewState,
+    tile_index: usize,
+    game_data: &GameData,
+    registry: &GameRegistry,
+) -> Result<DemolishPreview, DemolishError> {
+    let surface = state
+        .surface
+        .as_ref()
+        .ok_or(DemolishError::NoBuilding(tile_index))?;
+    let building = surface
+        .tiles
+        .get(tile_index)
+        .and_then(|tile| tile.building)
+        .ok_or(DemolishError::NoBuilding(tile_index))?;
+    if building == BuildingType::Base {
+        return Err(DemolishError::CannotDemolishBase);
+    }
+
+    let mut after = surface.clone();
+    after.tiles[tile_index].building = None;
+    update_connectivity(&mut after, game_data, registry);
+    let disconnected_tiles = surface
+        .tiles
+        .iter()
+        .zip(&after.tiles)
+        .enumerate()
+        .filter(|(_, (before, after))| before.connected && !after.connected)
+        .map(|(index, _)| index)
+        .collect();
+
+    let refund = registry
+        .surface_building(game_data, building.id())
+        .map_or(0, |def| def.production_cost / 2);
+
+    Ok(DemolishPreview {
+        building,
+        refund,
+        disconnected_tiles,
+    })
+}
+
+/// Demolish the building on `tile_index`, refunding half its construction
+/// cost to the production stockpile (up to the cap).
+///
+/// Queued construction on the tile is dropped and connectivity and
+/// stockpile caps are recomputed.
+///
+/// # Errors
+/// Returns a [`DemolishError`] (leaving the colony unchanged) if the tile
+/// is empty or holds the Base.
+pub fn demolish_building(
+    state: &mut PlanetViewState,
+    tile_index: usize,
+    game_data: &GameData,
+    registry: &GameRegistry,
+) -> Result<DemolishPreview, DemolishError> {
+    let preview = demolish_preview(state, tile_index, game_data, registry)?;
+
+    if let Some(surface) = &mut state.surface {
+        surface.tiles[tile_index].building = None;
+        update_connectivity(surface, game_data, registry);
+    }
+    state
+        .production_queue
+        .retain(|project| project.target_tile_index != tile_index);
+    update_stockpile_caps(state, game_data);
+    state.production = (state.production + preview.refund).min(state.max_production);
+
+    info!("Demolished {:?}", preview.building);
+    Ok(preview)
+}
+
+/// Move the queued project at `from` to position `to`, shifting the
+/// projects in between. The project keeps its progress; whichever project
+/// ends up first receives the next turn's production.
+///
+/// Returns `false`, leaving the queue unchanged, if either index is out of
+/// range.
+pub fn move_queued_project(state: &mut PlanetViewState, from: usize, to: usize) -> bool {
+    let len = state.production_queue.len();
+    if from >= len || to >= len {
+        return false;
+    }
+    if let Some(project) = state.production_queue.remove(from) {
+        state.production_queue.insert(to, project);
+    }
+    true
+}
+
+/// Recompute the stockpile caps of a colony from its buildings.
+///
+/// Every cap starts at [`BASE_STOCKPILE_CAP`] and is raised by each
+/// building with a [`SpecialBehavior::StorageBonus`].
+pub fn update_stockpile_caps(state: &mut PlanetViewState, game_data: &GameData) {
+    let (mut max_food, mut max_production, mut max_science) =
+        (BASE_STOCKPILE_CAP, BASE_STOCKPILE_CAP, BASE_STOCKPILE_CAP);
+
+    let buildings = state
+        .surface
+        .iter()
+        .flat_map(|surface| &surface.tiles)
+        .filter_map(|tile| tile.building);
+    for building in buildings {
+        let Some(def) = game_data
+            .surface_buildings
+            .iter()
+            .find(|b| b.id == building.id())
+        else {
+            continue;
+        };
+        if let SpecialBehavior::StorageBonus { resource, amount } = def.special_behavior {
+            let cap = match resource {
+                ResourceKind::Food => &mut max_food,
+                ResourceKind::Production => &mut max_production,
+                ResourceKind::Science => &mut max_science,
+            };
+            *cap = cap.saturating_add(amount.max(0) as u32);
+        }
+    }
+
+    state.max_food = max_food;
+    state.max_production = max_production;
+    state.max_science = max_science;
+}
 
 /// Update the connectivity status of all tiles on the planet surface.
 ///
@@ -28,17 +429,23 @@ use std::collections::{HashSet, VecDeque};
 /// 2. Find the Base building (starting point)
 /// 3. BFS to find all "grid nodes" (buildings with `counts_for_adjacency: true`)
 /// 4. Mark grid nodes and their orthogonal neighbors as connected
+/// 5. Reveal the resource deposits of newly connected tiles (see
+///    [`reveal_deposits`])
 ///
 /// # Arguments
 ///
 /// * `surface` - The planet surface to update (mutated in place)
 /// * `game_data` - Game data containing building definitions
 /// * `registry` - Registry for looking up building IDs
+///
+/// # Returns
+///
+/// The indices of the tiles whose deposit was revealed by this update.
 pub fn update_connectivity(
     surface: &mut PlanetSurface,
     _game_data: &GameData,
     _registry: &GameRegistry,
-) {
+) -> Vec<usize> {
     let width = surface.row_width;
     let height = surface.tiles.len() / width;
 
@@ -57,7 +464,9 @@ pub fn update_connectivity(
     }
 
     // If no base exists, nothing can be connected
-    let Some(start_node) = base_index else { return };
+    let Some(start_node) = base_index else {
+        return Vec::new();
+    };
 
     // Step 3: BFS to find all "Grid Nodes" (buildings that extend the power grid)
     let mut grid_nodes = HashSet::new();
@@ -123,4 +532,7 @@ pub fn update_connectivity(
             surface.tiles[idx + width].connected = true;
         }
     }
+
+    // Step 5: Discover deposits under newly connected tiles
+    reveal_deposits(surface)
 }
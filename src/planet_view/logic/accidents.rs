@@ -0,0 +1,81 @@
+//! Research accidents while researching dangerous technologies.
+//!
+//! Each turn the active technology is rolled against its
+//! `accident_chance`. An accident destroys part of the research progress
+//! and leaves the colony with a [`SciencePenalty`] for a few turns. Like
+//! planet events, rolls use an RNG seeded with the game's seed and the turn
+//! number.
+
+use rand::prelude::*;
+
+use crate::data_types::{GameData, Technology};
+use crate::planet_view::types::{PlanetViewState, SciencePenalty};
+use crate::research::ResearchQueue;
+
+/// Share of the technology's science cost lost from the research
+/// progress in an accident.
+pub const ACCIDENT_PROGRESS_LOSS_PERCENT: i32 = 30;
+
+/// Share of the science yield lost after an accident.
+pub const ACCIDENT_SCIENCE_PENALTY_PERCENT: i32 = 20;
+
+/// Turns the science penalty of an accident lasts.
+pub const ACCIDENT_PENALTY_TURNS: u32 = 3;
+
+/// Mixed into the seed so accident rolls differ from event rolls.
+const ACCIDENT_SEED_SALT: u64 = 0xACC1_DE47;
+
+/// RNG for the accident roll of `turn` in the game seeded with `game_seed`
+/// (see [`PlanetViewState::game_seed`]).
+pub fn research_accident_rng(game_seed: u64, turn: u32) -> StdRng {
+    StdRng::seed_from_u64(game_seed ^ u64::from(turn) ^ ACCIDENT_SEED_SALT)
+}
+
+/// The active technology, if it is dangerous and its accident roll hits.
+///
+/// Consumes one roll only when the active technology has a non-zero
+/// `accident_chance`.
+pub fn roll_research_accident<'a>(
+    research: &ResearchQueue,
+    game_data: &'a GameData,
+    rng: &mut impl Rng,
+) -> Option<&'a Technology> {
+    let active = research.active.as_ref()?;
+    let tech = game_data
+        .technologies()
+        .iter()
+        .find(|tech| tech.id == active.as_str())?;
+    (tech.accident_chance > 0.0 && rng.r#gen::<f32>() < tech.accident_chance).then_some(tech)
+}
+
+/// Apply an accident while researching `tech`: lose
+/// [`ACCIDENT_PROGRESS_LOSS_PERCENT`] of its cost from the progress and
+/// start (or restart) the science penalty.
+///
+/// Returns the progress lost.
+pub fn apply_research_accident(
+    state: &mut PlanetViewState,
+    research: &mut ResearchQueue,
+    tech: &Technology,
+) -> i32 {
+    let loss = tech.science_cost.max(0) * ACCIDENT_PROGRESS_LOSS_PERCENT / 100;
+    let lost = loss.min(research.progress.max(0));
+    research.progress -= lost;
+    state.science_penalty = Some(SciencePenalty {
+        remaining_turns: ACCIDENT_PENALTY_TURNS,
+        amount: ACCIDENT_SCIENCE_PENALTY_PERCENT,
+    });
+    lost
+}
+
+/// Reduce `science` by the colony's science penalty and count down its
+/// remaining turns, clearing it once it has run out.
+pub fn apply_science_penalty(state: &mut PlanetViewState, science: i32) -> i32 {
+    let Some(mut penalty) = state.science_penalty else {
+        return science;
+    };
+    let reduced = science - science.max(0) * penalty.amount / 100;
+    penalty.remaining_turns = penalty.remaining_turns.saturating_sub(1);
+    state.science_penalty = (penalty.remaining_turns > 0).then_some(penalty);
+    reduced
+}
@@ -0,0 +1,114 @@
+//! Yield bonuses for complementary buildings placed next to each other.
+//!
+//! The rules come from `adjacency_bonuses.ron` (see [`AdjacencyBonus`]).
+//! Every connected building earns each matching rule once per orthogonal
+//! neighbor, so a farm between two habitats gets the food bonus twice.
+
+use std::fmt;
+use std::ops::{AddAssign, Sub};
+
+use crate::data_types::AdjacencyBonus;
+use crate::planet_data::{BuildingType, PlanetSurface};
+
+/// Food, housing, production, and science summed over several sources.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub struct YieldTotals {
+    /// Food per turn.
+    pub food: i32,
+    /// Housing capacity.
+    pub housing: i32,
+    /// Production per turn.
+    pub production: i32,
+    /// Science per turn.
+    pub science: i32,
+}
+
+impl YieldTotals {
+    /// Whether every yield is zero.
+    pub fn is_zero(&self) -> bool {
+        *self == Self::default()
+    }
+}
+
+impl AddAssign<&AdjacencyBonus> for YieldTotals {
+    fn add_assign(&mut self, bonus: &AdjacencyBonus) {
+        self.food += bonus.yields_food;
+        self.housing += bonus.yields_housing;
+        self.production += bonus.yields_production;
+        self.science += bonus.yields_science;
+    }
+}
+
+impl Sub for YieldTotals {
+    type Output = Self;
+
+    fn sub(self, other: Self) -> Self {
+        Self {
+            food: self.food - other.food,
+            housing: self.housing - other.housing,
+            production: self.production - other.production,
+            science: self.science - other.science,
+        }
+    }
+}
+
+impl fmt::Display for YieldTotals {
+    /// Non-zero yields as e.g. `+1 food, +2 science`.
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let parts: Vec<String> = [
+            (self.food, "food"),
+            (self.housing, "housing"),
+            (self.production, "production"),
+            (self.science, "science"),
+        ]
+        .into_iter()
+        .filter(|(value, _)| *value != 0)
+        .map(|(value, label)| format!("{value:+} {label}"))
+        .collect();
+        f.write_str(&parts.join(", "))
+    }
+}
+
+/// Sum the adjacency bonuses earned by every connected building.
+pub fn calculate_adjacency_bonuses(
+    surface: &PlanetSurface,
+    rules: &[AdjacencyBonus],
+) -> YieldTotals {
+    let mut totals = YieldTotals::default();
+    for (index, tile) in surface.tiles.iter().enumerate() {
+        let Some(building) = tile.building.filter(|_| tile.connected) else {
+            continue;
+        };
+        for neighbor in surface.neighbors_4(index) {
+            let Some(neighbor) = surface.tiles[neighbor].building else {
+                continue;
+            };
+            for rule in rules.iter().filter(|rule| {
+                rule.building_kind == building.kind() && rule.neighbor_kind == neighbor.kind()
+            }) {
+                totals += rule;
+            }
+        }
+    }
+    totals
+}
+
+/// Change in adjacency bonuses if `building` were built on `tile_index`.
+///
+/// Counts both the bonus the new building earns and the bonuses it grants
+/// its neighbors.
+pub fn placement_adjacency_bonus(
+    surface: &PlanetSurface,
+    tile_index: usize,
+    building: BuildingType,
+    rules: &[AdjacencyBonus],
+) -> YieldTotals {
+    let mut placed = surface.clone();
+    let Some(tile) = placed.tiles.get_mut(tile_index) else {
+        return YieldTotals::default();
+    };
+    tile.building = Some(building);
+    tile.connected = true;
+
+    calculate_adjacency_bonuses(&placed, rules) - calculate_adjacency_bonuses(surface, rules)
+}
@@ -0,0 +1,43 @@
+//! Hidden resource deposits.
+//!
+//! Generation hides a [`ResourceDepositKind`] under some tiles. A deposit
+//! is revealed the first time its tile is connected to the power grid (see
+//! [`super::update_connectivity`]) and from then on adds its yield every
+//! turn while the tile stays connected.
+
+use crate::planet_data::{PlanetSurface, ResourceDepositKind};
+
+use super::YieldTotals;
+
+/// Reveal the deposits of connected tiles that are still hidden.
+///
+/// Returns the indices of the tiles whose deposit was just revealed.
+pub fn reveal_deposits(surface: &mut PlanetSurface) -> Vec<usize> {
+    surface
+        .tiles
+        .iter_mut()
+        .enumerate()
+        .filter(|(_, tile)| tile.connected && tile.deposit.is_some() && !tile.deposit_revealed)
+        .map(|(index, tile)| {
+            tile.deposit_revealed = true;
+            index
+        })
+        .collect()
+}
+
+/// Yields of the revealed deposits on connected tiles.
+pub fn deposit_bonus(surface: &PlanetSurface) -> YieldTotals {
+    let mut totals = YieldTotals::default();
+    for tile in &surface.tiles {
+        if !tile.connected || !tile.deposit_revealed {
+            continue;
+        }
+        match tile.deposit {
+            Some(ResourceDepositKind::IronOre(amount)) => totals.production += amount,
+            Some(ResourceDepositKind::RareEarths(amount)) => totals.science += amount,
+            Some(ResourceDepositKind::FertileLand(amount)) => totals.food += amount,
+            None => {}
+        }
+    }
+    totals
+}
@@ -0,0 +1,87 @@
+//! Random planet events rolled at the start of each turn.
+//!
+//! The events come from `planet_events.ron` (see [`PlanetEvent`]). Rolls
+//! use an RNG seeded with the game's seed and the turn number, so loading a
+//! save and ending the turn again gives the same events, while different
+//! games get different ones.
+
+use rand::prelude::*;
+
+use crate::data_types::{GameData, GameRegistry, PlanetEvent, PlanetEventEffect, ResourceKind};
+use crate::planet_data::TileColor;
+use crate::planet_view::types::PlanetViewState;
+
+use super::update_connectivity;
+
+/// RNG for the event rolls of `turn` in the game seeded with `game_seed`
+/// (see [`PlanetViewState::game_seed`]).
+pub fn planet_event_rng(game_seed: u64, turn: u32) -> StdRng {
+    StdRng::seed_from_u64(game_seed ^ u64::from(turn))
+}
+
+/// Events that fire this turn: each event that has not fired yet and whose
+/// `min_turn` has come is rolled against its `trigger_chance`.
+///
+/// Every eligible event consumes one roll, in data order.
+pub fn roll_planet_events<'a>(
+    state: &PlanetViewState,
+    events: &'a [PlanetEvent],
+    rng: &mut impl Rng,
+) -> Vec<&'a PlanetEvent> {
+    events
+        .iter()
+        .filter(|event| state.turn >= event.min_turn && !state.fired_event_ids.contains(&event.id))
+        .filter(|event| rng.r#gen::<f32>() < event.trigger_chance)
+        .collect()
+}
+
+/// Apply the effect of `event` and remember that it fired.
+///
+/// Returns `false`, leaving the colony unchanged, if the effect cannot be
+/// applied (a blocked tile that is not an empty White tile); the event may
+/// then fire on a later turn.
+pub fn apply_planet_event(
+    state: &mut PlanetViewState,
+    event: &PlanetEvent,
+    game_data: &GameData,
+    registry: &GameRegistry,
+) -> bool {
+    match event.effect {
+        PlanetEventEffect::ResourceBonus { kind, amount } => {
+            let (stockpile, cap) = match kind {
+                ResourceKind::Food => (&mut state.food, state.max_food),
+                ResourceKind::Production => (&mut state.production, state.max_production),
+                ResourceKind::Science => (&mut state.science, state.max_science),
+            };
+            *stockpile = add_capped(*stockpile, amount, cap);
+        }
+        PlanetEventEffect::ResearchBoost(amount) => {
+            state.science = add_capped(state.science, amount, state.max_science);
+        }
+        PlanetEventEffect::TileBlocked(x, y) => {
+            let Some(surface) = &mut state.surface else {
+                return false;
+            };
+            let Some(tile) = surface.get_mut(x, y) else {
+                return false;
+            };
+            if tile.color != TileColor::White || tile.building.is_some() {
+                return false;
+            }
+            tile.color = TileColor::Black;
+            update_connectivity(surface, game_data, registry);
+            let tile_index = y * surface.row_width + x;
+            state
+                .production_queue
+                .retain(|project| project.target_tile_index != tile_index);
+        }
+    }
+
+    state.fired_event_ids.insert(event.id.clone());
+    true
+}
+
+/// `value + amount`, kept between 0 and `cap`.
+fn add_capped(value: u32, amount: i32, cap: u32) -> u32 {
+    (i64::from(value) + i64::from(amount)).clamp(0, i64::from(cap)) as u32
+}
@@ -0,0 +1,80 @@
+//! Yield bonus for colonies that build only one kind of building.
+//!
+//! The Base and passages are ignored, so a colony of farms linked by
+//! passages still counts as specialized in food.
+
+use crate::data_types::GameData;
+use crate::planet_data::{BuildingKind, BuildingType, PlanetSurface};
+
+use super::YieldTotals;
+
+/// Extra yield of the specialized buildings, in percent (rounded down).
+pub const SPECIALIZATION_BONUS_PERCENT: i32 = 20;
+
+/// Whether `kind` counts towards (or against) specialization.
+fn counts_for_specialization(kind: BuildingKind) -> bool {
+    !matches!(kind, BuildingKind::Base | BuildingKind::Connector)
+}
+
+/// Kind shared by every counted building on the surface, if there is one.
+pub fn specialization(surface: &PlanetSurface) -> Option<BuildingKind> {
+    let mut kinds = surface
+        .tiles
+        .iter()
+        .filter_map(|tile| tile.building)
+        .map(|building| building.kind())
+        .filter(|kind| counts_for_specialization(*kind));
+
+    let first = kinds.next()?;
+    kinds.all(|kind| kind == first).then_some(first)
+}
+
+/// Specialization that building `building` would end.
+pub fn breaks_specialization(
+    surface: &PlanetSurface,
+    building: BuildingType,
+) -> Option<BuildingKind> {
+    let kind = building.kind();
+    specialization(surface).filter(|current| counts_for_specialization(kind) && *current != kind)
+}
+
+/// Extra yields granted by the colony's specialization.
+///
+/// [`SPECIALIZATION_BONUS_PERCENT`] of the summed yields of the connected
+/// specialized buildings, or nothing if the colony is not specialized.
+pub fn specialization_bonus(surface: &PlanetSurface, game_data: &GameData) -> YieldTotals {
+    let Some(kind) = specialization(surface) else {
+        return YieldTotals::default();
+    };
+
+    let mut yields = YieldTotals::default();
+    let buildings = surface
+        .tiles
+        .iter()
+        .filter(|tile| tile.connected)
+        .filter_map(|tile| tile.building);
+    for building in buildings {
+        if building.kind() != kind {
+            continue;
+        }
+        let Some(def) = game_data
+            .surface_buildings()
+            .iter()
+            .find(|def| def.id == building.id())
+        else {
+            continue;
+        };
+        yields.food += def.yields_food;
+        yields.housing += def.yields_housing;
+        yields.production += def.yields_production;
+        yields.science += def.yields_science;
+    }
+
+    let percent = |value: i32| value * SPECIALIZATION_BONUS_PERCENT / 100;
+    YieldTotals {
+        food: percent(yields.food),
+        housing: percent(yields.housing),
+        production: percent(yields.production),
+        science: percent(yields.science),
+    }
+}
@@ -0,0 +1,746 @@
+use std::path::PathBuf;
+
+use super::{
+    ACCIDENT_PENALTY_TURNS, BASE_STOCKPILE_CAP, DemolishError, GROUND_TROOPS_PER_BARRACKS,
+    UpgradeError, YieldTotals, apply_planet_event, apply_research_accident, apply_science_penalty,
+    apply_turn_production, breaks_specialization, calculate_adjacency_bonuses,
+    calculate_planet_yield, demolish_building, demolish_preview, deposit_bonus,
+    move_queued_project, placement_adjacency_bonus, planet_event_rng, research_accident_rng,
+    roll_planet_events, roll_research_accident, specialization, specialization_bonus,
+    train_ground_troops, update_connectivity, upgrade_building,
+};
+use crate::data_types::{
+    GameData, GameRegistry, PlanetEvent, PlanetEventEffect, ResourceKind, SpecialBehavior,
+    SurfaceBuilding, TechnologyId, load_game_data,
+};
+use crate::planet_data::{
+    BuildingKind, BuildingType, PlanetSurface, ResourceDepositKind, TileColor, generate_planet,
+};
+use crate::planet_view::test_support::strip_colony;
+use crate::planet_view::types::{PlanetViewState, ProductionProject, ProjectType, SciencePenalty};
+use crate::research::ResearchQueue;
+
+/// A generated planet with only its Base, connected to the power grid.
+fn new_colony(data: &GameData, registry: &GameRegistry, population: u32) -> PlanetViewState {
+    let mut surface = generate_planet(12345, false);
+    update_connectivity(&mut surface, data, registry);
+    PlanetViewState {
+        surface: Some(surface),
+        current_population: population,
+        ..Default::default()
+    }
+}
+
+/// Shipped game data with the Base building's yields replaced.
+fn data_with_base(food: i32, production: i32) -> (GameData, GameRegistry) {
+    let (mut data, _) = load_game_data(PathBuf::from("assets/data")).expect("data loads");
+    let base: &mut SurfaceBuilding = data
+        .surface_buildings
+        .iter_mut()
+        .find(|b| b.id == "building_base")
+        .expect("base is defined");
+    base.yields_food = food;
+    base.yields_production = production;
+    let registry = GameRegistry::from_game_data(&data).expect("registry builds");
+    (data, registry)
+}
+
+#[test]
+fn population_grows_to_housing_capacity() {
+    let (data, registry) = load_game_data(PathBuf::from("assets/data")).expect("data loads");
+    let mut state = new_colony(&data, &registry, 0);
+    let mut research = ResearchQueue::default();
+
+    let mut history = Vec::new();
+    for _ in 0..6 {
+        apply_turn_production(&mut state, &mut research, &data, &registry);
+        history.push(state.current_population);
+    }
+
+    // The Base provides 3 housing and a food surplus.
+    assert_eq!(state.housing, 3);
+    assert_eq!(history, [1, 2, 3, 3, 3, 3]);
+}
+
+#[test]
+fn population_starves_with_a_food_deficit() {
+    let (data, registry) = data_with_base(-1, 1);
+    let mut state = new_colony(&data, &registry, 2);
+    let mut research = ResearchQueue::default();
+
+    let mut history = Vec::new();
+    for _ in 0..3 {
+        apply_turn_production(&mut state, &mut research, &data, &registry);
+        history.push(state.current_population);
+    }
+
+    assert_eq!(history, [1, 0, 0]);
+}
+
+#[test]
+fn research_only_receives_each_turns_science_yield() {
+    let (mut data, _) = load_game_data(PathBuf::from("assets/data")).expect("data loads");
+    data.surface_buildings
+        .iter_mut()
+        .find(|b| b.id == "building_base")
+        .expect("base is defined")
+        .yields_science = 5;
+    let registry = GameRegistry::from_game_data(&data).expect("registry builds");
+    let mut state = new_colony(&data, &registry, 0);
+    let mut research = ResearchQueue::default();
+
+    apply_turn_production(&mut state, &mut research, &data, &registry);
+    let per_turn = research.progress;
+    assert!(per_turn >= 5);
+    apply_turn_production(&mut state, &mut research, &data, &registry);
+
+    // The stockpile carried over from the first turn is not spent again.
+    assert_eq!(research.progress, 2 * per_turn);
+    assert_eq!(state.science, 2 * per_turn as u32);
+    assert_eq!(state.science_yield, per_turn as u32);
+}
+
+#[test]
+fn disconnected_buildings_yield_nothing_at_end_of_turn() {
+    let (data, registry) = load_game_data(PathBuf::from("assets/data")).expect("data loads");
+    let mut state = strip_colony(&data, &registry);
+    let surface = state.surface.as_mut().unwrap();
+    // Without the Base, nothing is powered.
+    surface.tiles[0].building = None;
+    update_connectivity(surface, &data, &registry);
+    state.food = 10;
+    state.production = 10;
+    let mut research = ResearchQueue::default();
+
+    apply_turn_production(&mut state, &mut research, &data, &registry);
+
+    assert_eq!((state.food, state.production, state.science), (10, 10, 0));
+    assert_eq!(research.progress, 0);
+}
+
+#[test]
+fn overcrowding_reduces_production() {
+    let (data, registry) = data_with_base(0, 12);
+    let mut research = ResearchQueue::default();
+
+    let mut housed = new_colony(&data, &registry, 3);
+    apply_turn_production(&mut housed, &mut research, &data, &registry);
+    assert_eq!(housed.production, 12);
+
+    // 5 people in 3 housing: 12 - floor(12 * 20%) = 10
+    let mut crowded = new_colony(&data, &registry, 5);
+    apply_turn_production(&mut crowded, &mut research, &data, &registry);
+    assert_eq!(crowded.production, 10);
+}
+
+#[test]
+fn stockpile_overflow_is_discarded() {
+    let (data, registry) = data_with_base(5, 1);
+    let mut state = new_colony(&data, &registry, 1);
+    state.food = BASE_STOCKPILE_CAP - 2;
+    let mut research = ResearchQueue::default();
+
+    apply_turn_production(&mut state, &mut research, &data, &registry);
+    assert_eq!(state.max_food, BASE_STOCKPILE_CAP);
+    assert_eq!(state.food, BASE_STOCKPILE_CAP);
+
+    apply_turn_production(&mut state, &mut research, &data, &registry);
+    assert_eq!(state.food, BASE_STOCKPILE_CAP);
+}
+
+#[test]
+fn warehouse_raises_the_food_cap() {
+    let (data, registry) = load_game_data(PathBuf::from("assets/data")).expect("data loads");
+    let mut state = new_colony(&data, &registry, 1);
+    let tile = state
+        .surface
+        .as_mut()
+        .unwrap()
+        .tiles
+        .iter_mut()
+        .find(|tile| tile.color == TileColor::White && tile.building.is_none())
+        .expect("free white tile");
+    tile.building = Some(BuildingType::Warehouse);
+    state.food = BASE_STOCKPILE_CAP;
+    let mut research = ResearchQueue::default();
+
+    apply_turn_production(&mut state, &mut research, &data, &registry);
+
+    assert_eq!(state.max_food, BASE_STOCKPILE_CAP + 100);
+    assert_eq!(state.max_production, BASE_STOCKPILE_CAP);
+    assert_eq!(state.food, BASE_STOCKPILE_CAP + 1);
+}
+
+#[test]
+fn upgrading_pays_the_cost_difference() {
+    let (data, registry) = load_game_data(PathBuf::from("assets/data")).expect("data loads");
+    let mut state = new_colony(&data, &registry, 1);
+    let index = state
+        .surface
+        .as_ref()
+        .unwrap()
+        .tiles
+        .iter()
+        .position(|tile| tile.color == TileColor::White && tile.building.is_none())
+        .expect("free white tile");
+    state.surface.as_mut().unwrap().tiles[index].building = Some(BuildingType::Farm);
+
+    // Farm 2 costs 120, Farm 1 costs 50.
+    state.production = 69;
+    assert_eq!(
+        upgrade_building(&mut state, index, &data, &registry),
+        Err(UpgradeError::NotEnoughProduction {
+            needed: 70,
+            available: 69
+        })
+    );
+
+    state.production = 75;
+    assert_eq!(
+        upgrade_building(&mut state, index, &data, &registry),
+        Ok(BuildingType::Farm2)
+    );
+    assert_eq!(state.production, 5);
+    assert!(matches!(
+        upgrade_building(&mut state, index, &data, &registry),
+        Err(UpgradeError::NoUpgrade(_))
+    ));
+}
+
+#[test]
+fn upgrading_recomputes_stockpile_caps() {
+    let (mut data, _) = load_game_data(PathBuf::from("assets/data")).expect("data loads");
+    data.surface_buildings
+        .iter_mut()
+        .find(|b| b.id == "building_farm_2")
+        .expect("farm 2 is defined")
+        .special_behavior = SpecialBehavior::StorageBonus {
+        resource: ResourceKind::Food,
+        amount: 50,
+    };
+    let registry = GameRegistry::from_game_data(&data).expect("registry builds");
+    let mut state = strip_colony(&data, &registry);
+    state.production = 100;
+
+    assert_eq!(
+        upgrade_building(&mut state, 1, &data, &registry),
+        Ok(BuildingType::Farm2)
+    );
+    assert_eq!(state.max_food, BASE_STOCKPILE_CAP + 50);
+    assert!(state.surface.as_ref().unwrap().tiles[1].connected);
+}
+
+#[test]
+fn base_and_empty_tiles_cannot_be_demolished() {
+    let (data, registry) = load_game_data(PathBuf::from("assets/data")).expect("data loads");
+    let mut state = strip_colony(&data, &registry);
+
+    assert_eq!(
+        demolish_building(&mut state, 0, &data, &registry),
+        Err(DemolishError::CannotDemolishBase)
+    );
+    assert_eq!(
+        demolish_building(&mut state, 4, &data, &registry),
+        Err(DemolishError::NoBuilding(4))
+    );
+}
+
+#[test]
+fn demolish_preview_lists_tiles_losing_power() {
+    let (data, registry) = load_game_data(PathBuf::from("assets/data")).expect("data loads");
+    let state = strip_colony(&data, &registry);
+
+    let preview = demolish_preview(&state, 1, &data, &registry).expect("farm can be demolished");
+
+    // Tile 1 stays next to the Base; the farm beyond it and its neighbor go dark.
+    assert_eq!(preview.disconnected_tiles, vec![2, 3]);
+    assert_eq!(preview.building, BuildingType::Farm);
+    assert!(state.surface.as_ref().unwrap().tiles[2].connected);
+}
+
+#[test]
+fn demolishing_refunds_half_the_cost() {
+    let (data, registry) = load_game_data(PathBuf::from("assets/data")).expect("data loads");
+    let mut state = strip_colony(&data, &registry);
+    state.production = 10;
+
+    // Farm 1 costs 50.
+    let preview = demolish_building(&mut state, 2, &data, &registry).expect("farm demolished");
+
+    assert_eq!(preview.refund, 25);
+    assert_eq!(state.production, 35);
+    let surface = state.surface.as_ref().unwrap();
+    assert_eq!(surface.tiles[2].building, None);
+    assert!(!surface.tiles[3].connected);
+}
+
+#[test]
+fn adjacency_bonus_counts_each_connected_neighbor() {
+    let (data, registry) = load_game_data(PathBuf::from("assets/data")).expect("data loads");
+    let mut state = strip_colony(&data, &registry);
+    let surface = state.surface.as_mut().unwrap();
+    surface.tiles[3].building = Some(BuildingType::Habitat);
+    update_connectivity(surface, &data, &registry);
+
+    // Only the farm on tile 2 touches the habitat.
+    let bonus = calculate_adjacency_bonuses(surface, data.adjacency_bonuses());
+    assert_eq!(
+        bonus,
+        YieldTotals {
+            food: 1,
+            ..Default::default()
+        }
+    );
+
+    // Disconnected buildings earn nothing.
+    surface.tiles[2].connected = false;
+    assert!(calculate_adjacency_bonuses(surface, data.adjacency_bonuses()).is_zero());
+}
+
+#[test]
+fn adjacency_bonus_applies_to_every_upgrade_level() {
+    let (data, registry) = load_game_data(PathBuf::from("assets/data")).expect("data loads");
+    let mut state = strip_colony(&data, &registry);
+    let surface = state.surface.as_mut().unwrap();
+    surface.tiles[2].building = Some(BuildingType::Farm2);
+    surface.tiles[3].building = Some(BuildingType::Habitat2);
+    update_connectivity(surface, &data, &registry);
+
+    assert_eq!(
+        calculate_adjacency_bonuses(surface, data.adjacency_bonuses()),
+        YieldTotals {
+            food: 1,
+            ..Default::default()
+        }
+    );
+}
+
+#[test]
+fn placement_bonus_includes_bonuses_granted_to_neighbors() {
+    let (data, registry) = load_game_data(PathBuf::from("assets/data")).expect("data loads");
+    let state = strip_colony(&data, &registry);
+    let surface = state.surface.as_ref().unwrap();
+    let rules = data.adjacency_bonuses();
+
+    let habitat = placement_adjacency_bonus(surface, 3, BuildingType::Habitat, rules);
+    assert_eq!(habitat.to_string(), "+1 food");
+    assert!(placement_adjacency_bonus(surface, 3, BuildingType::Passage, rules).is_zero());
+}
+
+#[test]
+fn single_kind_colony_is_specialized() {
+    let (data, registry) = load_game_data(PathBuf::from("assets/data")).expect("data loads");
+    let mut state = strip_colony(&data, &registry);
+    let surface = state.surface.as_mut().unwrap();
+    // Passages do not count against specialization.
+    surface.tiles[3].building = Some(BuildingType::Passage);
+
+    assert_eq!(specialization(surface), Some(BuildingKind::Food));
+    // Two farms yield 6 food; 20% of that is 1 (rounded down).
+    assert_eq!(
+        specialization_bonus(surface, &data),
+        YieldTotals {
+            food: 1,
+            ..Default::default()
+        }
+    );
+    assert_eq!(
+        breaks_specialization(surface, BuildingType::Factory),
+        Some(BuildingKind::Food)
+    );
+    assert_eq!(breaks_specialization(surface, BuildingType::Farm2), None);
+}
+
+#[test]
+fn mixed_colony_is_not_specialized() {
+    let (data, registry) = load_game_data(PathBuf::from("assets/data")).expect("data loads");
+    let mut state = strip_colony(&data, &registry);
+    let surface = state.surface.as_mut().unwrap();
+    surface.tiles[3].building = Some(BuildingType::Factory);
+
+    assert_eq!(specialization(surface), None);
+    assert!(specialization_bonus(surface, &data).is_zero());
+    assert_eq!(
+        breaks_specialization(surface, BuildingType::Laboratory),
+        None
+    );
+}
+
+fn queued(tiles: &[usize]) -> PlanetViewState {
+    let mut state = PlanetViewState::default();
+    for (i, &tile) in tiles.iter().enumerate() {
+        state.production_queue.push_back(ProductionProject {
+            project_type: ProjectType::Building(BuildingType::Farm),
+            total_cost: 10,
+            progress: i as u32,
+            target_tile_index: tile,
+        });
+    }
+    state
+}
+
+fn queued_tiles(state: &PlanetViewState) -> Vec<usize> {
+    state
+        .production_queue
+        .iter()
+        .map(|project| project.target_tile_index)
+        .collect()
+}
+
+#[test]
+fn queued_project_moves_with_its_progress() {
+    let mut state = queued(&[10, 11, 12, 13]);
+
+    assert!(move_queued_project(&mut state, 3, 0));
+    assert_eq!(queued_tiles(&state), vec![13, 10, 11, 12]);
+    assert_eq!(state.production_queue[0].progress, 3);
+
+    assert!(move_queued_project(&mut state, 0, 2));
+    assert_eq!(queued_tiles(&state), vec![10, 11, 13, 12]);
+}
+
+#[test]
+fn moving_outside_the_queue_changes_nothing() {
+    let mut state = queued(&[10, 11]);
+
+    assert!(!move_queued_project(&mut state, 0, 2));
+    assert!(!move_queued_project(&mut state, 5, 0));
+    assert_eq!(queued_tiles(&state), vec![10, 11]);
+}
+
+fn planet_event(
+    id: &str,
+    trigger_chance: f32,
+    min_turn: u32,
+    effect: PlanetEventEffect,
+) -> PlanetEvent {
+    PlanetEvent {
+        id: id.to_string(),
+        name_en: id.to_string(),
+        description_en: String::new(),
+        trigger_chance,
+        min_turn,
+        effect,
+    }
+}
+
+#[test]
+fn planet_events_respect_min_turn_and_fire_once() {
+    let events = [
+        planet_event("event_sure", 1.0, 3, PlanetEventEffect::ResearchBoost(5)),
+        planet_event("event_never", 0.0, 0, PlanetEventEffect::ResearchBoost(5)),
+    ];
+    let mut state = PlanetViewState {
+        turn: 2,
+        ..Default::default()
+    };
+
+    assert!(roll_planet_events(&state, &events, &mut planet_event_rng(0, 2)).is_empty());
+
+    state.turn = 3;
+    let fired = roll_planet_events(&state, &events, &mut planet_event_rng(0, 3));
+    assert_eq!(fired, vec![&events[0]]);
+
+    state.fired_event_ids.insert("event_sure".to_string());
+    assert!(roll_planet_events(&state, &events, &mut planet_event_rng(0, 4)).is_empty());
+}
+
+#[test]
+fn planet_event_rolls_are_seeded_by_game_and_turn() {
+    let events: Vec<PlanetEvent> = (0..20)
+        .map(|i| {
+            planet_event(
+                &format!("event_{i}"),
+                0.5,
+                0,
+                PlanetEventEffect::ResearchBoost(1),
+            )
+        })
+        .collect();
+    let state = PlanetViewState::default();
+
+    let first = roll_planet_events(&state, &events, &mut planet_event_rng(1, 7));
+    let second = roll_planet_events(&state, &events, &mut planet_event_rng(1, 7));
+    let other_game = roll_planet_events(&state, &events, &mut planet_event_rng(2, 7));
+
+    assert_eq!(first, second);
+    assert_ne!(first, other_game);
+}
+
+#[test]
+fn resource_events_stay_within_stockpile_caps() {
+    let (data, registry) = load_game_data(PathBuf::from("assets/data")).expect("data loads");
+    let mut state = PlanetViewState {
+        food: 90,
+        max_food: 100,
+        science: 10,
+        max_science: 100,
+        ..Default::default()
+    };
+
+    let harvest = planet_event(
+        "event_harvest",
+        1.0,
+        0,
+        PlanetEventEffect::ResourceBonus {
+            kind: ResourceKind::Food,
+            amount: 20,
+        },
+    );
+    assert!(apply_planet_event(&mut state, &harvest, &data, &registry));
+    assert_eq!(state.food, 100);
+    assert!(state.fired_event_ids.contains("event_harvest"));
+
+    let loss = planet_event("event_loss", 1.0, 0, PlanetEventEffect::ResearchBoost(-25));
+    assert!(apply_planet_event(&mut state, &loss, &data, &registry));
+    assert_eq!(state.science, 0);
+}
+
+#[test]
+fn blocked_tile_must_be_empty_and_white() {
+    let (data, registry) = load_game_data(PathBuf::from("assets/data")).expect("data loads");
+    let mut state = strip_colony(&data, &registry);
+    state.production_queue = queued(&[3]).production_queue;
+
+    let on_base = planet_event("event_base", 1.0, 0, PlanetEventEffect::TileBlocked(0, 0));
+    assert!(!apply_planet_event(&mut state, &on_base, &data, &registry));
+    assert!(state.fired_event_ids.is_empty());
+
+    let rockslide = planet_event(
+        "event_rockslide",
+        1.0,
+        0,
+        PlanetEventEffect::TileBlocked(3, 0),
+    );
+    assert!(apply_planet_event(&mut state, &rockslide, &data, &registry));
+    let surface = state.surface.as_ref().unwrap();
+    assert_eq!(surface.tiles[3].color, TileColor::Black);
+    assert!(!surface.tiles[3].connected);
+    assert!(state.production_queue.is_empty());
+}
+
+/// Shipped data with the first technology made as dangerous as `chance`,
+/// and a queue researching it with `progress` science spent.
+fn dangerous_research(chance: f32, progress: i32) -> (GameData, ResearchQueue) {
+    let (mut data, _) = load_game_data(PathBuf::from("assets/data")).expect("data loads");
+    data.technologies[0].accident_chance = chance;
+    let research = ResearchQueue {
+        active: Some(TechnologyId::from(data.technologies[0].id.as_str())),
+        progress,
+        ..Default::default()
+    };
+    (data, research)
+}
+
+#[test]
+fn research_accidents_only_hit_dangerous_technologies() {
+    let (safe, research) = dangerous_research(0.0, 0);
+    assert!(roll_research_accident(&research, &safe, &mut research_accident_rng(0, 1)).is_none());
+
+    let (data, research) = dangerous_research(1.0, 0);
+    assert!(roll_research_accident(&research, &data, &mut research_accident_rng(0, 1)).is_some());
+    assert!(
+        roll_research_accident(
+            &ResearchQueue::default(),
+            &data,
+            &mut research_accident_rng(0, 1)
+        )
+        .is_none()
+    );
+}
+
+#[test]
+fn research_accident_loses_progress_and_penalizes_science() {
+    let (data, mut research) = dangerous_research(0.5, 80);
+    let mut state = PlanetViewState::default();
+    // Find a turn whose seeded roll hits the 50% chance.
+    let turn = (1..100)
+        .find(|&turn| {
+            roll_research_accident(&research, &data, &mut research_accident_rng(0, turn)).is_some()
+        })
+        .expect("some turn rolls an accident");
+    let tech = roll_research_accident(&research, &data, &mut research_accident_rng(0, turn))
+        .expect("the same seed rolls the same accident");
+
+    let lost = apply_research_accident(&mut state, &mut research, tech);
+
+    // 30% of the 100 science cost.
+    assert_eq!(lost, 30);
+    assert_eq!(research.progress, 50);
+    assert_eq!(
+        state.science_penalty,
+        Some(SciencePenalty {
+            remaining_turns: ACCIDENT_PENALTY_TURNS,
+            amount: 20,
+        })
+    );
+
+    // Progress never goes negative.
+    research.progress = 10;
+    assert_eq!(apply_research_accident(&mut state, &mut research, tech), 10);
+    assert_eq!(research.progress, 0);
+}
+
+#[test]
+fn science_penalty_wears_off() {
+    let mut state = PlanetViewState {
+        science_penalty: Some(SciencePenalty {
+            remaining_turns: 2,
+            amount: 20,
+        }),
+        ..Default::default()
+    };
+
+    assert_eq!(apply_science_penalty(&mut state, 50), 40);
+    assert_eq!(apply_science_penalty(&mut state, 50), 40);
+    assert_eq!(state.science_penalty, None);
+    assert_eq!(apply_science_penalty(&mut state, 50), 50);
+}
+
+#[test]
+fn deposits_are_revealed_once_when_connected() {
+    let (data, registry) = load_game_data(PathBuf::from("assets/data")).expect("data loads");
+    let mut state = strip_colony(&data, &registry);
+    let surface = state.surface.as_mut().unwrap();
+    surface.tiles[3].deposit = Some(ResourceDepositKind::RareEarths(2));
+    surface.tiles[4].deposit = Some(ResourceDepositKind::IronOre(3));
+
+    // Tile 3 is already next to the farm on tile 2, tile 4 is not.
+    assert_eq!(update_connectivity(surface, &data, &registry), vec![3]);
+    assert!(!surface.tiles[4].deposit_revealed);
+    assert_eq!(
+        update_connectivity(surface, &data, &registry),
+        Vec::<usize>::new()
+    );
+
+    surface.tiles[3].building = Some(BuildingType::Farm);
+    assert_eq!(update_connectivity(surface, &data, &registry), vec![4]);
+    assert_eq!(
+        deposit_bonus(surface),
+        YieldTotals {
+            production: 3,
+            science: 2,
+            ..Default::default()
+        }
+    );
+}
+
+#[test]
+fn disconnected_deposits_yield_nothing() {
+    let (data, registry) = load_game_data(PathBuf::from("assets/data")).expect("data loads");
+    let mut state = strip_colony(&data, &registry);
+    let surface = state.surface.as_mut().unwrap();
+    surface.tiles[3].deposit = Some(ResourceDepositKind::FertileLand(1));
+    update_connectivity(surface, &data, &registry);
+    assert_eq!(deposit_bonus(surface).food, 1);
+
+    // Losing the farm on tile 2 cuts tile 3 off; the deposit stays known.
+    surface.tiles[2].building = None;
+    update_connectivity(surface, &data, &registry);
+    assert!(surface.tiles[3].deposit_revealed);
+    assert!(deposit_bonus(surface).is_zero());
+}
+
+#[test]
+fn planet_yield_of_base_only_planet_is_base_yields() {
+    let (data, registry) = load_game_data(PathBuf::from("assets/data")).expect("data loads");
+    let mut surface = PlanetSurface::new(3, 1);
+    for tile in &mut surface.tiles {
+        tile.color = TileColor::White;
+    }
+    surface.tiles[1].building = Some(BuildingType::Base);
+    update_connectivity(&mut surface, &data, &registry);
+
+    let base = registry.surface_building(&data, "building_base").unwrap();
+    assert_eq!(
+        calculate_planet_yield(&surface, &data, &registry),
+        YieldTotals {
+            food: base.yields_food,
+            housing: base.yields_housing,
+            production: base.yields_production,
+            science: base.yields_science,
+        }
+    );
+}
+
+#[test]
+fn planet_yield_ignores_disconnected_buildings() {
+    let (data, registry) = load_game_data(PathBuf::from("assets/data")).expect("data loads");
+    let mut surface = PlanetSurface::new(3, 1);
+    for tile in &mut surface.tiles {
+        tile.color = TileColor::White;
+    }
+    surface.tiles[0].building = Some(BuildingType::Base);
+    update_connectivity(&mut surface, &data, &registry);
+    let base_only = calculate_planet_yield(&surface, &data, &registry);
+
+    // The middle tile is Black, so the factory on the far side has no power.
+    surface.tiles[1].color = TileColor::Black;
+    surface.tiles[2].building = Some(BuildingType::Factory);
+    update_connectivity(&mut surface, &data, &registry);
+    assert!(!surface.tiles[2].connected);
+
+    assert_eq!(
+        calculate_planet_yield(&surface, &data, &registry),
+        base_only
+    );
+}
+
+#[test]
+fn planet_yield_sums_food_buildings() {
+    let (data, registry) = load_game_data(PathBuf::from("assets/data")).expect("data loads");
+    let state = strip_colony(&data, &registry);
+    let surface = state.surface.as_ref().unwrap();
+
+    let base = registry.surface_building(&data, "building_base").unwrap();
+    let farm = registry.surface_building(&data, "building_farm_1").unwrap();
+    let bonus = specialization_bonus(surface, &data);
+    let yields = calculate_planet_yield(surface, &data, &registry);
+    assert_eq!(
+        yields.food,
+        base.yields_food + 2 * farm.yields_food + bonus.food
+    );
+    assert_eq!(yields.production, base.yields_production);
+}
+
+/// Base and two Barracks on a 3x1 strip, with `housing` capacity.
+fn barracks_colony(housing: u32) -> PlanetViewState {
+    let mut surface = PlanetSurface::new(3, 1);
+    for tile in &mut surface.tiles {
+        tile.color = TileColor::White;
+        tile.connected = true;
+    }
+    surface.tiles[0].building = Some(BuildingType::Base);
+    surface.tiles[1].building = Some(BuildingType::Barracks);
+    surface.tiles[2].building = Some(BuildingType::Barracks);
+    PlanetViewState {
+        surface: Some(surface),
+        housing,
+        ..Default::default()
+    }
+}
+
+#[test]
+fn barracks_train_ground_troops_up_to_the_housing_cap() {
+    let mut state = barracks_colony(1);
+
+    train_ground_troops(&mut state);
+    assert_eq!(state.ground_troops, 2 * GROUND_TROOPS_PER_BARRACKS);
+    train_ground_troops(&mut state);
+    assert_eq!(state.ground_troops, 5, "capped at housing * 5");
+
+    state.housing = 0;
+    train_ground_troops(&mut state);
+    assert_eq!(state.ground_troops, 0);
+}
+
+#[test]
+fn disconnected_barracks_train_nothing() {
+    let mut state = barracks_colony(10);
+    for tile in &mut state.surface.as_mut().unwrap().tiles[1..] {
+        tile.connected = false;
+    }
+
+    train_ground_troops(&mut state);
+    assert_eq!(state.ground_troops, 0);
+}
@@ -0,0 +1,33 @@
+//! Ground troops trained by Barracks.
+//!
+//! Every connected Barracks adds [`GROUND_TROOPS_PER_BARRACKS`] troops per
+//! turn, up to [`GROUND_TROOPS_PER_HOUSING`] troops per point of housing.
+
+use crate::planet_data::BuildingType;
+use crate::planet_view::types::PlanetViewState;
+
+/// Troops each connected Barracks trains per turn.
+pub const GROUND_TROOPS_PER_BARRACKS: u32 = 2;
+
+/// Troops the colony can keep per point of housing.
+pub const GROUND_TROOPS_PER_HOUSING: u32 = 5;
+
+/// Most troops the colony can keep.
+pub fn ground_troop_cap(state: &PlanetViewState) -> u32 {
+    state.housing.saturating_mul(GROUND_TROOPS_PER_HOUSING)
+}
+
+/// Train one turn of troops in the connected Barracks.
+///
+/// Troops above the cap (e.g. after housing was lost) are dismissed.
+pub fn train_ground_troops(state: &mut PlanetViewState) {
+    let barracks = state.surface.as_ref().map_or(0, |surface| {
+        surface
+            .tiles
+            .iter()
+            .filter(|tile| tile.connected && tile.building == Some(BuildingType::Barracks))
+            .count() as u32
+    });
+    let trained = state.ground_troops + barracks * GROUND_TROOPS_PER_BARRACKS;
+    state.ground_troops = trained.min(ground_troop_cap(state));
+}
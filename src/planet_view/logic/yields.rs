@@ -0,0 +1,46 @@
+//! Per-turn yields of a whole planet surface.
+
+use bevy::log::warn;
+
+use crate::data_types::{GameData, GameRegistry};
+use crate::planet_data::PlanetSurface;
+
+use super::{YieldTotals, calculate_adjacency_bonuses, deposit_bonus, specialization_bonus};
+
+/// Yields of every connected building on `surface`, looked up in the
+/// [`GameRegistry`], plus the adjacency, specialization and deposit bonuses.
+///
+/// Disconnected buildings contribute nothing.
+pub fn calculate_planet_yield(
+    surface: &PlanetSurface,
+    game_data: &GameData,
+    registry: &GameRegistry,
+) -> YieldTotals {
+    let mut totals = YieldTotals::default();
+    for tile in surface.tiles.iter().filter(|tile| tile.connected) {
+        let Some(building) = tile.building else {
+            continue;
+        };
+        match registry.surface_building(game_data, building.id()) {
+            Some(def) => {
+                totals.food += def.yields_food;
+                totals.housing += def.yields_housing;
+                totals.production += def.yields_production;
+                totals.science += def.yields_science;
+            }
+            None => warn!("Missing building definition for ID: {}", building.id()),
+        }
+    }
+
+    for bonus in [
+        calculate_adjacency_bonuses(surface, game_data.adjacency_bonuses()),
+        specialization_bonus(surface, game_data),
+        deposit_bonus(surface),
+    ] {
+        totals.food += bonus.food;
+        totals.housing += bonus.housing;
+        totals.production += bonus.production;
+        totals.science += bonus.science;
+    }
+    totals
+}
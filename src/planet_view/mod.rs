@@ -4,11 +4,11 @@
 //! - View the planet's surface as a 3D grid of tiles
 //! - Place buildings on connected tiles
 //! - Manage the production queue
-//! - Track resource yields (Food, Housing, Production, Science)
+//! - Track resource yields (Food, Housing, Production, Science) and population
 //!
 //! # Module Structure
 //!
-//! - [`logic`] - Pure game logic (connectivity algorithm)
+//! - [`logic`] - Pure game logic (connectivity algorithm, turn economy)
 //! - [`setup`] - Scene initialization (3D meshes, UI layout)
 //! - [`systems`] - Bevy ECS systems (input, rendering, game loop)
 //! - [`types`] - Data structures and component definitions
@@ -26,12 +26,24 @@
 pub mod logic;
 mod setup;
 mod systems;
-mod types;
+#[cfg(test)]
+pub(crate) mod test_support;
+pub mod types;
 pub mod ui;
 
+pub use setup::DEFAULT_SCENARIO_ID;
+
 use crate::main_menu::GameState;
+use crate::research::ResearchQueue;
 
-use crate::planet_view::types::{PlanetViewState, TileUpdateEvent};
+use crate::planet_view::types::{
+    BuildingCompleted, DepositDiscovered, PlanetViewState, ResearchAccident, TechUnlocked,
+    TileUpdateEvent, TurnEnded,
+};
+use crate::planet_view::ui::context_menu::ContextMenuState;
+use crate::planet_view::ui::notification::NotificationQueue;
+use crate::planet_view::ui::notification_log::{NotificationLog, NotificationLogPanelState};
+use crate::planet_view::ui::production_queue::QueueDragState;
 use bevy::prelude::*;
 
 /// Plugin that manages the planet view screen.
@@ -45,8 +57,24 @@ impl Plugin for PlanetViewPlugin {
         app
             // Initialize the planet state resource with defaults
             .init_resource::<PlanetViewState>()
+            // The player's researched and queued technologies
+            .init_resource::<ResearchQueue>()
             // Register the tile update event for visual refresh
             .add_message::<TileUpdateEvent>()
+            // Announce resolved turns to other simulations (e.g. AI species)
+            .add_message::<TurnEnded>()
+            // Finished buildings and technologies, shown as notifications
+            .add_message::<BuildingCompleted>()
+            .add_message::<TechUnlocked>()
+            // Discovered resource deposits, shown as notifications
+            .add_message::<DepositDiscovered>()
+            // Research accidents, shown as notifications
+            .add_message::<ResearchAccident>()
+            .init_resource::<NotificationQueue>()
+            .init_resource::<NotificationLog>()
+            .init_resource::<NotificationLogPanelState>()
+            .init_resource::<QueueDragState>()
+            .init_resource::<ContextMenuState>()
             // Setup: Run once when entering planet view
             .add_systems(OnEnter(GameState::PlanetView), setup::setup_planet_view)
             // Cleanup: Run once when leaving planet view
@@ -60,12 +88,85 @@ impl Plugin for PlanetViewPlugin {
                     systems::update_visuals_system,      // Refresh tile meshes
                     systems::update_connectivity_system, // Recalculate power grid
                     systems::update_ui_system,           // Update stat display
-                    systems::update_production_queue_ui, // Update queue display
+                    systems::update_population_bar_system, // Update capacity bar
+                    systems::update_specialization_badge_system, // Specialized: ... badge
+                    systems::update_island_count_system, // Surface: N islands
+                    systems::update_victory_progress_system, // Victory progress bars
+                    ui::production_queue::update_production_queue_ui, // Update queue display
                     ui::build_menu::update_build_menu,   // Show/hide build menu
                     ui::build_menu::build_menu_interaction, // Handle menu clicks
+                    ui::build_menu::update_demolish_dialog, // Show/hide demolish dialog
+                    ui::build_menu::demolish_dialog_interaction, // Confirm demolition
+                    ui::notification::tech_notification_system, // Queue research news
+                    ui::notification::building_notification_system, // Queue construction news
+                    ui::notification::update_notification_panel, // Show front notification
+                    ui::notification::notification_button_system, // Dismiss / tech tree
                     systems::configure_ui_camera,        // Layer UI over 3D
                 )
                     .run_if(in_state(GameState::PlanetView)),
+            )
+            // Production queue reordering by drag-and-drop or keyboard
+            .add_systems(
+                Update,
+                (
+                    ui::production_queue::queue_selection_system,
+                    ui::production_queue::queue_press_system,
+                    ui::production_queue::queue_drop_system,
+                    ui::production_queue::queue_keyboard_system,
+                    ui::production_queue::queue_ghost_system,
+                )
+                    .chain()
+                    .run_if(in_state(GameState::PlanetView)),
+            )
+            // Random planet events, rolled once the turn has been resolved
+            .add_systems(
+                Update,
+                systems::planet_event_system
+                    .after(systems::ui_action_system)
+                    .run_if(in_state(GameState::PlanetView)),
+            )
+            // Resource deposits: news of discoveries and their 3D markers
+            .add_systems(
+                Update,
+                (
+                    ui::notification::deposit_notification_system,
+                    systems::update_deposit_markers_system,
+                )
+                    .run_if(in_state(GameState::PlanetView)),
+            )
+            // Research accidents, rolled once the turn has been resolved
+            .add_systems(
+                Update,
+                systems::research_accident_system
+                    .after(systems::ui_action_system)
+                    .run_if(in_state(GameState::PlanetView)),
+            )
+            // Turns remaining in games with a turn limit
+            .add_systems(
+                Update,
+                systems::update_turn_limit_system.run_if(in_state(GameState::PlanetView)),
+            )
+            // Notification history panel
+            .add_systems(
+                Update,
+                (
+                    ui::notification_log::notification_log_toggle_system,
+                    ui::notification_log::notification_log_eviction_system,
+                    ui::notification_log::update_notification_log_panel,
+                    ui::notification_log::notification_log_row_system,
+                    ui::notification_log::notification_log_scroll_system,
+                )
+                    .run_if(in_state(GameState::PlanetView)),
+            )
+            // Right-click context menu on tiles
+            .add_systems(
+                Update,
+                (
+                    ui::context_menu::context_menu_interaction,
+                    ui::context_menu::update_context_menu,
+                )
+                    .chain()
+                    .run_if(in_state(GameState::PlanetView)),
             );
     }
 }
@@ -9,9 +9,11 @@
 mod overlay;
 mod scene;
 
-use crate::planet_data::generate_planet;
+use crate::planet_data::{generate_planet, generate_scenario_planet};
 use crate::planet_view::types::PlanetViewState;
-use crate::planet_view::logic::update_connectivity;
+use crate::planet_view::logic::{
+    calculate_planet_yield, update_connectivity, update_stockpile_caps,
+};
 use crate::data_types::GameData;
 use crate::data_types::GameRegistry;
 use bevy::prelude::*;
@@ -19,21 +21,23 @@ use bevy::prelude::*;
 use self::overlay::setup_ui_overlay;
 use self::scene::setup_scene;
 
+/// Scenario used to generate the player's planet for a new game.
+pub const DEFAULT_SCENARIO_ID: &str = "scenario_mvp";
+
 /// Main setup system for the Planet View screen.
 ///
 /// This system runs on entering `GameState::PlanetView` and:
-/// 1. Generates a new planet surface with a fixed seed
-/// 2. Calculates initial resource yields from the Base building
-/// 3. Initializes connectivity (determines which tiles are "powered")
+/// 1. Generates a new planet surface with a fixed seed, using the grid size
+///    and black ratio of [`DEFAULT_SCENARIO_ID`] (skipped when a session is already in progress, e.g. after loading a
+///    save game or returning from the tech tree)
+/// 2. Initializes connectivity (determines which tiles are "powered")
+/// 3. Calculates initial resource yields with [`calculate_planet_yield`]
 /// 4. Spawns the 3D scene (camera, lights, tiles, buildings)
 /// 5. Spawns the 2D UI overlay (resource bars, controls)
 ///
 /// # Resource Initialization
-/// The Base building provides initial yields:
-/// - Food: +1
-/// - Housing: +3
-/// - Production: +1
-/// - Science: +1
+/// The colony starts with one turn's worth of yields in its stockpiles,
+/// which with the shipped data is what the Base building provides.
 pub fn setup_planet_view(
     mut commands: Commands,
     mut meshes: ResMut<Assets<Mesh>>,
@@ -43,37 +47,54 @@ pub fn setup_planet_view(
     game_data: Res<GameData>,
     registry: Res<GameRegistry>,
 ) {
-    // Initialize Game State
-    let mut surface = generate_planet(12345); // Fixed seed for MVP
+    // Resume an existing session as-is; "New Game" clears the surface.
+    if planet_state.surface.is_none() {
+        // Initialize Game State
+        let mut surface = match registry.scenario(&game_data, DEFAULT_SCENARIO_ID) {
+            Some(scenario) => generate_scenario_planet(12345, scenario), // Fixed seed for MVP
+            None => {
+                warn!("Scenario {DEFAULT_SCENARIO_ID} not found, using default planet");
+                generate_planet(12345, true)
+            }
+        };
 
-    // Calculate initial yields from Base
-    let mut food = 0;
-    let mut housing = 0;
-    let mut production = 0;
-    let mut science = 0;
+        // Calculate initial connectivity, then the yields of the powered tiles
+        update_connectivity(&mut surface, &game_data, &registry);
+        let yields = calculate_planet_yield(&surface, &game_data, &registry);
+        let to_u32 = |value: i32| value.max(0) as u32;
 
-    // Base provides: Food +1, Housing +3, Production +1, Science +1
-    // Since we just generated it, we know there is one Base.
-    food += 1;
-    housing += 3;
-    production += 1;
-    science += 1;
-// Calculate initial connectivity
-    update_connectivity(&mut surface, &game_data, &registry);
+        *planet_state = PlanetViewState {
+            surface: Some(surface),
+            turn: 1,
+            food: to_u32(yields.food),
+            housing: to_u32(yields.housing),
+            current_population: 1, // Colonists arriving with the Base
+            production: to_u32(yields.production),
+            science: to_u32(yields.science),
+            science_yield: 0,
+            victory: None,
+            production_queue: Default::default(),
+            build_menu_open: false,
+            build_menu_target_tile: None,
+            demolish_confirm_tile: None,
+            demolish_preview: Vec::new(),
+            // Filled in by `update_stockpile_caps` below
+            max_food: 0,
+            max_production: 0,
+            max_science: 0,
+            fired_event_ids: Default::default(),
+            prosperity_streak: 0,
+            defeat: None,
+            science_penalty: None,
+            ground_troops: 0,
+            game_seed: rand::random(),
+        };
+        update_stockpile_caps(&mut planet_state, &game_data);
+    }
 
-    *planet_state = PlanetViewState {
-        surface: Some(surface.clone()),
-        turn: 1,
-        food,
-        housing,
-        production,
-        science,
-        research_progress: 0,
-        terraforming_unlocked: false,
-        victory: false,
-        production_queue: Default::default(),
-        build_menu_open: false,
-        build_menu_target_tile: None,
+    let Some(surface) = planet_state.surface.clone() else {
+        warn!("Planet view entered without a planet surface");
+        return;
     };
 
     // Setup Scene (Grid)
@@ -2,11 +2,17 @@
 //!
 //! Creates the HUD elements that appear on top of the 3D scene:
 //! - Top bar with resource counters and turn number
-//! - Victory message (hidden until triggered)
+//! - Production queue list, reorderable by drag-and-drop, and ground troops
+//! - Victory and defeat messages (hidden until triggered)
 //! - Bottom bar with End Turn button
 
 // use crate::planet_data::BuildingType;
-use crate::planet_view::types::{PlanetViewRoot, UIAction};
+use crate::planet_view::types::{
+    DefeatMessage, IslandCountText, PlanetViewRoot, PopulationBar, RivalProgressRow,
+    SpecializationBadge, TurnLimitText, UIAction, VictoryProgressBar, VictoryProgressText,
+};
+use crate::planet_view::ui::panels::ProductionQueueList;
+use crate::ui_layout::{LayoutButton, LayoutPanel, LayoutText};
 use bevy::core_pipeline::core_2d::graph::Core2d;
 use bevy::render::camera::CameraRenderGraph;
 use bevy::prelude::*;
@@ -16,10 +22,13 @@ use bevy::prelude::*;
 /// # Layout
 /// ```text
 /// ┌────────────────────────────────────────────────────┐
-/// │ Turn: 1  Food: 0  Housing: 0  Prod: 0  Science: 0  │  ← Top Bar
+/// │ Turn: 1  Food: 0  ...  Research: idle  Queue: empty │  ← Top Bar
 /// ├────────────────────────────────────────────────────┤
-/// │                                                    │
-/// │                  3D Scene Area                     │
+/// │ ┌──────────────┐                                   │
+/// │ │ Production   │  ← Queue (drag to reorder)        │
+/// │ │ :: Farm 2/10 │                                   │
+/// │ │ Troops: 4    │                                   │
+/// │ └──────────────┘  3D Scene Area                    │
 /// │                                                    │
 /// │         ┌──────────────────────────┐               │
 /// │         │  VICTORY! (hidden)       │               │  ← Victory Message
@@ -27,7 +36,7 @@ use bevy::prelude::*;
 /// │         └──────────────────────────┘               │
 /// │                                                    │
 /// ├────────────────────────────────────────────────────┤
-/// │              [End Turn]                            │  ← Bottom Bar
+/// │              [End Turn] [Research]                 │  ← Bottom Bar
 /// └────────────────────────────────────────────────────┘
 /// ```
 ///
@@ -35,6 +44,8 @@ use bevy::prelude::*;
 /// - `PlanetViewRoot` - Marker for cleanup on screen exit
 /// - `VictoryMessage` - Hidden message shown when victory condition met
 /// - `UIAction::EndTurn` - Button to advance the turn
+/// - `UIAction::OpenTechTree` - Button to open the tech tree
+/// - `UIAction::OpenDiplomacy` - Button to show known species
 /// - `UIAction::Quit` - Button to return to main menu
 pub fn setup_ui_overlay(commands: &mut Commands) {
     // 2D Camera for UI overlay
@@ -71,11 +82,93 @@ pub fn setup_ui_overlay(commands: &mut Commands) {
             ))
             .with_children(|top| {
                 spawn_text(top, "Turn: 1");
+                top.spawn((
+                    Text::new(""),
+                    TextFont {
+                        font_size: 20.0,
+                        ..default()
+                    },
+                    TextColor(Color::srgb(1.0, 0.6, 0.1)),
+                    Node {
+                        display: Display::None,
+                        ..default()
+                    },
+                    LayoutText,
+                    TurnLimitText,
+                ));
                 spawn_text(top, "Food: 0");
                 spawn_text(top, "Housing: 0");
+                spawn_text(top, "Pop: 0/0");
+                spawn_population_bar(top);
                 spawn_text(top, "Prod: 0");
                 spawn_text(top, "Science: 0");
-                spawn_text(top, "Research: 0/100"); // Placeholder
+                spawn_text(top, "Research: idle (0)");
+                spawn_text(top, "Queue: empty");
+                top.spawn((
+                    Text::new("Surface: 1 island"),
+                    TextFont {
+                        font_size: 20.0,
+                        ..default()
+                    },
+                    TextColor(Color::WHITE),
+                    LayoutText,
+                    IslandCountText,
+                ));
+                spawn_victory_progress(top);
+                top.spawn((
+                    Text::new(""),
+                    TextFont {
+                        font_size: 16.0,
+                        ..default()
+                    },
+                    TextColor(Color::srgb(1.0, 0.85, 0.3)),
+                    Node {
+                        display: Display::None,
+                        ..default()
+                    },
+                    SpecializationBadge,
+                ));
+            });
+
+            // Left: Production queue, filled by `update_production_queue_ui`
+            root.spawn((
+                Node {
+                    position_type: PositionType::Absolute,
+                    left: Val::Px(10.0),
+                    top: Val::Px(60.0),
+                    flex_direction: FlexDirection::Column,
+                    padding: UiRect::all(Val::Px(8.0)),
+                    row_gap: Val::Px(4.0),
+                    ..default()
+                },
+                BackgroundColor(Color::BLACK.with_alpha(0.8)),
+                LayoutPanel,
+            ))
+            .with_children(|panel| {
+                panel.spawn((
+                    Text::new("Production"),
+                    TextFont {
+                        font_size: 16.0,
+                        ..default()
+                    },
+                    TextColor(Color::WHITE),
+                ));
+                panel.spawn((
+                    Node {
+                        flex_direction: FlexDirection::Column,
+                        row_gap: Val::Px(2.0),
+                        ..default()
+                    },
+                    ProductionQueueList,
+                ));
+                panel.spawn((
+                    Text::new("Troops: 0"),
+                    TextFont {
+                        font_size: 16.0,
+                        ..default()
+                    },
+                    TextColor(Color::WHITE),
+                ));
             });
 
             // Center: Victory Message (Hidden by default)
@@ -120,6 +213,45 @@ pub fn setup_ui_overlay(commands: &mut Commands) {
                 });
             });
 
+            // Center: Defeat Message (Hidden by default)
+            root.spawn((
+                Node {
+                    position_type: PositionType::Absolute,
+                    left: Val::Percent(50.0),
+                    top: Val::Percent(50.0),
+                    margin: UiRect::all(Val::Auto),
+                    padding: UiRect::all(Val::Px(20.0)),
+                    display: Display::None,
+                    ..default()
+                },
+                BackgroundColor(Color::BLACK.with_alpha(0.9)),
+                DefeatMessage,
+            ))
+            .with_children(|msg| {
+                msg.spawn((
+                    Text::new("DEFEAT!"),
+                    TextFont {
+                        font_size: 40.0,
+                        ..default()
+                    },
+                    TextColor(Color::srgb(0.9, 0.2, 0.2)),
+                ));
+
+                msg.spawn((
+                    Button,
+                    Node {
+                        margin: UiRect::top(Val::Px(20.0)),
+                        padding: UiRect::all(Val::Px(10.0)),
+                        ..default()
+                    },
+                    BackgroundColor(Color::srgb(0.2, 0.2, 0.2)),
+                ))
+                .insert(UIAction::Quit)
+                .with_children(|btn| {
+                    btn.spawn((Text::new("Return to Menu"), TextColor(Color::WHITE)));
+                });
+            });
+
             // Bottom Bar: Controls
             root.spawn((
                 Node {
@@ -140,18 +272,135 @@ pub fn setup_ui_overlay(commands: &mut Commands) {
                         Button,
                         Node {
                             padding: UiRect::all(Val::Px(10.0)),
+                            align_items: AlignItems::Center,
                             ..default()
                         },
+                        LayoutButton,
                         BackgroundColor(Color::srgb(0.0, 0.5, 0.0)),
                     ))
                     .insert(UIAction::EndTurn)
                     .with_children(|btn| {
                         btn.spawn((Text::new("End Turn"), TextColor(Color::WHITE)));
                     });
+
+                // Research (tech tree)
+                bottom
+                    .spawn((
+                        Button,
+                        Node {
+                            padding: UiRect::all(Val::Px(10.0)),
+                            align_items: AlignItems::Center,
+                            ..default()
+                        },
+                        LayoutButton,
+                        BackgroundColor(Color::srgb(0.3, 0.3, 0.3)),
+                    ))
+                    .insert(UIAction::OpenTechTree)
+                    .with_children(|btn| {
+                        btn.spawn((Text::new("Research"), TextColor(Color::WHITE)));
+                    });
+
+                // Species (diplomacy)
+                bottom
+                    .spawn((
+                        Button,
+                        Node {
+                            padding: UiRect::all(Val::Px(10.0)),
+                            align_items: AlignItems::Center,
+                            ..default()
+                        },
+                        LayoutButton,
+                        BackgroundColor(Color::srgb(0.3, 0.3, 0.3)),
+                    ))
+                    .insert(UIAction::OpenDiplomacy)
+                    .with_children(|btn| {
+                        btn.spawn((Text::new("Species"), TextColor(Color::WHITE)));
+                    });
             });
         });
 }
 
+/// Capacity bar shown next to the population counter.
+fn spawn_population_bar(parent: &mut ChildSpawnerCommands) {
+    parent
+        .spawn((
+            Node {
+                width: Val::Px(80.0),
+                height: Val::Px(12.0),
+                border: UiRect::all(Val::Px(1.0)),
+                ..default()
+            },
+            BackgroundColor(Color::srgb(0.15, 0.15, 0.15)),
+            BorderColor::all(Color::srgb(0.5, 0.5, 0.5)),
+        ))
+        .with_children(|bar| {
+            bar.spawn((
+                Node {
+                    width: Val::Percent(0.0),
+                    height: Val::Percent(100.0),
+                    ..default()
+                },
+                BackgroundColor(Color::srgb(0.2, 0.8, 0.2)),
+                PopulationBar,
+            ));
+        });
+}
+
+/// Spawn the player's and the rival's victory progress rows.
+fn spawn_victory_progress(parent: &mut ChildSpawnerCommands) {
+    parent
+        .spawn(Node {
+            flex_direction: FlexDirection::Column,
+            row_gap: Val::Px(2.0),
+            ..default()
+        })
+        .with_children(|column| {
+            for rival in [false, true] {
+                let mut row = column.spawn(Node {
+                    align_items: AlignItems::Center,
+                    column_gap: Val::Px(6.0),
+                    display: if rival { Display::None } else { Display::Flex },
+                    ..default()
+                });
+                if rival {
+                    row.insert(RivalProgressRow);
+                }
+                row.with_children(|row| {
+                    row.spawn((
+                        Text::new(""),
+                        TextFont {
+                            font_size: 12.0,
+                            ..default()
+                        },
+                        TextColor(Color::WHITE),
+                        VictoryProgressText { rival },
+                    ));
+                    row.spawn((
+                        Node {
+                            width: Val::Px(80.0),
+                            height: Val::Px(8.0),
+                            border: UiRect::all(Val::Px(1.0)),
+                            ..default()
+                        },
+                        BackgroundColor(Color::srgb(0.15, 0.15, 0.15)),
+                        BorderColor::all(Color::srgb(0.5, 0.5, 0.5)),
+                    ))
+                    .with_children(|bar| {
+                        bar.spawn((
+                            Node {
+                                width: Val::Percent(0.0),
+                                height: Val::Percent(100.0),
+                                ..default()
+                            },
+                            BackgroundColor(Color::WHITE),
+                            VictoryProgressBar { rival },
+                        ));
+                    });
+                });
+            }
+        });
+}
+
 fn spawn_text(parent: &mut ChildSpawnerCommands, text: &str) {
     parent.spawn((
         Text::new(text),
@@ -160,5 +409,6 @@ fn spawn_text(parent: &mut ChildSpawnerCommands, text: &str) {
             ..default()
         },
         TextColor(Color::WHITE),
+        LayoutText,
     ));
 }
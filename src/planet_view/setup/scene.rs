@@ -3,7 +3,7 @@
 //! Creates the isometric camera, lighting, tile grid, buildings,
 //! and hover cursor for the planet surface visualization.
 
-use crate::planet_data::{BuildingType, PlanetSurface, TileColor};
+use crate::planet_data::{PlanetSurface, TileColor};
 use crate::planet_view::types::{BuildingEntity, PlanetView3D, TileEntity, PlanetViewAssets, PlanetViewCursor};
 use crate::data_types::GameData;
 use bevy::camera::ScalingMode;
@@ -143,15 +143,7 @@ pub fn setup_scene(
 
         // Spawn Building if present
         if let Some(building) = tile.building {
-            let building_id = match building {
-                BuildingType::Base => "building_base",
-                BuildingType::Farm => "building_farm_1",
-                BuildingType::Habitat => "building_habitat_1",
-                BuildingType::Factory => "building_factory_1",
-                BuildingType::Laboratory => "building_laboratory_1",
-                BuildingType::Passage => "building_passage",
-                BuildingType::Terraformer => "building_terraformer",
-            };
+            let building_id = building.id();
 
             if let Some(b_mat) = building_materials.get(building_id) {
                 commands.spawn((
@@ -5,8 +5,10 @@
 //!
 //! - **Lifecycle**: [`cleanup_planet_view`], [`configure_ui_camera`]
 //! - **Input**: [`ui_action_system`], [`tile_interaction_system`]
-//! - **Game Logic**: [`end_turn`], [`update_connectivity_system`]
-//! - **Rendering**: [`update_visuals_system`], [`update_ui_system`], [`update_production_queue_ui`]
+//! - **Game Logic**: [`end_turn`] (via [`apply_turn_production`]), [`update_connectivity_system`],
+//!   [`planet_event_system`]
+//! - **Rendering**: [`update_visuals_system`], [`update_ui_system`], [`update_population_bar_system`],
+//!   [`update_specialization_badge_system`], [`update_victory_progress_system`]
 //!
 //! # System Ordering
 //!
@@ -15,25 +17,52 @@
 //! 2. Game logic processes state changes
 //! 3. Visual systems update the display to match state
 
+use std::collections::HashSet;
+
 use bevy::prelude::*;
 
+use crate::ai::AiState;
 use crate::data_types::GameData;
 use crate::data_types::GameRegistry;
+use crate::data_types::PlanetEventEffect;
+use crate::data_types::TechnologyId;
+use crate::data_types::VictoryConditionId;
+use crate::data_types::VictoryType;
+use crate::diplomacy::panel::DiplomacyPanelState;
 use crate::main_menu::GameState;
-use crate::planet_data::{BuildingType, TileColor};
-use crate::planet_view::logic::update_connectivity;
+use crate::planet_data::{
+    BuildingType, PlanetSurface, ResourceDepositKind, TileColor, label_connected_components,
+};
+use crate::planet_view::DEFAULT_SCENARIO_ID;
+use crate::planet_view::logic::{
+    TurnReport, apply_planet_event, apply_research_accident, apply_turn_production,
+    demolish_preview, planet_event_rng, research_accident_rng, roll_planet_events,
+    roll_research_accident, specialization, update_connectivity, upgrade_option,
+};
 use crate::planet_view::types::{
-    BuildingEntity, PlanetView3D, PlanetViewRoot, PlanetViewState, TileEntity, TileUpdateEvent,
-    UIAction,
+    BuildingCompleted, BuildingEntity, DefeatMessage, DepositDiscovered, DepositMarker,
+    IslandCountText, PlanetView3D, PlanetViewRoot, PlanetViewState, PopulationBar,
+    ResearchAccident, RivalProgressRow, SpecializationBadge, TechUnlocked, TileEntity,
+    TileUpdateEvent, TurnEnded, TurnLimitText, UIAction, VictoryProgressBar, VictoryProgressText,
 };
-use crate::planet_view::ui::panels::ProductionQueueList;
+use crate::planet_view::ui::context_menu::{ContextMenuState, tile_context_items};
+use crate::planet_view::ui::notification::{
+    NotificationQueue, planet_event_notification, research_accident_notification,
+};
+use crate::planet_view::ui::notification_log::NotificationLog;
+use crate::research::ResearchQueue;
+use crate::screen_transition::TransitionToState;
+use crate::sfx::PlaySfx;
+use crate::victory::{VictoryRules, VictoryTracker, turns_remaining};
 
 /// Clean up all planet view entities when leaving the screen.
 pub fn cleanup_planet_view(
     mut commands: Commands,
     ui_query: Query<Entity, With<PlanetViewRoot>>,
     view_3d_query: Query<Entity, With<PlanetView3D>>,
+    mut context_menu: ResMut<ContextMenuState>,
 ) {
+    context_menu.close();
     for entity in &ui_query {
         commands.entity(entity).despawn();
     }
@@ -68,8 +97,15 @@ pub fn ui_action_system(
         (Changed<Interaction>, With<Button>),
     >,
     mut planet_state: ResMut<PlanetViewState>,
-    mut next_state: ResMut<NextState<GameState>>,
+    mut research: ResMut<ResearchQueue>,
+    mut transitions: MessageWriter<TransitionToState>,
     mut update_events: MessageWriter<TileUpdateEvent>,
+    mut turn_events: MessageWriter<TurnEnded>,
+    mut tech_events: MessageWriter<TechUnlocked>,
+    mut building_events: MessageWriter<BuildingCompleted>,
+    mut deposit_events: MessageWriter<DepositDiscovered>,
+    mut sfx: MessageWriter<PlaySfx>,
+    mut diplomacy_panel: ResMut<DiplomacyPanelState>,
     game_data: Res<GameData>,
     registry: Res<GameRegistry>,
 ) {
@@ -79,13 +115,47 @@ pub fn ui_action_system(
                 *bg_color = BackgroundColor(Color::srgb(0.5, 0.5, 0.5));
                 match action {
                     UIAction::EndTurn => {
-                        end_turn(&mut planet_state, &game_data, &registry, &mut update_events);
+                        let report = end_turn(
+                            &mut planet_state,
+                            &mut research,
+                            &game_data,
+                            &registry,
+                            &mut update_events,
+                            &mut turn_events,
+                            &mut sfx,
+                        );
+                        let surface = planet_state.surface.as_ref();
+                        building_events.write_batch(report.completed_tiles.iter().filter_map(
+                            |&tile_index| {
+                                let building = surface?.tiles.get(tile_index)?.building?;
+                                Some(BuildingCompleted {
+                                    tile_index,
+                                    building,
+                                })
+                            },
+                        ));
+                        tech_events.write_batch(
+                            report
+                                .unlocked_techs
+                                .into_iter()
+                                .map(|tech| TechUnlocked { tech }),
+                        );
+                        deposit_events.write_batch(deposits_found(
+                            planet_state.surface.as_ref(),
+                            &report.discovered_deposits,
+                        ));
                     }
                     // UIAction::OpenBuildMenu => {
                     //     info!("Open Build Menu");
                     // }
                     UIAction::Quit => {
-                        next_state.set(GameState::MainMenu);
+                        transitions.write(TransitionToState(GameState::MainMenu));
+                    }
+                    UIAction::OpenTechTree => {
+                        transitions.write(TransitionToState(GameState::TechTree));
+                    }
+                    UIAction::OpenDiplomacy => {
+                        diplomacy_panel.open = !diplomacy_panel.open;
                     }
                 }
             }
@@ -101,103 +171,64 @@ pub fn ui_action_system(
 
 /// Process the end of a game turn.
 ///
-/// This function is called when the player clicks "End Turn" and handles:
+/// This function is called when the player clicks "End Turn". The economy
+/// itself (yields, production queue, research) is resolved by
+/// [`apply_turn_production`]; this wrapper refreshes the visuals of every
+/// tile touched by completed construction and announces the new turn.
 ///
-/// 1. **Turn Counter**: Increment the turn number
-/// 2. **Resource Yields**: Sum up yields from all buildings (data-driven)
-/// 3. **Production Queue**: Apply production to the first project in queue
-/// 4. **Construction Completion**: Place buildings when projects finish
-/// 5. **Research Progress**: Accumulate science toward tech unlocks
-///
-/// # Data-Driven Yields
-///
-/// Building yields are read from `GameData.surface_buildings` rather than
-/// being hardcoded, allowing easy balancing via RON files.
+/// Returns the [`TurnReport`] so the caller can announce completed
+/// buildings and technologies.
 fn end_turn(
     state: &mut PlanetViewState,
+    research: &mut ResearchQueue,
     game_data: &GameData,
     registry: &GameRegistry,
     update_events: &mut MessageWriter<TileUpdateEvent>,
-) {
-    state.turn += 1;
+    turn_events: &mut MessageWriter<TurnEnded>,
+    sfx: &mut MessageWriter<PlaySfx>,
+) -> TurnReport {
+    let report = apply_turn_production(state, research, game_data, registry);
 
-    // Calculate yields
-    if let Some(surface) = &state.surface {
-        for tile in &surface.tiles {
-            if let Some(building) = tile.building {
-                let building_id = building.id();
-                if let Some(def) = game_data.surface_buildings.iter().find(|b| b.id == building_id) {
-                    state.food = (state.food as i32 + def.yields_food).max(0) as u32;
-                    state.housing = (state.housing as i32 + def.yields_housing).max(0) as u32;
-                    state.production = (state.production as i32 + def.yields_production).max(0) as u32;
-                    state.science = (state.science as i32 + def.yields_science).max(0) as u32;
-                } else {
-                    warn!("Missing building definition for ID: {}", building_id);
-                }
-            }
-        }
+    for tech in &report.unlocked_techs {
+        info!("Research Complete: {}", tech.as_str());
+    }
+    if !report.unlocked_techs.is_empty() {
+        sfx.write(PlaySfx::TechUnlocked);
+    }
+    if !report.completed_tiles.is_empty() {
+        sfx.write(PlaySfx::BuildingPlaced);
     }
 
-    // Process Production Queue
-    if let Some(project) = state.production_queue.front_mut() {
-        let needed = project.total_cost.saturating_sub(project.progress);
-        let available = state.production;
-        let amount = std::cmp::min(needed, available);
-
-        project.progress += amount;
-        // state.production -= amount;
-
-        if project.progress >= project.total_cost {
-            // Finished!
-            let finished_project = state.production_queue.pop_front().unwrap();
-            match finished_project.project_type {
-                crate::planet_view::types::ProjectType::Building(b_type) => {
-                    if let Some(surface) = &mut state.surface {
-                        if let Some(tile) =
-                            surface.tiles.get_mut(finished_project.target_tile_index)
-                        {
-                            tile.building = Some(b_type);
-                            info!("Construction Complete: {:?}", b_type);
-                            let x = finished_project.target_tile_index % surface.row_width;
-                            let y = finished_project.target_tile_index / surface.row_width;
-                            update_events.write(TileUpdateEvent { x, y });
-                            // Update connectivity
-                            update_connectivity(surface, game_data, registry);
-                            let width = surface.row_width;
-                            let height = surface.tiles.len() / width;
-                            if x > 0 {
-                                update_events.write(TileUpdateEvent { x: x - 1, y });
-                            }
-                            if x + 1 < width {
-                                update_events.write(TileUpdateEvent { x: x + 1, y });
-                            }
-                            if y > 0 {
-                                update_events.write(TileUpdateEvent { x, y: y - 1 });
-                            }
-                            if y + 1 < height {
-                                update_events.write(TileUpdateEvent { x, y: y + 1 });
-                            }
-                        }
-                    }
-                }
+    if let Some(surface) = &state.surface {
+        let width = surface.row_width;
+        let height = surface.height();
+        for &tile_index in &report.completed_tiles {
+            let x = tile_index % width;
+            let y = tile_index / width;
+            update_events.write(TileUpdateEvent { x, y });
+            if x > 0 {
+                update_events.write(TileUpdateEvent { x: x - 1, y });
+            }
+            if x + 1 < width {
+                update_events.write(TileUpdateEvent { x: x + 1, y });
+            }
+            if y > 0 {
+                update_events.write(TileUpdateEvent { x, y: y - 1 });
+            }
+            if y + 1 < height {
+                update_events.write(TileUpdateEvent { x, y: y + 1 });
             }
         }
     }
 
-    // Research
-    state.research_progress += state.science;
-    if state.research_progress >= 100 {
-        // Hardcoded cost
-        state.terraforming_unlocked = true;
-    }
-
-    // Reset per-turn stats if needed (Production accumulates? MVP says "Production may be used to construct buildings". "Instant construction is acceptable". So maybe Production is a currency.)
-    // "Sum yields... Production may be used to construct buildings" implies accumulation.
+    turn_events.write(TurnEnded { turn: state.turn });
 
     info!(
         "Turn ended. Food: {}, Housing: {}, Prod: {}, Sci: {}",
         state.food, state.housing, state.production, state.science
     );
+
+    report
 }
 
 /// Handle mouse interaction with the 3D tile grid.
@@ -220,17 +251,25 @@ fn end_turn(
 /// # Click Handling
 ///
 /// When left mouse button is pressed, delegates to [`handle_tile_click`]
-/// to open the build menu if the tile is valid.
+/// to open the build menu if the tile is valid. The right mouse button
+/// opens the tile's context menu instead (see [`ContextMenuState`]).
 pub fn tile_interaction_system(
     mouse: Res<ButtonInput<MouseButton>>,
     windows: Query<&Window>,
     camera_q: Query<(&Camera, &GlobalTransform), With<Camera3d>>,
     mut planet_state: ResMut<PlanetViewState>,
     tile_q: Query<(Entity, &TileEntity, &Transform)>,
-    mut cursor_q: Query<(&mut Transform, &mut Visibility), (With<crate::planet_view::types::PlanetViewCursor>, Without<TileEntity>)>,
+    mut cursor_q: Query<
+        (&mut Transform, &mut Visibility),
+        (
+            With<crate::planet_view::types::PlanetViewCursor>,
+            Without<TileEntity>,
+        ),
+    >,
     mut update_events: MessageWriter<crate::planet_view::types::TileUpdateEvent>,
     game_data: Res<GameData>,
     registry: Res<GameRegistry>,
+    mut context_menu: ResMut<ContextMenuState>,
 ) {
     let mut hovered_tile_pos = None;
     let mut hovered_tile_data = None;
@@ -250,7 +289,8 @@ pub fn tile_interaction_system(
                         for (_entity, tile, transform) in &tile_q {
                             // Ignore y difference for distance check
                             let flat_intersection = Vec3::new(intersection.x, 0.0, intersection.z);
-                            let flat_tile_pos = Vec3::new(transform.translation.x, 0.0, transform.translation.z);
+                            let flat_tile_pos =
+                                Vec3::new(transform.translation.x, 0.0, transform.translation.z);
 
                             let dist = flat_intersection.distance(flat_tile_pos);
                             if dist < closest_dist {
@@ -275,10 +315,28 @@ pub fn tile_interaction_system(
         }
     }
 
-    // Handle Click
+    // Right-click opens the context menu for the hovered tile
+    if mouse.just_pressed(MouseButton::Right) {
+        context_menu.close();
+        let cursor = windows.iter().next().and_then(Window::cursor_position);
+        if let (Some(tile_data), Some(cursor), Some(surface)) =
+            (hovered_tile_data, cursor, &planet_state.surface)
+        {
+            let target_idx = tile_data.y * surface.row_width + tile_data.x;
+            let items = tile_context_items(&planet_state, target_idx, &game_data, &registry);
+            context_menu.open(target_idx, items, cursor);
+        }
+        return;
+    }
+
+    // Handle Click (a click while the context menu is open only closes it)
     if mouse.just_pressed(MouseButton::Left) {
+        if context_menu.is_open() {
+            context_menu.close();
+            return;
+        }
         if let Some(tile_data) = hovered_tile_data {
-             handle_tile_click(
+            handle_tile_click(
                 tile_data.x,
                 tile_data.y,
                 &mut planet_state,
@@ -296,7 +354,8 @@ pub fn tile_interaction_system(
 ///
 /// # Validation Rules
 ///
-/// 1. Tile must be empty (no existing building)
+/// 1. Tile must be empty, or hold a building that can be upgraded or
+///    demolished (the menu then offers those actions instead)
 /// 2. Tile must be connected to the power grid
 ///
 /// If validation passes, opens the build menu by setting `build_menu_open = true`
@@ -306,14 +365,19 @@ fn handle_tile_click(
     y: usize,
     state: &mut PlanetViewState,
     _update_events: &mut MessageWriter<crate::planet_view::types::TileUpdateEvent>,
-    _game_data: &GameData,
-    _registry: &GameRegistry,
+    game_data: &GameData,
+    registry: &GameRegistry,
 ) {
-    if let Some(surface) = &mut state.surface {
-        let target_idx = y * surface.row_width + x;
+    let target_idx = match &state.surface {
+        Some(surface) => y * surface.row_width + x,
+        None => return,
+    };
+    let upgradable = upgrade_option(state, target_idx, game_data, registry).is_ok();
+    let demolishable = demolish_preview(state, target_idx, game_data, registry).is_ok();
 
+    if let Some(surface) = &mut state.surface {
         // Check if empty
-        if surface.get(x, y).unwrap().building.is_some() {
+        if surface.get(x, y).unwrap().building.is_some() && !upgradable && !demolishable {
             info!("Tile occupied!");
             return;
         }
@@ -399,7 +463,10 @@ pub fn update_visuals_system(
                             );
                         } else {
                             // Check if there is a construction project for this tile
-                            if let Some(project) = planet_state.production_queue.iter().find(|p| p.target_tile_index == (tile_data.y * surface.row_width + tile_data.x)) {
+                            if let Some(project) = planet_state.production_queue.iter().find(|p| {
+                                p.target_tile_index
+                                    == (tile_data.y * surface.row_width + tile_data.x)
+                            }) {
                                 match project.project_type {
                                     crate::planet_view::types::ProjectType::Building(b_type) => {
                                         spawn_building(
@@ -418,14 +485,24 @@ pub fn update_visuals_system(
 
                         // If terraformed, update tile color
                         if tile.color == TileColor::White {
+                            // Red while a demolition would disconnect the tile
+                            let tile_index = tile_data.y * surface.row_width + tile_data.x;
+                            let base_color = if planet_state.demolish_preview.contains(&tile_index)
+                            {
+                                Color::srgb(0.9, 0.3, 0.3)
+                            } else {
+                                Color::WHITE
+                            };
                             commands.entity(entity).insert(MeshMaterial3d(materials.add(
                                 StandardMaterial {
-                                    base_color: Color::WHITE,
+                                    base_color,
                                     ..default()
                                 },
                             )));
                         } else if tile.color == TileColor::Black {
-                             commands.entity(entity).insert(MeshMaterial3d(assets.black_mat.clone()));
+                            commands
+                                .entity(entity)
+                                .insert(MeshMaterial3d(assets.black_mat.clone()));
                         }
                     }
                 }
@@ -457,18 +534,14 @@ fn spawn_building(
     position: Vec3,
     is_construction: bool,
 ) {
-    let building_id = match building_type {
-        BuildingType::Base => "building_base",
-        BuildingType::Farm => "building_farm_1",
-        BuildingType::Habitat => "building_habitat_1",
-        BuildingType::Factory => "building_factory_1",
-        BuildingType::Laboratory => "building_laboratory_1",
-        BuildingType::Passage => "building_passage",
-        BuildingType::Terraformer => "building_terraformer",
-    };
+    let building_id = building_type.id();
 
     // Find color in GameData
-    let color = if let Some(def) = game_data.surface_buildings.iter().find(|b| b.id == building_id) {
+    let color = if let Some(def) = game_data
+        .surface_buildings
+        .iter()
+        .find(|b| b.id == building_id)
+    {
         let (r, g, b) = def.color;
         Color::srgb(r, g, b)
     } else {
@@ -486,14 +559,14 @@ fn spawn_building(
         Mesh3d(meshes.add(Cuboid::new(0.6, 0.6, 0.6))),
         MeshMaterial3d(materials.add(StandardMaterial {
             base_color: final_color,
-            alpha_mode: if is_construction { AlphaMode::Blend } else { AlphaMode::Opaque },
+            alpha_mode: if is_construction {
+                AlphaMode::Blend
+            } else {
+                AlphaMode::Opaque
+            },
             ..default()
         })),
-        Transform::from_xyz(
-            position.x,
-            0.4,
-            position.z,
-        ),
+        Transform::from_xyz(position.x, 0.4, position.z),
         PlanetView3D,
         BuildingEntity,
     ));
@@ -502,9 +575,10 @@ fn spawn_building(
 /// Update the resource display texts in the UI.
 ///
 /// This system finds text entities by their content prefix (e.g., "Turn:", "Food:")
-/// and updates them to reflect the current [`PlanetViewState`] values.
+/// and updates them to reflect the current [`PlanetViewState`] and
+/// [`ResearchQueue`] values.
 ///
-/// Also controls the visibility of the victory message overlay.
+/// Also controls the visibility of the victory and defeat message overlays.
 ///
 /// # Note
 ///
@@ -512,12 +586,28 @@ fn spawn_building(
 /// A proper implementation would use marker components for each stat display.
 pub fn update_ui_system(
     planet_state: Res<PlanetViewState>,
+    research: Res<ResearchQueue>,
+    game_data: Res<GameData>,
     mut text_query: Query<&mut Text>,
     mut victory_query: Query<&mut Node, With<crate::planet_view::types::VictoryMessage>>,
+    mut defeat_query: Query<
+        &mut Node,
+        (
+            With<DefeatMessage>,
+            Without<crate::planet_view::types::VictoryMessage>,
+        ),
+    >,
 ) {
     // Victory Message
     if let Some(mut node) = victory_query.iter_mut().next() {
-        node.display = if planet_state.victory {
+        node.display = if planet_state.victory.is_some() {
+            Display::Flex
+        } else {
+            Display::None
+        };
+    }
+    if let Some(mut node) = defeat_query.iter_mut().next() {
+        node.display = if planet_state.defeat.is_some() {
             Display::Flex
         } else {
             Display::None
@@ -536,81 +626,447 @@ pub fn update_ui_system(
         if text.0.starts_with("Turn:") {
             text.0 = format!("Turn: {}", planet_state.turn);
         } else if text.0.starts_with("Food:") {
-            text.0 = format!("Food: {}", planet_state.food);
+            text.0 = format!("Food: {}/{}", planet_state.food, planet_state.max_food);
         } else if text.0.starts_with("Housing:") {
             text.0 = format!("Housing: {}", planet_state.housing);
+        } else if text.0.starts_with("Pop:") {
+            text.0 = format!(
+                "Pop: {}/{}",
+                planet_state.current_population, planet_state.housing
+            );
+        } else if text.0.starts_with("Troops:") {
+            text.0 = format!("Troops: {}", planet_state.ground_troops);
         } else if text.0.starts_with("Prod:") {
-            text.0 = format!("Prod: {}", planet_state.production);
+            text.0 = format!(
+                "Prod: {}/{}",
+                planet_state.production, planet_state.max_production
+            );
         } else if text.0.starts_with("Science:") {
-            text.0 = format!("Science: {}", planet_state.science);
+            text.0 = format!(
+                "Science: {}/{}",
+                planet_state.science, planet_state.max_science
+            );
         } else if text.0.starts_with("Research:") {
-            text.0 = format!("Research: {}/100", planet_state.research_progress);
+            text.0 = match (&research.active, research.active_cost(&game_data)) {
+                (Some(active), Some(cost)) => format!(
+                    "Research: {} {}/{}",
+                    tech_name(&game_data, active),
+                    research.progress,
+                    cost
+                ),
+                _ => format!("Research: idle ({})", research.progress),
+            };
+        } else if text.0.starts_with("Queue:") {
+            let queued: Vec<&str> = research
+                .queued
+                .iter()
+                .map(|id| tech_name(&game_data, id))
+                .collect();
+            text.0 = if queued.is_empty() {
+                "Queue: empty".to_string()
+            } else {
+                format!("Queue: {}", queued.join(", "))
+            };
+        } else if text.0.starts_with("VICTORY!") {
+            if let Some(id) = &planet_state.victory {
+                let name = game_data
+                    .victory_conditions()
+                    .iter()
+                    .find(|condition| condition.id == id.as_str())
+                    .map_or(id.as_str(), |condition| condition.name_en.as_str());
+                text.0 = format!("VICTORY! {name}");
+            }
+        } else if text.0.starts_with("DEFEAT!") {
+            if let Some(reason) = planet_state.defeat {
+                text.0 = format!("DEFEAT! {}", reason.label());
+            }
+        }
+    }
+}
+
+/// Show the turns left in games with a turn limit.
+pub fn update_turn_limit_system(
+    planet_state: Res<PlanetViewState>,
+    rules: Res<VictoryRules>,
+    game_data: Res<GameData>,
+    registry: Res<GameRegistry>,
+    mut query: Query<(&mut Text, &mut Node), With<TurnLimitText>>,
+) {
+    let scenario = registry.scenario(&game_data, DEFAULT_SCENARIO_ID);
+    let limit = rules.turn_limit(scenario);
+    for (mut text, mut node) in &mut query {
+        let display = if limit.is_some() {
+            Display::Flex
+        } else {
+            Display::None
+        };
+        if node.display != display {
+            node.display = display;
+        }
+        let Some(limit) = limit else {
+            continue;
+        };
+        let label = format!(
+            "Turns remaining: {}",
+            turns_remaining(planet_state.turn, limit)
+        );
+        if text.0 != label {
+            text.0 = label;
         }
     }
 }
 
+/// Resize and recolor the population capacity bar.
+///
+/// - Green: below 75% of capacity
+/// - Yellow: 75% up to full capacity
+/// - Red: overcrowded (population above capacity)
+pub fn update_population_bar_system(
+    planet_state: Res<PlanetViewState>,
+    mut bar_query: Query<(&mut Node, &mut BackgroundColor), With<PopulationBar>>,
+) {
+    let population = planet_state.current_population as f32;
+    let capacity = planet_state.housing as f32;
+    let ratio = if capacity > 0.0 {
+        population / capacity
+    } else if population > 0.0 {
+        f32::INFINITY
+    } else {
+        0.0
+    };
+
+    let color = if ratio > 1.0 {
+        Color::srgb(0.9, 0.2, 0.2)
+    } else if ratio >= 0.75 {
+        Color::srgb(0.9, 0.8, 0.2)
+    } else {
+        Color::srgb(0.2, 0.8, 0.2)
+    };
+
+    for (mut node, mut bg_color) in &mut bar_query {
+        node.width = Val::Percent(ratio.min(1.0) * 100.0);
+        *bg_color = BackgroundColor(color);
+    }
+}
+
+/// Color of the rival's victory progress bar.
+const RIVAL_PROGRESS_COLOR: Color = Color::srgb(0.7, 0.4, 0.9);
+
+/// Color of the player's bar while their leading condition is the
+/// alliance victory, which is shared with their allies.
+const ALLIANCE_PROGRESS_COLOR: Color = Color::srgb(0.3, 0.8, 0.9);
+
+/// Fill the victory progress bars from the [`VictoryTracker`].
+///
+/// The player's bar goes from white through orange to red as their leading
+/// condition nears completion, or is cyan while that condition is the
+/// combined alliance victory. The rival row only shows while an AI species
+/// is ahead of the player (see [`VictoryTracker::rival_ahead`]).
+pub fn update_victory_progress_system(
+    tracker: Res<VictoryTracker>,
+    ai_state: Res<AiState>,
+    game_data: Res<GameData>,
+    mut bar_query: Query<
+        (&mut Node, &mut BackgroundColor, &VictoryProgressBar),
+        Without<RivalProgressRow>,
+    >,
+    mut text_query: Query<(&mut Text, &VictoryProgressText)>,
+    mut row_query: Query<&mut Node, With<RivalProgressRow>>,
+) {
+    let condition_name = |id: &VictoryConditionId| {
+        game_data
+            .victory_conditions()
+            .iter()
+            .find(|condition| condition.id == id.as_str())
+            .map_or(id.as_str().to_string(), |condition| {
+                condition.name_en.clone()
+            })
+    };
+
+    let player = tracker.leading();
+    let rival = tracker.rival_ahead();
+    let player_allied = player.as_ref().is_some_and(|lead| {
+        game_data.victory_conditions().iter().any(|condition| {
+            condition.id == lead.condition.as_str()
+                && condition.condition_type == VictoryType::Alliance
+        })
+    });
+
+    for mut node in &mut row_query {
+        node.display = if rival.is_some() {
+            Display::Flex
+        } else {
+            Display::None
+        };
+    }
+
+    for (mut text, label) in &mut text_query {
+        text.0 = match (label.rival, &player, &rival) {
+            (false, Some(lead), _) => match tracker.details.get(&lead.condition) {
+                Some(detail) => format!("{}: {detail}", condition_name(&lead.condition)),
+                None => format!(
+                    "{}: {:.0}%",
+                    condition_name(&lead.condition),
+                    lead.progress * 100.0
+                ),
+            },
+            (true, _, Some((species, lead))) => {
+                let name = ai_state
+                    .species
+                    .get(*species)
+                    .map_or(species.as_str(), |s| s.name.as_str());
+                format!(
+                    "{name} {}: {:.0}%",
+                    condition_name(&lead.condition),
+                    lead.progress * 100.0
+                )
+            }
+            _ => String::new(),
+        };
+    }
+
+    for (mut node, mut bg_color, bar) in &mut bar_query {
+        let (progress, color) = if bar.rival {
+            let progress = rival.as_ref().map_or(0.0, |(_, lead)| lead.progress);
+            (progress, RIVAL_PROGRESS_COLOR)
+        } else {
+            let progress = player.as_ref().map_or(0.0, |lead| lead.progress);
+            let color = if player_allied {
+                ALLIANCE_PROGRESS_COLOR
+            } else {
+                progress_color(progress)
+            };
+            (progress, color)
+        };
+        node.width = Val::Percent(progress.clamp(0.0, 1.0) * 100.0);
+        *bg_color = BackgroundColor(color);
+    }
+}
+
+/// White at 0%, orange at 50%, red at 100%.
+fn progress_color(progress: f32) -> Color {
+    let white = Color::WHITE;
+    let orange = Color::srgb(1.0, 0.6, 0.1);
+    let red = Color::srgb(0.9, 0.15, 0.1);
+    let progress = progress.clamp(0.0, 1.0);
+    if progress < 0.5 {
+        white.mix(&orange, progress * 2.0)
+    } else {
+        orange.mix(&red, (progress - 0.5) * 2.0)
+    }
+}
+
+/// Show the specialization badge while the colony is specialized.
+pub fn update_specialization_badge_system(
+    planet_state: Res<PlanetViewState>,
+    mut badge_query: Query<(&mut Text, &mut Node), With<SpecializationBadge>>,
+) {
+    if !planet_state.is_changed() {
+        return;
+    }
+    let kind = planet_state.surface.as_ref().and_then(specialization);
+    for (mut text, mut node) in &mut badge_query {
+        match kind {
+            Some(kind) => {
+                text.0 = format!("Specialized: {}", kind.label());
+                node.display = Display::Flex;
+            }
+            None => node.display = Display::None,
+        }
+    }
+}
+
+/// Roll the planet events when a new turn begins, apply those that fire,
+/// and announce them as notifications.
+pub fn planet_event_system(
+    mut turn_events: MessageReader<TurnEnded>,
+    mut planet_state: ResMut<PlanetViewState>,
+    mut queue: ResMut<NotificationQueue>,
+    mut log: ResMut<NotificationLog>,
+    mut update_events: MessageWriter<TileUpdateEvent>,
+    game_data: Res<GameData>,
+    registry: Res<GameRegistry>,
+) {
+    let Some(event) = turn_events.read().last() else {
+        return;
+    };
+    let mut rng = planet_event_rng(planet_state.game_seed, event.turn);
+    let fired = roll_planet_events(&planet_state, game_data.planet_events(), &mut rng);
+
+    for planet_event in fired {
+        if !apply_planet_event(&mut planet_state, planet_event, &game_data, &registry) {
+            continue;
+        }
+        info!("Planet event: {}", planet_event.id);
+        let notification = planet_event_notification(planet_event);
+        log.record(planet_state.turn, &notification);
+        queue.push(notification);
+
+        // A blocked tile can cut off power anywhere, so refresh every tile.
+        if let (PlanetEventEffect::TileBlocked(..), Some(surface)) =
+            (planet_event.effect, &planet_state.surface)
+        {
+            let width = surface.row_width;
+            update_events.write_batch((0..surface.tiles.len()).map(|index| TileUpdateEvent {
+                x: index % width,
+                y: index / width,
+            }));
+        }
+    }
+}
+
+/// Roll for a research accident when a new turn begins, apply it, and
+/// announce it as a notification.
+pub fn research_accident_system(
+    mut turn_events: MessageReader<TurnEnded>,
+    mut planet_state: ResMut<PlanetViewState>,
+    mut research: ResMut<ResearchQueue>,
+    mut queue: ResMut<NotificationQueue>,
+    mut log: ResMut<NotificationLog>,
+    mut accident_events: MessageWriter<ResearchAccident>,
+    game_data: Res<GameData>,
+) {
+    let Some(event) = turn_events.read().last() else {
+        return;
+    };
+    let mut rng = research_accident_rng(planet_state.game_seed, event.turn);
+    let Some(tech) = roll_research_accident(&research, &game_data, &mut rng) else {
+        return;
+    };
+
+    let progress_lost = apply_research_accident(&mut planet_state, &mut research, tech);
+    warn!("Research accident while researching {}", tech.id);
+    let notification = research_accident_notification(tech, progress_lost);
+    log.record(planet_state.turn, &notification);
+    queue.push(notification);
+    accident_events.write(ResearchAccident {
+        tech: TechnologyId::from(tech.id.as_str()),
+        progress_lost,
+    });
+}
+
+/// Show how many islands of White tiles the surface has.
+pub fn update_island_count_system(
+    planet_state: Res<PlanetViewState>,
+    mut text_query: Query<&mut Text, With<IslandCountText>>,
+) {
+    if !planet_state.is_changed() {
+        return;
+    }
+    let Some(surface) = &planet_state.surface else {
+        return;
+    };
+    let islands = label_connected_components(surface).len();
+    let label = if islands == 1 { "island" } else { "islands" };
+    for mut text in &mut text_query {
+        text.0 = format!("Surface: {islands} {label}");
+    }
+}
+
+/// Display name of a technology, falling back to its id.
+fn tech_name<'a>(game_data: &'a GameData, id: &'a TechnologyId) -> &'a str {
+    game_data
+        .technologies()
+        .iter()
+        .find(|tech| tech.id == id.as_str())
+        .map_or(id.as_str(), |tech| tech.name_en.as_str())
+}
+
 /// Recalculate tile connectivity each frame.
 ///
 /// Delegates to [`logic::update_connectivity`] to perform the BFS algorithm
-/// that determines which tiles are powered by the base.
+/// that determines which tiles are powered by the base. The state is only
+/// marked changed when a tile's connection or a deposit actually changed,
+/// so systems guarded by `is_changed` do not rerun every frame.
 pub fn update_connectivity_system(
     mut planet_state: ResMut<PlanetViewState>,
+    mut deposit_events: MessageWriter<DepositDiscovered>,
     game_data: Res<GameData>,
     registry: Res<GameRegistry>,
 ) {
-    if let Some(surface) = &mut planet_state.surface {
-        update_connectivity(surface, &game_data, &registry);
+    let Some(surface) = planet_state.bypass_change_detection().surface.as_mut() else {
+        return;
+    };
+    let before: Vec<bool> = surface.tiles.iter().map(|tile| tile.connected).collect();
+    let revealed = update_connectivity(surface, &game_data, &registry);
+    let reconnected = surface
+        .tiles
+        .iter()
+        .map(|tile| tile.connected)
+        .ne(before.iter().copied());
+    deposit_events.write_batch(deposits_found(Some(surface), &revealed));
+
+    if reconnected || !revealed.is_empty() {
+        planet_state.set_changed();
     }
 }
 
-/// Update the production queue UI panel.
-///
-/// This system rebuilds the queue display each frame by:
-/// 1. Despawning all existing child text entities
-/// 2. Spawning new text entities for each project in the queue
-///
-/// The first (active) project is highlighted in green and shows
-/// the production income rate (e.g., "+5").
-pub fn update_production_queue_ui(
+/// [`DepositDiscovered`] messages for the deposits under `tile_indices`.
+fn deposits_found(
+    surface: Option<&PlanetSurface>,
+    tile_indices: &[usize],
+) -> Vec<DepositDiscovered> {
+    tile_indices
+        .iter()
+        .filter_map(|&tile_index| {
+            let deposit = surface?.tiles.get(tile_index)?.deposit?;
+            Some(DepositDiscovered {
+                tile_index,
+                deposit,
+            })
+        })
+        .collect()
+}
+
+/// Place a small colored gem on each tile with a revealed deposit.
+pub fn update_deposit_markers_system(
     mut commands: Commands,
+    mut meshes: ResMut<Assets<Mesh>>,
+    mut materials: ResMut<Assets<StandardMaterial>>,
     planet_state: Res<PlanetViewState>,
-    queue_query: Query<(Entity, Option<&Children>), With<ProductionQueueList>>,
+    tile_query: Query<(&TileEntity, &Transform)>,
+    marker_query: Query<&DepositMarker>,
 ) {
-    for (entity, children) in &queue_query {
-        if let Some(children) = children {
-            for child in children {
-                commands.entity(*child).despawn();
-            }
-        }
+    if !planet_state.is_changed() {
+        return;
+    }
+    let Some(surface) = &planet_state.surface else {
+        return;
+    };
+    let marked: HashSet<usize> = marker_query
+        .iter()
+        .map(|marker| marker.tile_index)
+        .collect();
 
-        commands.entity(entity).with_children(|parent| {
-            for (i, project) in planet_state.production_queue.iter().enumerate() {
-                let name = match project.project_type {
-                    crate::planet_view::types::ProjectType::Building(b) => format!("{:?}", b),
-                };
-
-                let progress_text = format!("{} / {}", project.progress, project.total_cost);
-                let color = if i == 0 {
-                    Color::srgb(0.0, 1.0, 0.0)
-                } else {
-                    Color::WHITE
-                };
-
-                let income_text = if i == 0 {
-                    format!(" (+{})", planet_state.production)
-                } else {
-                    "".to_string()
-                };
-
-                parent.spawn((
-                    Text::new(format!("{}: {}{}", name, progress_text, income_text)),
-                    TextFont {
-                        font_size: 14.0,
-                        ..default()
-                    },
-                    TextColor(color),
-                ));
-            }
-        });
+    for (tile, transform) in &tile_query {
+        let tile_index = tile.y * surface.row_width + tile.x;
+        if marked.contains(&tile_index) {
+            continue;
+        }
+        let Some(deposit) = surface
+            .tiles
+            .get(tile_index)
+            .filter(|tile| tile.deposit_revealed)
+            .and_then(|tile| tile.deposit)
+        else {
+            continue;
+        };
+        let color = match deposit {
+            ResourceDepositKind::IronOre(_) => Color::srgb(0.8, 0.45, 0.2),
+            ResourceDepositKind::RareEarths(_) => Color::srgb(0.7, 0.3, 0.9),
+            ResourceDepositKind::FertileLand(_) => Color::srgb(0.3, 0.85, 0.3),
+        };
+        commands.spawn((
+            Mesh3d(meshes.add(Sphere::new(0.12))),
+            MeshMaterial3d(materials.add(StandardMaterial {
+                base_color: color,
+                emissive: color.to_linear() * 0.5,
+                ..default()
+            })),
+            // Corner of the tile, clear of the building cube
+            Transform::from_translation(transform.translation + Vec3::new(0.3, 0.15, -0.3)),
+            DepositMarker { tile_index },
+            PlanetView3D,
+        ));
     }
 }
@@ -0,0 +1,26 @@
+//! Colonies shared by the planet view tests.
+
+use crate::data_types::{GameData, GameRegistry};
+use crate::planet_data::{BuildingType, PlanetSurface, TileColor};
+use crate::planet_view::logic::{BASE_STOCKPILE_CAP, update_connectivity};
+use crate::planet_view::types::PlanetViewState;
+
+/// A 5x1 strip of white tiles: Base, Farm, Farm, then two empty tiles.
+///
+/// Tile 3 is powered by the farm next to it; tile 4 is not.
+pub(crate) fn strip_colony(data: &GameData, registry: &GameRegistry) -> PlanetViewState {
+    let mut surface = PlanetSurface::new(5, 1);
+    for tile in &mut surface.tiles {
+        tile.color = TileColor::White;
+    }
+    surface.tiles[0].building = Some(BuildingType::Base);
+    surface.tiles[1].building = Some(BuildingType::Farm);
+    surface.tiles[2].building = Some(BuildingType::Farm);
+    update_connectivity(&mut surface, data, registry);
+
+    PlanetViewState {
+        surface: Some(surface),
+        max_production: BASE_STOCKPILE_CAP,
+        ..Default::default()
+    }
+}
@@ -11,15 +11,18 @@
 //! - [`TileUpdateEvent`] triggers visual updates when tile state changes
 //! - [`PlanetViewAssets`] caches shared mesh/material handles for performance
 
-use crate::planet_data::{BuildingType, PlanetSurface};
+use crate::data_types::{TechnologyId, VictoryConditionId};
+use crate::planet_data::{BuildingType, PlanetSurface, ResourceDepositKind};
+use crate::victory::DefeatReason;
 use bevy::prelude::*;
-use std::collections::VecDeque;
+use serde::{Deserialize, Serialize};
+use std::collections::{HashSet, VecDeque};
 
 /// The type of project that can be added to the production queue.
 ///
 /// Currently only supports building construction, but could be extended
 /// to include research projects, terraforming, etc.
-#[derive(Debug, Clone, PartialEq, Eq)]
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
 pub enum ProjectType {
     /// Construct a building of the specified type.
     Building(BuildingType),
@@ -29,7 +32,7 @@ pub enum ProjectType {
 ///
 /// Projects accumulate production points each turn until they reach
 /// their total cost, at which point the building is placed on the target tile.
-#[derive(Debug, Clone, PartialEq, Eq)]
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
 pub struct ProductionProject {
     /// What kind of project this is (building type, etc.).
     pub project_type: ProjectType,
@@ -52,7 +55,8 @@ pub struct ProductionProject {
 /// - Populated by `setup::setup_planet_view` with generated planet data
 /// - Modified by `systems::end_turn` each game turn
 /// - Reset when leaving the planet view
-#[derive(Resource, Default)]
+/// - Serialized into save games by [`crate::save_load`] (UI-only fields are skipped)
+#[derive(Resource, Default, Debug, Clone, Serialize, Deserialize)]
 pub struct PlanetViewState {
     /// The planet's surface grid containing tiles and buildings.
     pub surface: Option<PlanetSurface>,
@@ -60,24 +64,77 @@ pub struct PlanetViewState {
     pub turn: u32,
     /// Accumulated food resource (sum of all building yields).
     pub food: u32,
-    /// Accumulated housing capacity (sum of all building yields).
+    /// Population capacity (sum of all building housing yields).
     pub housing: u32,
+    /// Current population (grows toward `housing` while food is in surplus).
+    #[serde(default)]
+    pub current_population: u32,
     /// Accumulated production points (used to build structures).
     pub production: u32,
-    /// Accumulated science points (used for research).
+    /// Accumulated science points. Each turn's science yield also goes to
+    /// the [`ResearchQueue`](crate::research::ResearchQueue); the stockpile
+    /// itself is never spent on research.
     pub science: u32,
-    /// Progress toward the current research goal (0-100).
-    pub research_progress: u32,
-    /// Whether terraforming technology has been unlocked.
-    pub terraforming_unlocked: bool,
-    /// Whether the victory condition has been achieved.
-    pub victory: bool,
+    /// Science yielded by the last turn (after any research accident
+    /// penalty), which is what research received.
+    #[serde(default)]
+    pub science_yield: u32,
+    /// Food stockpile cap; surplus beyond it is discarded.
+    #[serde(default)]
+    pub max_food: u32,
+    /// Production stockpile cap; surplus beyond it is discarded.
+    #[serde(default)]
+    pub max_production: u32,
+    /// Science stockpile cap; surplus beyond it is discarded.
+    #[serde(default)]
+    pub max_science: u32,
+    /// Victory condition that has been achieved, if any
+    /// (set by [`crate::victory`]).
+    #[serde(default)]
+    pub victory: Option<VictoryConditionId>,
     /// Queue of buildings awaiting construction, processed FIFO.
     pub production_queue: VecDeque<ProductionProject>,
     /// Whether the build menu modal is currently open.
+    #[serde(skip)]
     pub build_menu_open: bool,
     /// The tile index where the next building will be placed (when menu is open).
+    #[serde(skip)]
     pub build_menu_target_tile: Option<usize>,
+    /// Tile whose building is awaiting demolition confirmation.
+    #[serde(skip)]
+    pub demolish_confirm_tile: Option<usize>,
+    /// Tiles drawn in red because demolishing would disconnect them.
+    #[serde(skip)]
+    pub demolish_preview: Vec<usize>,
+    /// Planet events that have already fired; each fires once per game.
+    #[serde(default)]
+    pub fired_event_ids: HashSet<String>,
+    /// Consecutive turns ended with prosperity at or above the economic
+    /// victory threshold (see [`crate::victory::prosperity`]).
+    #[serde(default)]
+    pub prosperity_streak: u32,
+    /// Why the game was lost, once it has been.
+    #[serde(default)]
+    pub defeat: Option<DefeatReason>,
+    /// Science yield reduction left by a research accident, if any.
+    #[serde(default)]
+    pub science_penalty: Option<SciencePenalty>,
+    /// Ground troops defending the colony, trained by Barracks.
+    #[serde(default)]
+    pub ground_troops: u32,
+    /// Seed of this game's planet event and research accident rolls,
+    /// picked when the game starts.
+    #[serde(default)]
+    pub game_seed: u64,
+}
+
+/// Temporary reduction of a colony's science yield.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub struct SciencePenalty {
+    /// Turns the penalty still applies to.
+    pub remaining_turns: u32,
+    /// Share of the science yield lost, in percent.
+    pub amount: i32,
 }
 
 /// Marker component for UI entities that belong to the planet view.
@@ -130,8 +187,62 @@ pub enum UIAction {
     EndTurn,
     /// Return to the main menu.
     Quit,
+    /// Open the technology tree screen.
+    OpenTechTree,
+    /// Toggle the diplomacy panel.
+    OpenDiplomacy,
+}
+
+/// Marker for the fill of the population capacity bar in the top bar.
+///
+/// Its width and color are driven by `current_population / housing`.
+#[derive(Component)]
+pub struct PopulationBar;
+
+/// Marker for the fill of a victory progress bar in the top bar.
+///
+/// The player's bar shows their leading condition; the rival bar shows an
+/// AI species that is ahead of the player.
+#[derive(Component)]
+pub struct VictoryProgressBar {
+    /// Whether this is the rival bar.
+    pub rival: bool,
 }
 
+/// Marker for the label next to a [`VictoryProgressBar`], e.g. "Cover the
+/// Planet: 42%".
+#[derive(Component)]
+pub struct VictoryProgressText {
+    /// Whether this labels the rival bar.
+    pub rival: bool,
+}
+
+/// Marker for the row holding the rival bar, hidden while no AI species is
+/// ahead of the player.
+#[derive(Component)]
+pub struct RivalProgressRow;
+
+/// Marker for the "Specialized: ..." badge in the top bar.
+///
+/// Hidden unless every counted building shares one kind (see
+/// [`crate::planet_view::logic::specialization`]).
+#[derive(Component)]
+pub struct SpecializationBadge;
+
+/// Marker for the "Surface: N islands" counter in the top bar.
+#[derive(Component)]
+pub struct IslandCountText;
+
+/// Marker for the "Turns remaining: N" countdown in the top bar, hidden in
+/// games without a turn limit.
+#[derive(Component)]
+pub struct TurnLimitText;
+
+/// Marker component for the defeat message overlay, shown once
+/// `PlanetViewState::defeat` is set.
+#[derive(Component)]
+pub struct DefeatMessage;
+
 /// Marker component for the victory message overlay.
 ///
 /// This UI element is hidden by default and shown when `PlanetViewState::victory`
@@ -151,6 +262,58 @@ pub struct TileUpdateEvent {
     pub y: usize,
 }
 
+/// Message fired after the player's turn has been resolved.
+///
+/// Other simulations (such as AI species) listen for this to take their own
+/// turns before the player acts again.
+#[derive(Debug, Clone, Copy, bevy::prelude::Message)]
+pub struct TurnEnded {
+    /// Number of the turn that has just begun.
+    pub turn: u32,
+}
+
+/// Message fired for each building the player finishes constructing.
+#[derive(Debug, Clone, Copy, bevy::prelude::Message)]
+pub struct BuildingCompleted {
+    /// Tile the building was placed on.
+    pub tile_index: usize,
+    /// Building that was completed.
+    pub building: BuildingType,
+}
+
+/// Message fired for each technology the player finishes researching.
+#[derive(Debug, Clone, bevy::prelude::Message)]
+pub struct TechUnlocked {
+    /// Technology that was researched.
+    pub tech: TechnologyId,
+}
+
+/// Message fired when a resource deposit is discovered on the player's
+/// planet.
+#[derive(Debug, Clone, Copy, bevy::prelude::Message)]
+pub struct DepositDiscovered {
+    /// Tile the deposit lies under.
+    pub tile_index: usize,
+    /// The deposit that was found.
+    pub deposit: ResourceDepositKind,
+}
+
+/// Marker on the 3D icon of a revealed resource deposit.
+#[derive(Component)]
+pub struct DepositMarker {
+    /// Tile the deposit lies under.
+    pub tile_index: usize,
+}
+
+/// Message fired when a research accident hits the player's colony.
+#[derive(Debug, Clone, bevy::prelude::Message)]
+pub struct ResearchAccident {
+    /// Technology whose research went wrong.
+    pub tech: TechnologyId,
+    /// Research progress lost.
+    pub progress_lost: i32,
+}
+
 /// Cached mesh and material handles for the planet view.
 ///
 /// These assets are created once during setup and reused across all tiles
@@ -3,11 +3,27 @@
 //! This module implements the popup menu that appears when a player
 //! clicks on a valid (connected, empty) tile. It displays available
 //! building types and adds selected buildings to the production queue.
+//! Buildings unlocked by a technology are only offered once it has been
+//! researched.
+//!
+//! Clicking an existing building opens the same menu with its upgrade
+//! (if any) and a Demolish option instead. Hovering Demolish paints the
+//! tiles that would lose power red, and clicking it asks for confirmation.
 
+use crate::data_types::{GameData, GameRegistry, SpecialBehavior, TechnologyId};
+use crate::planet_data::{BuildingType, PlanetSurface};
+use crate::planet_view::logic::{
+    DemolishPreview, breaks_specialization, demolish_building, demolish_preview,
+    placement_adjacency_bonus, upgrade_building, upgrade_option,
+};
+use crate::planet_view::types::{PlanetViewState, ProductionProject, ProjectType, TileUpdateEvent};
+use crate::research::ResearchQueue;
+use crate::tooltip::Tooltip;
+use crate::ui_layout::{LayoutButton, MIN_BUTTON_HEIGHT};
 use bevy::prelude::*;
-use crate::data_types::GameData;
-use crate::planet_data::BuildingType;
-use crate::planet_view::types::{PlanetViewState, ProductionProject, ProjectType};
+
+#[cfg(test)]
+mod tests;
 
 /// Marker component for the build menu root entity.
 ///
@@ -25,6 +41,26 @@ pub struct BuildMenuAction(pub BuildingType);
 #[derive(Component)]
 pub struct BuildMenuCancel;
 
+/// Marker component for the upgrade button (shown for occupied tiles).
+#[derive(Component)]
+pub struct BuildMenuUpgrade;
+
+/// Marker component for the demolish button (shown for occupied tiles).
+#[derive(Component)]
+pub struct BuildMenuDemolish;
+
+/// Marker component for the demolition confirmation dialog.
+#[derive(Component)]
+pub struct DemolishDialogRoot;
+
+/// Marker component for the dialog's confirm button.
+#[derive(Component)]
+pub struct DemolishConfirm;
+
+/// Marker component for the dialog's cancel button.
+#[derive(Component)]
+pub struct DemolishCancel;
+
 /// System to show/hide the build menu based on game state.
 ///
 /// - Spawns the menu when `build_menu_open` becomes true
@@ -34,13 +70,33 @@ pub fn update_build_menu(
     planet_state: Res<PlanetViewState>,
     menu_query: Query<Entity, With<BuildMenuRoot>>,
     game_data: Res<GameData>,
+    registry: Res<GameRegistry>,
+    research: Res<ResearchQueue>,
 ) {
     let is_open = planet_state.build_menu_open;
     let has_menu = !menu_query.is_empty();
 
     if is_open && !has_menu {
         // Menu should be open but doesn't exist - spawn it
-        spawn_build_menu(&mut commands, &game_data);
+        let target = planet_state.build_menu_target_tile;
+        let occupied = target.is_some_and(|tile| {
+            planet_state
+                .surface
+                .as_ref()
+                .and_then(|surface| surface.tiles.get(tile))
+                .is_some_and(|tile| tile.building.is_some())
+        });
+        match target.filter(|_| occupied) {
+            Some(tile) => {
+                let upgrade = upgrade_option(&planet_state, tile, &game_data, &registry).ok();
+                let demolish = demolish_preview(&planet_state, tile, &game_data, &registry).ok();
+                spawn_building_menu(&mut commands, &game_data, upgrade, demolish);
+            }
+            None => {
+                let placement = planet_state.surface.as_ref().zip(target);
+                spawn_build_menu(&mut commands, &game_data, &research, placement);
+            }
+        }
     } else if !is_open && has_menu {
         // Menu should be closed but exists - despawn it
         for entity in &menu_query {
@@ -53,9 +109,16 @@ pub fn update_build_menu(
 ///
 /// Creates a centered modal dialog with:
 /// - Title text
-/// - List of building type buttons
+/// - List of building type buttons (with cost/yield tooltips, plus the
+///   adjacency bonus gained by building on the `placement` tile), leaving
+///   out buildings whose technology is not researched yet
 /// - Cancel button at the bottom
-fn spawn_build_menu(commands: &mut Commands, _game_data: &GameData) {
+fn spawn_build_menu(
+    commands: &mut Commands,
+    game_data: &GameData,
+    research: &ResearchQueue,
+    placement: Option<(&PlanetSurface, usize)>,
+) {
     commands
         .spawn((
             Node {
@@ -97,22 +160,44 @@ fn spawn_build_menu(commands: &mut Commands, _game_data: &GameData) {
                 (BuildingType::Laboratory, "Laboratory"),
                 (BuildingType::Passage, "Passage"),
                 (BuildingType::Terraformer, "Terraformer"),
+                (BuildingType::Warehouse, "Warehouse"),
+                (BuildingType::Barracks, "Barracks"),
             ];
 
             for (b_type, name) in buildings {
+                if !is_unlocked(game_data, research, b_type) {
+                    continue;
+                }
+                let mut tooltip = building_tooltip(game_data, b_type, name);
+                let bonus = placement.map(|(surface, tile)| {
+                    placement_adjacency_bonus(surface, tile, b_type, game_data.adjacency_bonuses())
+                });
+                if let Some(bonus) = bonus.filter(|bonus| !bonus.is_zero()) {
+                    tooltip = tooltip.with_stat("Adjacency bonus", bonus);
+                }
+                let broken =
+                    placement.and_then(|(surface, _)| breaks_specialization(surface, b_type));
+                if let Some(kind) = broken {
+                    tooltip = tooltip.with_stat(
+                        "Warning",
+                        format!("Will break {} specialization", kind.label()),
+                    );
+                }
                 parent
                     .spawn((
                         Button,
                         Node {
                             width: Val::Percent(100.0),
-                            height: Val::Px(40.0),
+                            min_height: Val::Px(MIN_BUTTON_HEIGHT),
                             margin: UiRect::bottom(Val::Px(5.0)),
                             justify_content: JustifyContent::Center,
                             align_items: AlignItems::Center,
                             ..default()
                         },
+                        LayoutButton,
                         BackgroundColor(Color::srgb(0.2, 0.2, 0.2)),
                         BuildMenuAction(b_type),
+                        tooltip,
                     ))
                     .with_children(|btn| {
                         btn.spawn((
@@ -132,12 +217,151 @@ fn spawn_build_menu(commands: &mut Commands, _game_data: &GameData) {
                     Button,
                     Node {
                         width: Val::Percent(100.0),
-                        height: Val::Px(40.0),
+                        min_height: Val::Px(MIN_BUTTON_HEIGHT),
+                        margin: UiRect::top(Val::Px(20.0)),
+                        justify_content: JustifyContent::Center,
+                        align_items: AlignItems::Center,
+                        ..default()
+                    },
+                    LayoutButton,
+                    BackgroundColor(Color::srgb(0.5, 0.0, 0.0)),
+                    BuildMenuCancel,
+                ))
+                .with_children(|btn| {
+                    btn.spawn((Text::new("Cancel"), TextColor(Color::WHITE)));
+                });
+        });
+}
+
+/// Whether the technology unlocking `b_type` (if any) has been researched.
+fn is_unlocked(game_data: &GameData, research: &ResearchQueue, b_type: BuildingType) -> bool {
+    game_data
+        .surface_buildings()
+        .iter()
+        .find(|b| b.id == b_type.id())
+        .and_then(|b| b.unlocked_by_tech_id.as_deref())
+        .is_none_or(|tech| research.is_researched(&TechnologyId::from(tech)))
+}
+
+/// Display name of a building type from its data definition.
+fn building_name(game_data: &GameData, b_type: BuildingType) -> &str {
+    game_data
+        .surface_buildings()
+        .iter()
+        .find(|b| b.id == b_type.id())
+        .map_or(b_type.id(), |b| b.name_en.as_str())
+}
+
+/// Spawn the menu for an existing building, offering its upgrade and
+/// demolition when available.
+fn spawn_building_menu(
+    commands: &mut Commands,
+    game_data: &GameData,
+    upgrade: Option<(BuildingType, u32)>,
+    demolish: Option<DemolishPreview>,
+) {
+    commands
+        .spawn((
+            Node {
+                position_type: PositionType::Absolute,
+                left: Val::Percent(30.0),
+                top: Val::Percent(35.0),
+                width: Val::Percent(40.0),
+                flex_direction: FlexDirection::Column,
+                padding: UiRect::all(Val::Px(10.0)),
+                border: UiRect::all(Val::Px(2.0)),
+                ..default()
+            },
+            BackgroundColor(Color::BLACK.with_alpha(0.9)),
+            BorderColor::all(Color::WHITE),
+            BuildMenuRoot,
+            GlobalZIndex(10),
+        ))
+        .with_children(|parent| {
+            parent.spawn((
+                Text::new("Manage Building"),
+                TextFont {
+                    font_size: 24.0,
+                    ..default()
+                },
+                TextColor(Color::WHITE),
+                Node {
+                    margin: UiRect::bottom(Val::Px(10.0)),
+                    ..default()
+                },
+            ));
+
+            if let Some((target, cost)) = upgrade {
+                let name = building_name(game_data, target);
+                parent
+                    .spawn((
+                        Button,
+                        Node {
+                            width: Val::Percent(100.0),
+                            min_height: Val::Px(MIN_BUTTON_HEIGHT),
+                            margin: UiRect::bottom(Val::Px(5.0)),
+                            justify_content: JustifyContent::Center,
+                            align_items: AlignItems::Center,
+                            ..default()
+                        },
+                        LayoutButton,
+                        BackgroundColor(Color::srgb(0.2, 0.2, 0.2)),
+                        BuildMenuUpgrade,
+                        building_tooltip(game_data, target, name),
+                    ))
+                    .with_children(|btn| {
+                        btn.spawn((
+                            Text::new(format!("Upgrade to {name} ({cost} production)")),
+                            TextFont {
+                                font_size: 18.0,
+                                ..default()
+                            },
+                            TextColor(Color::WHITE),
+                        ));
+                    });
+            }
+
+            if let Some(preview) = demolish {
+                let name = building_name(game_data, preview.building);
+                parent
+                    .spawn((
+                        Button,
+                        Node {
+                            width: Val::Percent(100.0),
+                            min_height: Val::Px(MIN_BUTTON_HEIGHT),
+                            margin: UiRect::bottom(Val::Px(5.0)),
+                            justify_content: JustifyContent::Center,
+                            align_items: AlignItems::Center,
+                            ..default()
+                        },
+                        LayoutButton,
+                        BackgroundColor(Color::srgb(0.2, 0.2, 0.2)),
+                        BuildMenuDemolish,
+                    ))
+                    .with_children(|btn| {
+                        btn.spawn((
+                            Text::new(format!("Demolish {name} (+{} production)", preview.refund)),
+                            TextFont {
+                                font_size: 18.0,
+                                ..default()
+                            },
+                            TextColor(Color::WHITE),
+                        ));
+                    });
+            }
+
+            parent
+                .spawn((
+                    Button,
+                    Node {
+                        width: Val::Percent(100.0),
+                        min_height: Val::Px(MIN_BUTTON_HEIGHT),
                         margin: UiRect::top(Val::Px(20.0)),
                         justify_content: JustifyContent::Center,
                         align_items: AlignItems::Center,
                         ..default()
                     },
+                    LayoutButton,
                     BackgroundColor(Color::srgb(0.5, 0.0, 0.0)),
                     BuildMenuCancel,
                 ))
@@ -147,6 +371,36 @@ fn spawn_build_menu(commands: &mut Commands, _game_data: &GameData) {
         });
 }
 
+/// Build the hover tooltip for a building button from its data definition.
+fn building_tooltip(game_data: &GameData, b_type: BuildingType, name: &str) -> Tooltip {
+    let Some(def) = game_data
+        .surface_buildings()
+        .iter()
+        .find(|b| b.id == b_type.id())
+    else {
+        return Tooltip::new(name);
+    };
+
+    let mut tooltip = Tooltip::new(def.name_en.clone()).with_stat("Cost", def.production_cost);
+    for (label, value) in [
+        ("Food", def.yields_food),
+        ("Housing", def.yields_housing),
+        ("Production", def.yields_production),
+        ("Science", def.yields_science),
+    ] {
+        if value != 0 {
+            tooltip = tooltip.with_stat(label, format!("{value:+}"));
+        }
+    }
+    if let SpecialBehavior::StorageBonus { resource, amount } = def.special_behavior {
+        tooltip = tooltip.with_stat(format!("{resource:?} storage"), format!("{amount:+}"));
+    }
+    if let Some(tech) = &def.unlocked_by_tech_id {
+        tooltip = tooltip.with_stat("Requires", tech);
+    }
+    tooltip
+}
+
 /// System to handle button clicks in the build menu.
 ///
 /// # Building Selection
@@ -156,6 +410,14 @@ fn spawn_build_menu(commands: &mut Commands, _game_data: &GameData) {
 /// 3. Adds the project to the production queue
 /// 4. Closes the menu
 ///
+/// # Upgrade
+/// Pays the upgrade cost from the production stockpile and replaces the
+/// building immediately.
+///
+/// # Demolish
+/// Hovering previews the tiles that would lose power; clicking closes the
+/// menu and opens the confirmation dialog (see [`update_demolish_dialog`]).
+///
 /// # Cancel
 /// When cancel is clicked, simply closes the menu without adding anything.
 pub fn build_menu_interaction(
@@ -164,10 +426,57 @@ pub fn build_menu_interaction(
         (Changed<Interaction>, With<Button>),
     >,
     mut cancel_query: Query<(&Interaction, &BuildMenuCancel), (Changed<Interaction>, With<Button>)>,
+    upgrade_query: Query<&Interaction, (Changed<Interaction>, With<BuildMenuUpgrade>)>,
+    demolish_query: Query<&Interaction, (Changed<Interaction>, With<BuildMenuDemolish>)>,
     mut planet_state: ResMut<PlanetViewState>,
     mut update_events: MessageWriter<crate::planet_view::types::TileUpdateEvent>,
-    _game_data: Res<GameData>,
+    game_data: Res<GameData>,
+    registry: Res<GameRegistry>,
 ) {
+    // Handle Upgrade
+    for interaction in &upgrade_query {
+        if *interaction == Interaction::Pressed {
+            if let Some(target_idx) = planet_state.build_menu_target_tile {
+                match upgrade_building(&mut planet_state, target_idx, &game_data, &registry) {
+                    Ok(_) => {
+                        if let Some(surface) = &planet_state.surface {
+                            let x = target_idx % surface.row_width;
+                            let y = target_idx / surface.row_width;
+                            update_events
+                                .write(crate::planet_view::types::TileUpdateEvent { x, y });
+                        }
+                    }
+                    Err(err) => info!("{err}"),
+                }
+            }
+            planet_state.build_menu_open = false;
+            planet_state.build_menu_target_tile = None;
+        }
+    }
+
+    // Handle Demolish (preview on hover, confirm on click)
+    for interaction in &demolish_query {
+        let Some(target_idx) = planet_state.build_menu_target_tile else {
+            continue;
+        };
+        match interaction {
+            Interaction::Hovered => {
+                let tiles = demolish_preview(&planet_state, target_idx, &game_data, &registry)
+                    .map(|preview| preview.disconnected_tiles)
+                    .unwrap_or_default();
+                set_demolish_preview(&mut planet_state, tiles, &mut update_events);
+            }
+            Interaction::None => {
+                set_demolish_preview(&mut planet_state, Vec::new(), &mut update_events);
+            }
+            Interaction::Pressed => {
+                planet_state.demolish_confirm_tile = Some(target_idx);
+                planet_state.build_menu_open = false;
+                planet_state.build_menu_target_tile = None;
+            }
+        }
+    }
+
     // Handle Building Selection
     for (interaction, action) in &mut interaction_query {
         if *interaction == Interaction::Pressed {
@@ -175,8 +484,11 @@ pub fn build_menu_interaction(
                 // Get the selected building type from the button component
                 let b_type = action.0;
 
-                // TODO: Look up actual cost from game data
-                let cost = 50;
+                let cost = game_data
+                    .surface_buildings()
+                    .iter()
+                    .find(|b| b.id == b_type.id())
+                    .map_or(0, |b| b.production_cost);
 
                 // Create and enqueue the production project
                 planet_state.production_queue.push_back(ProductionProject {
@@ -208,3 +520,182 @@ pub fn build_menu_interaction(
         }
     }
 }
+
+/// Replace the red demolition preview, refreshing both old and new tiles.
+fn set_demolish_preview(
+    state: &mut PlanetViewState,
+    tiles: Vec<usize>,
+    update_events: &mut MessageWriter<TileUpdateEvent>,
+) {
+    if state.demolish_preview == tiles {
+        return;
+    }
+    let old = std::mem::replace(&mut state.demolish_preview, tiles);
+    let changed: Vec<usize> = old
+        .into_iter()
+        .chain(state.demolish_preview.iter().copied())
+        .collect();
+    refresh_tiles(state, changed, update_events);
+}
+
+/// Send a [`TileUpdateEvent`] for each tile index.
+fn refresh_tiles(
+    state: &PlanetViewState,
+    tiles: impl IntoIterator<Item = usize>,
+    update_events: &mut MessageWriter<TileUpdateEvent>,
+) {
+    if let Some(surface) = &state.surface {
+        for index in tiles {
+            let x = index % surface.row_width;
+            let y = index / surface.row_width;
+            update_events.write(TileUpdateEvent { x, y });
+        }
+    }
+}
+
+/// Show or hide the demolition confirmation dialog.
+///
+/// The dialog lists the refund and the tiles that will lose power.
+pub fn update_demolish_dialog(
+    mut commands: Commands,
+    planet_state: Res<PlanetViewState>,
+    dialog_query: Query<Entity, With<DemolishDialogRoot>>,
+    game_data: Res<GameData>,
+    registry: Res<GameRegistry>,
+) {
+    let has_dialog = !dialog_query.is_empty();
+    let preview = planet_state
+        .demolish_confirm_tile
+        .and_then(|tile| demolish_preview(&planet_state, tile, &game_data, &registry).ok());
+
+    match preview {
+        Some(preview) if !has_dialog => {
+            let row_width = planet_state
+                .surface
+                .as_ref()
+                .map_or(1, |surface| surface.row_width);
+            spawn_demolish_dialog(&mut commands, &game_data, &preview, row_width);
+        }
+        None if has_dialog => {
+            for entity in &dialog_query {
+                commands.entity(entity).despawn();
+            }
+        }
+        _ => {}
+    }
+}
+
+fn spawn_demolish_dialog(
+    commands: &mut Commands,
+    game_data: &GameData,
+    preview: &DemolishPreview,
+    row_width: usize,
+) {
+    let name = building_name(game_data, preview.building);
+    let affected = if preview.disconnected_tiles.is_empty() {
+        "No tiles lose power.".to_string()
+    } else {
+        let tiles: Vec<String> = preview
+            .disconnected_tiles
+            .iter()
+            .map(|index| format!("({}, {})", index % row_width, index / row_width))
+            .collect();
+        format!("Tiles losing power: {}", tiles.join(", "))
+    };
+
+    commands
+        .spawn((
+            Node {
+                position_type: PositionType::Absolute,
+                left: Val::Percent(30.0),
+                top: Val::Percent(35.0),
+                width: Val::Percent(40.0),
+                flex_direction: FlexDirection::Column,
+                padding: UiRect::all(Val::Px(10.0)),
+                border: UiRect::all(Val::Px(2.0)),
+                row_gap: Val::Px(8.0),
+                ..default()
+            },
+            BackgroundColor(Color::BLACK.with_alpha(0.9)),
+            BorderColor::all(Color::WHITE),
+            DemolishDialogRoot,
+            GlobalZIndex(10),
+        ))
+        .with_children(|parent| {
+            parent.spawn((
+                Text::new(format!("Demolish {name}?")),
+                TextFont {
+                    font_size: 24.0,
+                    ..default()
+                },
+                TextColor(Color::WHITE),
+            ));
+            parent.spawn((
+                Text::new(format!("Refund: {} production", preview.refund)),
+                TextColor(Color::WHITE),
+            ));
+            parent.spawn((Text::new(affected), TextColor(Color::srgb(1.0, 0.6, 0.6))));
+
+            for (label, color, confirm) in [
+                ("Demolish", Color::srgb(0.5, 0.0, 0.0), true),
+                ("Cancel", Color::srgb(0.2, 0.2, 0.2), false),
+            ] {
+                let mut button = parent.spawn((
+                    Button,
+                    Node {
+                        width: Val::Percent(100.0),
+                        min_height: Val::Px(MIN_BUTTON_HEIGHT),
+                        justify_content: JustifyContent::Center,
+                        align_items: AlignItems::Center,
+                        ..default()
+                    },
+                    LayoutButton,
+                    BackgroundColor(color),
+                ));
+                if confirm {
+                    button.insert(DemolishConfirm);
+                } else {
+                    button.insert(DemolishCancel);
+                }
+                button.with_children(|btn| {
+                    btn.spawn((Text::new(label), TextColor(Color::WHITE)));
+                });
+            }
+        });
+}
+
+/// Demolish the building or back out when a dialog button is pressed.
+pub fn demolish_dialog_interaction(
+    confirm_query: Query<&Interaction, (Changed<Interaction>, With<DemolishConfirm>)>,
+    cancel_query: Query<&Interaction, (Changed<Interaction>, With<DemolishCancel>)>,
+    mut planet_state: ResMut<PlanetViewState>,
+    mut update_events: MessageWriter<TileUpdateEvent>,
+    game_data: Res<GameData>,
+    registry: Res<GameRegistry>,
+) {
+    let Some(target_idx) = planet_state.demolish_confirm_tile else {
+        return;
+    };
+
+    if confirm_query.iter().any(|i| *i == Interaction::Pressed) {
+        match demolish_building(&mut planet_state, target_idx, &game_data, &registry) {
+            Ok(_) => {
+                // Connectivity can change anywhere on the grid
+                planet_state.demolish_preview.clear();
+                let all_tiles = planet_state
+                    .surface
+                    .as_ref()
+                    .map_or(0, |surface| surface.tiles.len());
+                refresh_tiles(&planet_state, 0..all_tiles, &mut update_events);
+            }
+            Err(err) => {
+                info!("{err}");
+                set_demolish_preview(&mut planet_state, Vec::new(), &mut update_events);
+            }
+        }
+        planet_state.demolish_confirm_tile = None;
+    } else if cancel_query.iter().any(|i| *i == Interaction::Pressed) {
+        set_demolish_preview(&mut planet_state, Vec::new(), &mut update_events);
+        planet_state.demolish_confirm_tile = None;
+    }
+}
@@ -0,0 +1,19 @@
+use std::path::PathBuf;
+
+use super::is_unlocked;
+use crate::data_types::{TechnologyId, load_game_data};
+use crate::planet_data::BuildingType;
+use crate::research::ResearchQueue;
+
+#[test]
+fn barracks_are_unlocked_by_military_drill() {
+    let (data, _) = load_game_data(PathBuf::from("assets/data")).expect("data loads");
+    let mut research = ResearchQueue::default();
+
+    assert!(!is_unlocked(&data, &research, BuildingType::Barracks));
+    assert!(is_unlocked(&data, &research, BuildingType::Farm));
+
+    research.grant(TechnologyId::from("tech_military"));
+
+    assert!(is_unlocked(&data, &research, BuildingType::Barracks));
+}
@@ -0,0 +1,266 @@
+//! Right-click context menu for surface tiles.
+//!
+//! Right-clicking a tile opens a small menu at the cursor listing only the
+//! actions that are valid for that tile (see [`tile_context_items`]).
+//! Choosing an item does what the build menu would do; any left-click
+//! elsewhere or the Back key closes the menu.
+
+use bevy::window::PrimaryWindow;
+use bevy::{ecs::hierarchy::ChildSpawnerCommands, prelude::*};
+
+use crate::data_types::{GameData, GameRegistry};
+use crate::planet_view::logic::{demolish_preview, upgrade_building, upgrade_option};
+use crate::planet_view::types::{PlanetViewRoot, PlanetViewState, TileUpdateEvent, colors};
+
+#[cfg(test)]
+mod tests;
+
+/// Width of the menu in UI pixels.
+const MENU_WIDTH: f32 = 140.0;
+/// Height of one item in UI pixels.
+const ITEM_HEIGHT: f32 = 28.0;
+/// Padding around the items in UI pixels.
+const MENU_PADDING: f32 = 4.0;
+
+/// Action offered by the context menu.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ContextItem {
+    /// Open the build menu for an empty tile.
+    Build,
+    /// Upgrade the building on the tile.
+    Upgrade,
+    /// Ask to demolish the building on the tile.
+    Demolish,
+}
+
+impl ContextItem {
+    fn label(self) -> &'static str {
+        match self {
+            ContextItem::Build => "Build",
+            ContextItem::Upgrade => "Upgrade",
+            ContextItem::Demolish => "Demolish",
+        }
+    }
+}
+
+/// The open context menu; it is closed while `items` is empty.
+#[derive(Resource, Debug, Default)]
+pub struct ContextMenuState {
+    /// Actions listed in the menu.
+    pub items: Vec<ContextItem>,
+    /// Cursor position in logical pixels where the menu was opened.
+    pub world_pos: Vec2,
+    /// Tile the actions apply to.
+    pub tile: Option<usize>,
+}
+
+impl ContextMenuState {
+    /// Whether the menu is on screen.
+    pub fn is_open(&self) -> bool {
+        !self.items.is_empty()
+    }
+
+    /// Open the menu at `world_pos` for `tile`; with no items it stays closed.
+    pub fn open(&mut self, tile: usize, items: Vec<ContextItem>, world_pos: Vec2) {
+        self.tile = Some(tile).filter(|_| !items.is_empty());
+        self.items = items;
+        self.world_pos = world_pos;
+    }
+
+    /// Close the menu.
+    pub fn close(&mut self) {
+        self.items.clear();
+        self.tile = None;
+    }
+}
+
+/// Actions valid for `tile_index`, in menu order.
+///
+/// Mirrors the left-click rules: a tile with construction queued offers
+/// nothing, Build needs an empty connected tile, and Upgrade and Demolish
+/// need a building that supports them.
+pub fn tile_context_items(
+    state: &PlanetViewState,
+    tile_index: usize,
+    game_data: &GameData,
+    registry: &GameRegistry,
+) -> Vec<ContextItem> {
+    let Some(tile) = state
+        .surface
+        .as_ref()
+        .and_then(|surface| surface.tiles.get(tile_index))
+    else {
+        return Vec::new();
+    };
+    if state
+        .production_queue
+        .iter()
+        .any(|project| project.target_tile_index == tile_index)
+    {
+        return Vec::new();
+    }
+
+    let mut items = Vec::new();
+    if tile.building.is_none() && tile.connected {
+        items.push(ContextItem::Build);
+    }
+    if upgrade_option(state, tile_index, game_data, registry).is_ok() {
+        items.push(ContextItem::Upgrade);
+    }
+    if demolish_preview(state, tile_index, game_data, registry).is_ok() {
+        items.push(ContextItem::Demolish);
+    }
+    items
+}
+
+/// Top-left corner for a menu of `size` opened at `pos` so that it stays
+/// inside a window of `window` size.
+pub fn clamp_menu_position(pos: Vec2, size: Vec2, window: Vec2) -> Vec2 {
+    pos.min(window - size).max(Vec2::ZERO)
+}
+
+/// Marker component for the context menu panel.
+#[derive(Component)]
+pub struct ContextMenuPanel;
+
+/// Context menu entry, with the action and the tile it applies to.
+#[derive(Component, Debug, Clone, Copy)]
+pub struct ContextMenuButton {
+    /// Action to perform.
+    pub item: ContextItem,
+    /// Tile the action applies to.
+    pub tile: usize,
+}
+
+/// Spawn the menu when it opens and remove it when it closes.
+pub fn update_context_menu(
+    mut commands: Commands,
+    menu: Res<ContextMenuState>,
+    windows: Query<&Window, With<PrimaryWindow>>,
+    ui_scale: Res<UiScale>,
+    panel_query: Query<Entity, With<ContextMenuPanel>>,
+) {
+    if !menu.is_changed() {
+        return;
+    }
+    for entity in &panel_query {
+        commands.entity(entity).despawn();
+    }
+    let Some(tile) = menu.tile.filter(|_| menu.is_open()) else {
+        return;
+    };
+
+    // Cursor and window sizes are in logical pixels; UI values are scaled
+    // by `UiScale`.
+    let scale = ui_scale.0;
+    let size = Vec2::new(
+        MENU_WIDTH,
+        menu.items.len() as f32 * ITEM_HEIGHT + 2.0 * MENU_PADDING,
+    );
+    let window = windows
+        .single()
+        .map_or(Vec2::splat(f32::MAX), |window| window.size() / scale);
+    let position = clamp_menu_position(menu.world_pos / scale, size, window);
+
+    commands
+        .spawn((
+            Node {
+                position_type: PositionType::Absolute,
+                left: Val::Px(position.x),
+                top: Val::Px(position.y),
+                width: Val::Px(MENU_WIDTH),
+                flex_direction: FlexDirection::Column,
+                padding: UiRect::all(Val::Px(MENU_PADDING)),
+                border: UiRect::all(Val::Px(1.0)),
+                ..default()
+            },
+            BackgroundColor(colors::PANEL_BG),
+            BorderColor::all(colors::BORDER),
+            GlobalZIndex(25),
+            ContextMenuPanel,
+            PlanetViewRoot,
+        ))
+        .with_children(|panel| {
+            for &item in &menu.items {
+                spawn_item(panel, ContextMenuButton { item, tile });
+            }
+        });
+}
+
+fn spawn_item(parent: &mut ChildSpawnerCommands, button: ContextMenuButton) {
+    parent
+        .spawn((
+            Button,
+            Node {
+                height: Val::Px(ITEM_HEIGHT),
+                padding: UiRect::horizontal(Val::Px(8.0)),
+                align_items: AlignItems::Center,
+                ..default()
+            },
+            BackgroundColor(Color::NONE),
+            button,
+        ))
+        .with_children(|item| {
+            item.spawn((
+                Text::new(button.item.label()),
+                TextFont {
+                    font_size: 14.0,
+                    ..default()
+                },
+                TextColor(colors::TEXT),
+            ));
+        });
+}
+
+/// Perform the chosen action and close the menu.
+///
+/// Build opens the build menu on the tile, Upgrade upgrades the building
+/// right away, and Demolish opens the demolition confirmation.
+pub fn context_menu_interaction(
+    mut interaction_query: Query<
+        (&Interaction, &ContextMenuButton, &mut BackgroundColor),
+        Changed<Interaction>,
+    >,
+    mut menu: ResMut<ContextMenuState>,
+    mut planet_state: ResMut<PlanetViewState>,
+    mut update_events: MessageWriter<TileUpdateEvent>,
+    game_data: Res<GameData>,
+    registry: Res<GameRegistry>,
+) {
+    for (interaction, button, mut background) in &mut interaction_query {
+        match interaction {
+            Interaction::Hovered => background.0 = colors::BUTTON_NORMAL,
+            Interaction::None => background.0 = Color::NONE,
+            Interaction::Pressed => {
+                menu.close();
+                match button.item {
+                    ContextItem::Build => {
+                        planet_state.build_menu_open = true;
+                        planet_state.build_menu_target_tile = Some(button.tile);
+                    }
+                    ContextItem::Upgrade => {
+                        match upgrade_building(
+                            &mut planet_state,
+                            button.tile,
+                            &game_data,
+                            &registry,
+                        ) {
+                            Ok(_) => {
+                                if let Some(surface) = &planet_state.surface {
+                                    update_events.write(TileUpdateEvent {
+                                        x: button.tile % surface.row_width,
+                                        y: button.tile / surface.row_width,
+                                    });
+                                }
+                            }
+                            Err(err) => info!("{err}"),
+                        }
+                    }
+                    ContextItem::Demolish => {
+                        planet_state.demolish_confirm_tile = Some(button.tile);
+                    }
+                }
+            }
+        }
+    }
+}
@@ -0,0 +1,76 @@
+use std::path::PathBuf;
+
+use bevy::math::Vec2;
+
+use super::{ContextItem, ContextMenuState, clamp_menu_position, tile_context_items};
+use crate::data_types::load_game_data;
+use crate::planet_data::BuildingType;
+use crate::planet_view::test_support::strip_colony;
+use crate::planet_view::types::{ProductionProject, ProjectType};
+
+#[test]
+fn items_match_what_the_tile_allows() {
+    let (data, registry) = load_game_data(PathBuf::from("assets/data")).expect("data loads");
+    let state = strip_colony(&data, &registry);
+
+    assert!(tile_context_items(&state, 0, &data, &registry).is_empty());
+    assert_eq!(
+        tile_context_items(&state, 1, &data, &registry),
+        [ContextItem::Upgrade, ContextItem::Demolish]
+    );
+    assert_eq!(
+        tile_context_items(&state, 3, &data, &registry),
+        [ContextItem::Build]
+    );
+    // Tile 4 only borders an empty tile, so it has no power.
+    assert!(tile_context_items(&state, 4, &data, &registry).is_empty());
+}
+
+#[test]
+fn queued_tiles_offer_nothing() {
+    let (data, registry) = load_game_data(PathBuf::from("assets/data")).expect("data loads");
+    let mut state = strip_colony(&data, &registry);
+    state.production_queue.push_back(ProductionProject {
+        project_type: ProjectType::Building(BuildingType::Farm),
+        total_cost: 50,
+        progress: 0,
+        target_tile_index: 3,
+    });
+
+    assert!(tile_context_items(&state, 3, &data, &registry).is_empty());
+}
+
+#[test]
+fn menu_without_items_stays_closed() {
+    let mut menu = ContextMenuState::default();
+
+    menu.open(2, Vec::new(), Vec2::new(10.0, 10.0));
+    assert!(!menu.is_open());
+    assert_eq!(menu.tile, None);
+
+    menu.open(2, vec![ContextItem::Build], Vec2::new(10.0, 10.0));
+    assert!(menu.is_open());
+    assert_eq!(menu.tile, Some(2));
+
+    menu.close();
+    assert!(!menu.is_open());
+}
+
+#[test]
+fn menu_is_kept_inside_the_window() {
+    let size = Vec2::new(140.0, 64.0);
+    let window = Vec2::new(800.0, 600.0);
+
+    assert_eq!(
+        clamp_menu_position(Vec2::new(100.0, 100.0), size, window),
+        Vec2::new(100.0, 100.0)
+    );
+    assert_eq!(
+        clamp_menu_position(Vec2::new(790.0, 590.0), size, window),
+        Vec2::new(660.0, 536.0)
+    );
+    assert_eq!(
+        clamp_menu_position(Vec2::new(-5.0, 20.0), size, window),
+        Vec2::new(0.0, 20.0)
+    );
+}
@@ -5,11 +5,18 @@
 //! - [`panels`] - Left and right info panels (production queue, yields, etc.)
 //! - [`top_bar`] - Top navigation bar with planet info and back button
 //! - [`build_menu`] - Building selection modal dialog
+//! - [`notification`] - Queued pop-up notifications (e.g. research complete)
+//! - [`notification_log`] - Scrollable history of past notifications
+//! - [`production_queue`] - Production queue list with drag-and-drop reordering
+//! - [`context_menu`] - Right-click menu with the actions valid for a tile
 
+pub mod build_menu;
+pub mod context_menu;
+pub mod notification;
+pub mod notification_log;
 pub mod panels;
+pub mod production_queue;
 pub mod top_bar;
-pub mod build_menu;
-
 
 pub use panels::{spawn_left_panel, spawn_right_panel};
 pub use top_bar::spawn_top_bar;
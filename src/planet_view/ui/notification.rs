@@ -0,0 +1,362 @@
+//! Pop-up notifications shown over the planet view.
+//!
+//! Notifications wait in a [`NotificationQueue`] and are shown one at a
+//! time; dismissing one reveals the next, so nothing is lost when several
+//! arrive in the same turn. Research notifications are created from
+//! [`TechUnlocked`] messages by [`tech_notification_system`] and list the
+//! buildings the technology unlocks; construction notifications come from
+//! [`BuildingCompleted`] messages via [`building_notification_system`].
+//! Every notification is also recorded in the [`NotificationLog`].
+
+use std::collections::VecDeque;
+
+use bevy::{ecs::hierarchy::ChildSpawnerCommands, prelude::*};
+use serde::{Deserialize, Serialize};
+
+use crate::data_types::{GameData, PlanetEvent, Technology, TechnologyId};
+use crate::main_menu::GameState;
+use crate::planet_data::{BuildingType, ResourceDepositKind};
+use crate::planet_view::logic::{ACCIDENT_PENALTY_TURNS, ACCIDENT_SCIENCE_PENALTY_PERCENT};
+use crate::planet_view::types::{
+    BuildingCompleted, DepositDiscovered, PlanetViewRoot, PlanetViewState, TechUnlocked, colors,
+};
+use crate::planet_view::ui::notification_log::NotificationLog;
+use crate::screen_transition::TransitionToState;
+
+#[cfg(test)]
+mod tests;
+
+/// Kind of event a notification reports, shown as its header.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum NotificationKind {
+    /// A technology was researched.
+    Research,
+    /// A building was constructed.
+    Construction,
+    /// A random planet event fired.
+    PlanetEvent,
+    /// Research of a dangerous technology went wrong.
+    ResearchAccident,
+    /// A resource deposit was found.
+    Discovery,
+    /// The game is about to be lost.
+    Warning,
+}
+
+impl NotificationKind {
+    /// Header shown above the notification.
+    pub fn label(self) -> &'static str {
+        match self {
+            NotificationKind::Research => "Research Complete",
+            NotificationKind::Construction => "Construction Complete",
+            NotificationKind::PlanetEvent => "Planet Event",
+            NotificationKind::ResearchAccident => "Research Accident",
+            NotificationKind::Discovery => "Deposit Discovered",
+            NotificationKind::Warning => "Warning",
+        }
+    }
+}
+
+/// A single notification.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Notification {
+    /// What the notification is about.
+    pub kind: NotificationKind,
+    /// Headline, e.g. the technology name.
+    pub title: String,
+    /// Further lines of text.
+    pub lines: Vec<String>,
+}
+
+/// Notifications waiting to be read; the front one is on screen.
+#[derive(Resource, Debug, Default)]
+pub struct NotificationQueue {
+    /// Pending notifications, oldest first.
+    pub pending: VecDeque<Notification>,
+}
+
+impl NotificationQueue {
+    /// Notification currently on screen.
+    pub fn current(&self) -> Option<&Notification> {
+        self.pending.front()
+    }
+
+    /// Add a notification behind those already waiting.
+    pub fn push(&mut self, notification: Notification) {
+        self.pending.push_back(notification);
+    }
+
+    /// Close the current notification, revealing the next one.
+    pub fn dismiss(&mut self) {
+        self.pending.pop_front();
+    }
+}
+
+/// Notification for a researched technology, listing the buildings it
+/// unlocks.
+pub fn tech_notification(game_data: &GameData, tech: &TechnologyId) -> Notification {
+    let title = game_data
+        .technologies()
+        .iter()
+        .find(|t| t.id == tech.as_str())
+        .map_or_else(|| tech.as_str().to_string(), |t| t.name_en.clone());
+
+    let buildings: Vec<&str> = game_data
+        .tech_unlocks(tech.as_str())
+        .map(|building| building.name_en.as_str())
+        .collect();
+    let lines = if buildings.is_empty() {
+        Vec::new()
+    } else {
+        vec![format!("New buildings: {}", buildings.join(", "))]
+    };
+
+    Notification {
+        kind: NotificationKind::Research,
+        title,
+        lines,
+    }
+}
+
+/// Notification for a building finished on tile `(x, y)`.
+pub fn building_notification(
+    game_data: &GameData,
+    building: BuildingType,
+    (x, y): (usize, usize),
+) -> Notification {
+    let name = game_data
+        .surface_buildings()
+        .iter()
+        .find(|def| def.id == building.id())
+        .map_or(building.id(), |def| def.name_en.as_str());
+
+    Notification {
+        kind: NotificationKind::Construction,
+        title: format!("{name} completed"),
+        lines: vec![format!("Built on tile ({x}, {y}).")],
+    }
+}
+
+/// Notification for a planet event that fired.
+pub fn planet_event_notification(event: &PlanetEvent) -> Notification {
+    Notification {
+        kind: NotificationKind::PlanetEvent,
+        title: event.name_en.clone(),
+        lines: vec![event.description_en.clone()],
+    }
+}
+
+/// Notification for a resource deposit found on tile `(x, y)`.
+pub fn deposit_notification(deposit: ResourceDepositKind, (x, y): (usize, usize)) -> Notification {
+    let bonus = match deposit {
+        ResourceDepositKind::IronOre(amount) => format!("+{amount} production"),
+        ResourceDepositKind::RareEarths(amount) => format!("+{amount} science"),
+        ResourceDepositKind::FertileLand(amount) => format!("+{amount} food"),
+    };
+    Notification {
+        kind: NotificationKind::Discovery,
+        title: format!("{} found", deposit.label()),
+        lines: vec![format!("Tile ({x}, {y}) yields {bonus} per turn.")],
+    }
+}
+
+/// Notification for a research accident while researching `tech`.
+pub fn research_accident_notification(tech: &Technology, progress_lost: i32) -> Notification {
+    Notification {
+        kind: NotificationKind::ResearchAccident,
+        title: format!("Accident researching {}", tech.name_en),
+        lines: vec![
+            format!("{progress_lost} research progress was lost."),
+            format!(
+                "Science output is reduced by {ACCIDENT_SCIENCE_PENALTY_PERCENT}% for \
+                 {ACCIDENT_PENALTY_TURNS} turns."
+            ),
+        ],
+    }
+}
+
+/// Marker component for the notification panel root entity.
+#[derive(Component)]
+pub struct NotificationPanel;
+
+/// Action performed by a notification panel button.
+#[derive(Component, Debug, Clone, Copy, PartialEq, Eq)]
+pub enum NotificationButton {
+    /// Close the notification.
+    Dismiss,
+    /// Close the notification and open the tech tree.
+    ViewTechTree,
+}
+
+/// Queue (and log) a notification for every technology the player
+/// researched.
+pub fn tech_notification_system(
+    mut tech_events: MessageReader<TechUnlocked>,
+    mut queue: ResMut<NotificationQueue>,
+    mut log: ResMut<NotificationLog>,
+    planet_state: Res<PlanetViewState>,
+    game_data: Res<GameData>,
+) {
+    for event in tech_events.read() {
+        let notification = tech_notification(&game_data, &event.tech);
+        log.record(planet_state.turn, &notification);
+        queue.push(notification);
+    }
+}
+
+/// Queue (and log) a notification for every building the player finished.
+pub fn building_notification_system(
+    mut building_events: MessageReader<BuildingCompleted>,
+    mut queue: ResMut<NotificationQueue>,
+    mut log: ResMut<NotificationLog>,
+    planet_state: Res<PlanetViewState>,
+    game_data: Res<GameData>,
+) {
+    let row_width = planet_state
+        .surface
+        .as_ref()
+        .map_or(1, |surface| surface.row_width);
+    for event in building_events.read() {
+        let position = (event.tile_index % row_width, event.tile_index / row_width);
+        let notification = building_notification(&game_data, event.building, position);
+        log.record(planet_state.turn, &notification);
+        queue.push(notification);
+    }
+}
+
+/// Queue (and log) a notification for every deposit the player discovered.
+pub fn deposit_notification_system(
+    mut deposit_events: MessageReader<DepositDiscovered>,
+    mut queue: ResMut<NotificationQueue>,
+    mut log: ResMut<NotificationLog>,
+    planet_state: Res<PlanetViewState>,
+) {
+    let row_width = planet_state
+        .surface
+        .as_ref()
+        .map_or(1, |surface| surface.row_width);
+    for event in deposit_events.read() {
+        let position = (event.tile_index % row_width, event.tile_index / row_width);
+        let notification = deposit_notification(event.deposit, position);
+        log.record(planet_state.turn, &notification);
+        queue.push(notification);
+    }
+}
+
+/// Show the front notification of the queue, or remove the panel once the
+/// queue is empty.
+pub fn update_notification_panel(
+    mut commands: Commands,
+    queue: Res<NotificationQueue>,
+    panel_query: Query<Entity, With<NotificationPanel>>,
+) {
+    // The panel is despawned with the planet view, so respawn it on return.
+    if !queue.is_changed() && !panel_query.is_empty() {
+        return;
+    }
+
+    for entity in &panel_query {
+        commands.entity(entity).despawn();
+    }
+
+    let Some(notification) = queue.current() else {
+        return;
+    };
+    let waiting = queue.pending.len() - 1;
+
+    commands
+        .spawn((
+            Node {
+                position_type: PositionType::Absolute,
+                left: Val::Percent(35.0),
+                top: Val::Percent(25.0),
+                width: Val::Percent(30.0),
+                flex_direction: FlexDirection::Column,
+                padding: UiRect::all(Val::Px(16.0)),
+                border: UiRect::all(Val::Px(2.0)),
+                row_gap: Val::Px(10.0),
+                ..default()
+            },
+            BackgroundColor(colors::PANEL_BG),
+            BorderColor::all(colors::BORDER),
+            GlobalZIndex(20),
+            NotificationPanel,
+            PlanetViewRoot,
+        ))
+        .with_children(|panel| {
+            panel.spawn((
+                Text::new(notification.kind.label()),
+                TextFont {
+                    font_size: 14.0,
+                    ..default()
+                },
+                TextColor(colors::BORDER),
+            ));
+            panel.spawn((
+                Text::new(notification.title.clone()),
+                TextFont {
+                    font_size: 22.0,
+                    ..default()
+                },
+                TextColor(colors::HEADER_TEXT),
+            ));
+            for line in &notification.lines {
+                panel.spawn((Text::new(line.clone()), TextColor(colors::TEXT)));
+            }
+            if waiting > 0 {
+                panel.spawn((
+                    Text::new(format!("{waiting} more waiting")),
+                    TextFont {
+                        font_size: 12.0,
+                        ..default()
+                    },
+                    TextColor(colors::TEXT),
+                ));
+            }
+
+            panel
+                .spawn(Node {
+                    justify_content: JustifyContent::SpaceBetween,
+                    ..default()
+                })
+                .with_children(|row| {
+                    if notification.kind == NotificationKind::Research {
+                        spawn_button(row, "View Tech Tree", NotificationButton::ViewTechTree);
+                    }
+                    spawn_button(row, "OK", NotificationButton::Dismiss);
+                });
+        });
+}
+
+fn spawn_button(parent: &mut ChildSpawnerCommands, label: &str, action: NotificationButton) {
+    parent
+        .spawn((
+            Button,
+            Node {
+                padding: UiRect::axes(Val::Px(14.0), Val::Px(6.0)),
+                ..default()
+            },
+            BackgroundColor(colors::BUTTON_NORMAL),
+            action,
+        ))
+        .with_children(|btn| {
+            btn.spawn((Text::new(label), TextColor(colors::HEADER_TEXT)));
+        });
+}
+
+/// Handle the notification panel buttons.
+pub fn notification_button_system(
+    interaction_query: Query<(&Interaction, &NotificationButton), Changed<Interaction>>,
+    mut queue: ResMut<NotificationQueue>,
+    mut transitions: MessageWriter<TransitionToState>,
+) {
+    for (interaction, button) in &interaction_query {
+        if *interaction != Interaction::Pressed {
+            continue;
+        }
+        queue.dismiss();
+        if *button == NotificationButton::ViewTechTree {
+            transitions.write(TransitionToState(GameState::TechTree));
+        }
+    }
+}
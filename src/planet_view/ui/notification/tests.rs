@@ -0,0 +1,67 @@
+use std::path::PathBuf;
+
+use super::{
+    Notification, NotificationKind, NotificationQueue, building_notification,
+    planet_event_notification, tech_notification,
+};
+use crate::data_types::{TechnologyId, load_game_data};
+use crate::planet_data::BuildingType;
+
+#[test]
+fn tech_notification_lists_unlocked_buildings() {
+    let (data, _) = load_game_data(PathBuf::from("assets/data")).expect("data loads");
+
+    let notification = tech_notification(&data, &TechnologyId::from("tech_terraforming"));
+
+    assert_eq!(notification.kind, NotificationKind::Research);
+    assert_eq!(notification.title, "Terraforming");
+    assert_eq!(notification.lines, vec!["New buildings: Terraformer"]);
+}
+
+#[test]
+fn planet_event_notification_shows_the_description() {
+    let (data, _) = load_game_data(PathBuf::from("assets/data")).expect("data loads");
+    let event = data
+        .planet_events()
+        .iter()
+        .find(|event| event.id == "event_ancient_ruins")
+        .expect("ruins are defined");
+
+    let notification = planet_event_notification(event);
+
+    assert_eq!(notification.kind, NotificationKind::PlanetEvent);
+    assert_eq!(notification.title, "Ancient Ruins");
+    assert_eq!(
+        notification.lines,
+        vec!["Ruins of a long-gone civilization yield new insights."]
+    );
+}
+
+#[test]
+fn building_notification_names_building_and_tile() {
+    let (data, _) = load_game_data(PathBuf::from("assets/data")).expect("data loads");
+
+    let notification = building_notification(&data, BuildingType::Farm, (3, 4));
+
+    assert_eq!(notification.kind, NotificationKind::Construction);
+    assert_eq!(notification.title, "Farm 1 completed");
+    assert_eq!(notification.lines, vec!["Built on tile (3, 4)."]);
+}
+
+#[test]
+fn queued_notifications_are_shown_in_order() {
+    let note = |title: &str| Notification {
+        kind: NotificationKind::Research,
+        title: title.to_string(),
+        lines: Vec::new(),
+    };
+    let mut queue = NotificationQueue::default();
+    queue.push(note("first"));
+    queue.push(note("second"));
+
+    assert_eq!(queue.current().map(|n| n.title.as_str()), Some("first"));
+    queue.dismiss();
+    assert_eq!(queue.current().map(|n| n.title.as_str()), Some("second"));
+    queue.dismiss();
+    assert!(queue.current().is_none());
+}
@@ -0,0 +1,294 @@
+//! Scrollable history of the notifications shown in the planet view.
+//!
+//! Every notification queued by [`crate::planet_view::ui::notification`] is
+//! also recorded in the [`NotificationLog`], which is part of save games.
+//! The log panel (toggled with the notification log key, L by default)
+//! lists the newest [`LOG_PANEL_ROWS`] entries; entries that arrived since
+//! the panel was last closed are highlighted, and clicking a row shows the
+//! notification again.
+
+use std::collections::VecDeque;
+
+use bevy::input::mouse::{AccumulatedMouseScroll, MouseScrollUnit};
+use bevy::{ecs::hierarchy::ChildSpawnerCommands, prelude::*};
+use serde::{Deserialize, Serialize};
+
+use crate::keybindings::{KeyBindings, actions};
+use crate::planet_view::types::{PlanetViewRoot, TurnEnded, colors};
+use crate::planet_view::ui::notification::{Notification, NotificationQueue};
+
+#[cfg(test)]
+mod tests;
+
+/// Default number of entries kept in the log.
+pub const MAX_LOG_ENTRIES: usize = 100;
+
+/// Number of entries listed in the log panel.
+pub const LOG_PANEL_ROWS: usize = 50;
+
+/// Entries from more than this many turns ago are dropped.
+pub const LOG_MAX_AGE_TURNS: u32 = 50;
+
+/// Pixels scrolled per mouse wheel line.
+const SCROLL_LINE_PIXELS: f32 = 40.0;
+
+/// Row background of an entry not yet seen in the panel.
+const UNREAD_BG: Color = Color::srgba(0.3, 0.5, 0.8, 0.35);
+
+/// One recorded notification.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct NotificationLogEntry {
+    /// Turn on which the notification arrived.
+    pub turn: u32,
+    /// One-line summary shown in the panel.
+    pub short_message: String,
+    /// The notification itself, shown again when the row is clicked.
+    pub notification: Notification,
+    /// Whether the entry was listed when the panel was last closed.
+    #[serde(default)]
+    pub read: bool,
+}
+
+/// History of notifications, oldest first.
+#[derive(Resource, Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct NotificationLog {
+    /// Recorded notifications, oldest first.
+    pub entries: VecDeque<NotificationLogEntry>,
+    /// Most entries kept; the oldest are dropped beyond it.
+    pub max_entries: usize,
+}
+
+impl Default for NotificationLog {
+    fn default() -> Self {
+        Self {
+            entries: VecDeque::new(),
+            max_entries: MAX_LOG_ENTRIES,
+        }
+    }
+}
+
+impl NotificationLog {
+    /// Record a notification that arrived on `turn`.
+    pub fn record(&mut self, turn: u32, notification: &Notification) {
+        self.entries.push_back(NotificationLogEntry {
+            turn,
+            short_message: format!("{}: {}", notification.kind.label(), notification.title),
+            notification: notification.clone(),
+            read: false,
+        });
+        self.evict(turn);
+    }
+
+    /// Drop entries older than [`LOG_MAX_AGE_TURNS`] at `current_turn`
+    /// and the oldest entries beyond [`NotificationLog::max_entries`].
+    pub fn evict(&mut self, current_turn: u32) {
+        self.entries
+            .retain(|entry| current_turn.saturating_sub(entry.turn) <= LOG_MAX_AGE_TURNS);
+        while self.entries.len() > self.max_entries {
+            self.entries.pop_front();
+        }
+    }
+
+    /// Number of entries not yet seen in the panel.
+    pub fn unread_count(&self) -> usize {
+        self.entries.iter().filter(|entry| !entry.read).count()
+    }
+
+    /// Mark every entry as seen.
+    pub fn mark_all_read(&mut self) {
+        for entry in &mut self.entries {
+            entry.read = true;
+        }
+    }
+
+    /// Entries listed in the panel, newest first, with their index in
+    /// [`NotificationLog::entries`].
+    pub fn recent(&self) -> impl Iterator<Item = (usize, &NotificationLogEntry)> {
+        self.entries.iter().enumerate().rev().take(LOG_PANEL_ROWS)
+    }
+}
+
+/// Whether the log panel is open.
+#[derive(Resource, Debug, Default)]
+pub struct NotificationLogPanelState {
+    /// Whether the panel is on screen.
+    pub open: bool,
+}
+
+/// Marker component for the log panel root entity.
+#[derive(Component)]
+pub struct NotificationLogPanel;
+
+/// Scrolling list of the log panel.
+#[derive(Component)]
+pub struct NotificationLogScroll;
+
+/// Row of the log panel, with the index of its entry.
+#[derive(Component)]
+pub struct NotificationLogRow(pub usize);
+
+/// Open or close the panel with the notification log key. Closing marks
+/// the listed entries as read.
+pub fn notification_log_toggle_system(
+    keyboard: Res<ButtonInput<KeyCode>>,
+    bindings: Res<KeyBindings>,
+    mut panel: ResMut<NotificationLogPanelState>,
+    mut log: ResMut<NotificationLog>,
+) {
+    if !bindings.just_pressed(&keyboard, actions::NOTIFICATION_LOG) {
+        return;
+    }
+    if panel.open {
+        log.mark_all_read();
+    }
+    panel.open = !panel.open;
+}
+
+/// Drop old entries when a new turn begins.
+pub fn notification_log_eviction_system(
+    mut turn_events: MessageReader<TurnEnded>,
+    mut log: ResMut<NotificationLog>,
+) {
+    if let Some(event) = turn_events.read().last() {
+        log.evict(event.turn);
+    }
+}
+
+/// Spawn, rebuild, or despawn the log panel to match the log and
+/// [`NotificationLogPanelState`].
+pub fn update_notification_log_panel(
+    mut commands: Commands,
+    panel: Res<NotificationLogPanelState>,
+    log: Res<NotificationLog>,
+    panel_query: Query<Entity, With<NotificationLogPanel>>,
+) {
+    // The panel is despawned with the planet view, so respawn it on return.
+    let missing = panel.open && panel_query.is_empty();
+    if !panel.is_changed() && !log.is_changed() && !missing {
+        return;
+    }
+
+    for entity in &panel_query {
+        commands.entity(entity).despawn();
+    }
+    if !panel.open {
+        return;
+    }
+
+    commands
+        .spawn((
+            Node {
+                position_type: PositionType::Absolute,
+                left: Val::Percent(30.0),
+                top: Val::Percent(12.0),
+                width: Val::Percent(40.0),
+                max_height: Val::Percent(70.0),
+                flex_direction: FlexDirection::Column,
+                padding: UiRect::all(Val::Px(12.0)),
+                border: UiRect::all(Val::Px(2.0)),
+                row_gap: Val::Px(8.0),
+                ..default()
+            },
+            BackgroundColor(colors::PANEL_BG),
+            BorderColor::all(colors::BORDER),
+            GlobalZIndex(15),
+            NotificationLogPanel,
+            PlanetViewRoot,
+        ))
+        .with_children(|root| {
+            root.spawn((
+                Text::new(format!("Notifications ({} new)", log.unread_count())),
+                TextFont {
+                    font_size: 18.0,
+                    ..default()
+                },
+                TextColor(colors::HEADER_TEXT),
+            ));
+            root.spawn((
+                Node {
+                    flex_direction: FlexDirection::Column,
+                    overflow: Overflow::scroll_y(),
+                    row_gap: Val::Px(2.0),
+                    ..default()
+                },
+                ScrollPosition::default(),
+                NotificationLogScroll,
+            ))
+            .with_children(|list| {
+                if log.entries.is_empty() {
+                    list.spawn((Text::new("No notifications yet"), TextColor(colors::TEXT)));
+                }
+                for (index, entry) in log.recent() {
+                    spawn_row(list, index, entry);
+                }
+            });
+        });
+}
+
+fn spawn_row(parent: &mut ChildSpawnerCommands, index: usize, entry: &NotificationLogEntry) {
+    let background = if entry.read { Color::NONE } else { UNREAD_BG };
+    parent
+        .spawn((
+            Button,
+            Node {
+                column_gap: Val::Px(10.0),
+                padding: UiRect::axes(Val::Px(6.0), Val::Px(3.0)),
+                ..default()
+            },
+            BackgroundColor(background),
+            NotificationLogRow(index),
+        ))
+        .with_children(|row| {
+            row.spawn((
+                Text::new(format!("Turn {}", entry.turn)),
+                TextFont {
+                    font_size: 14.0,
+                    ..default()
+                },
+                TextColor(colors::BORDER),
+            ));
+            row.spawn((
+                Text::new(entry.short_message.clone()),
+                TextFont {
+                    font_size: 14.0,
+                    ..default()
+                },
+                TextColor(colors::TEXT),
+            ));
+        });
+}
+
+/// Show a logged notification again when its row is clicked.
+pub fn notification_log_row_system(
+    interaction_query: Query<(&Interaction, &NotificationLogRow), Changed<Interaction>>,
+    log: Res<NotificationLog>,
+    mut queue: ResMut<NotificationQueue>,
+) {
+    for (interaction, row) in &interaction_query {
+        if *interaction != Interaction::Pressed {
+            continue;
+        }
+        if let Some(entry) = log.entries.get(row.0) {
+            queue.push(entry.notification.clone());
+        }
+    }
+}
+
+/// Scroll the log with the mouse wheel.
+pub fn notification_log_scroll_system(
+    mouse_scroll: Res<AccumulatedMouseScroll>,
+    mut query: Query<&mut ScrollPosition, With<NotificationLogScroll>>,
+) {
+    let delta = match mouse_scroll.unit {
+        MouseScrollUnit::Line => mouse_scroll.delta.y * SCROLL_LINE_PIXELS,
+        MouseScrollUnit::Pixel => mouse_scroll.delta.y,
+    };
+    if delta == 0.0 {
+        return;
+    }
+    for mut position in &mut query {
+        // Bevy clamps the offset to the content size during layout.
+        position.y = (position.y - delta).max(0.0);
+    }
+}
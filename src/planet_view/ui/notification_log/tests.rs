@@ -0,0 +1,79 @@
+use super::{LOG_MAX_AGE_TURNS, LOG_PANEL_ROWS, MAX_LOG_ENTRIES, NotificationLog};
+use crate::planet_view::ui::notification::{Notification, NotificationKind};
+
+fn notification(title: &str) -> Notification {
+    Notification {
+        kind: NotificationKind::Construction,
+        title: title.to_string(),
+        lines: Vec::new(),
+    }
+}
+
+#[test]
+fn records_a_summary_of_each_notification() {
+    let mut log = NotificationLog::default();
+
+    log.record(3, &notification("Farm 1 completed"));
+
+    let entry = &log.entries[0];
+    assert_eq!(entry.turn, 3);
+    assert_eq!(
+        entry.short_message,
+        "Construction Complete: Farm 1 completed"
+    );
+    assert_eq!(entry.notification, notification("Farm 1 completed"));
+    assert!(!entry.read);
+}
+
+#[test]
+fn keeps_at_most_max_entries() {
+    let mut log = NotificationLog::default();
+
+    for i in 0..=MAX_LOG_ENTRIES {
+        log.record(1, &notification(&i.to_string()));
+    }
+
+    assert_eq!(log.entries.len(), MAX_LOG_ENTRIES);
+    assert_eq!(log.entries[0].notification.title, "1");
+}
+
+#[test]
+fn drops_entries_older_than_the_age_limit() {
+    let mut log = NotificationLog::default();
+    log.record(1, &notification("old"));
+    log.record(5, &notification("recent"));
+
+    log.evict(1 + LOG_MAX_AGE_TURNS);
+    assert_eq!(log.entries.len(), 2);
+
+    log.evict(2 + LOG_MAX_AGE_TURNS);
+    assert_eq!(log.entries.len(), 1);
+    assert_eq!(log.entries[0].notification.title, "recent");
+}
+
+#[test]
+fn panel_lists_the_newest_entries_first() {
+    let mut log = NotificationLog::default();
+    for i in 0..60 {
+        log.record(1, &notification(&i.to_string()));
+    }
+
+    let recent: Vec<usize> = log.recent().map(|(index, _)| index).collect();
+
+    assert_eq!(recent.len(), LOG_PANEL_ROWS);
+    assert_eq!(recent[0], 59);
+    assert_eq!(log.entries[recent[0]].notification.title, "59");
+}
+
+#[test]
+fn marking_read_clears_the_unread_count() {
+    let mut log = NotificationLog::default();
+    log.record(1, &notification("a"));
+    log.record(1, &notification("b"));
+    assert_eq!(log.unread_count(), 2);
+
+    log.mark_all_read();
+    log.record(2, &notification("c"));
+
+    assert_eq!(log.unread_count(), 1);
+}
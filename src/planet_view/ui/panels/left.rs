@@ -3,13 +3,14 @@
 //! Displays:
 //! - Surface and orbital slot counts
 //! - Production queue with progress bars
-//! - Population display
+//! - Population and ground troops display
 //! - Current project status
 
 use bevy::ecs::hierarchy::ChildSpawnerCommands;
 use bevy::prelude::*;
 
 use crate::planet_view::types::colors;
+use crate::ui_layout::{LayoutConfig, LayoutPanel};
 
 /// Marker component for the production queue list container.
 ///
@@ -33,6 +34,7 @@ pub struct ProductionQueueList;
 /// ├──────────────────────┤
 /// │ Population           │
 /// │ [█][█][█]            │
+/// │ Troops: 4            │
 /// ├──────────────────────┤
 /// │ Project              │
 /// │ [None]               │
@@ -42,6 +44,7 @@ pub struct ProductionQueueList;
 /// # Arguments
 /// - `surface_slots` - Number of buildable surface tiles
 /// - `orbital_slots` - Number of orbital structure slots
+/// - `layout` - Current UI sizes; the panel keeps following them via [`LayoutPanel`]
 pub fn spawn_left_panel(
     main: &mut ChildSpawnerCommands,
     _planet_name: &str,
@@ -49,10 +52,11 @@ pub fn spawn_left_panel(
     _planet_size: &str,
     surface_slots: usize,
     orbital_slots: usize,
+    layout: &LayoutConfig,
 ) {
     main.spawn((
         Node {
-            width: Val::Px(220.0),
+            width: Val::Px(layout.panel_width_px),
             height: Val::Percent(100.0),
             flex_direction: FlexDirection::Column,
             padding: UiRect::all(Val::Px(15.0)),
@@ -62,6 +66,7 @@ pub fn spawn_left_panel(
         },
         BackgroundColor(colors::PANEL_BG.with_alpha(0.85)),
         BorderColor::all(colors::BORDER),
+        LayoutPanel,
     ))
     .with_children(|panel| {
         // Surface info header
@@ -169,6 +174,16 @@ pub fn spawn_left_panel(
                 }
             });
 
+        // Ground troops, kept current by `update_ui_system`
+        panel.spawn((
+            Text::new("Troops: 0"),
+            TextFont {
+                font_size: 14.0,
+                ..default()
+            },
+            TextColor(colors::TEXT),
+        ));
+
         // Divider
         panel.spawn((
             Node {
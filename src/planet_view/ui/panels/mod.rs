@@ -6,5 +6,5 @@
 mod left;
 mod right;
 
-pub use left::{spawn_left_panel, ProductionQueueList};
+pub use left::{ProductionQueueList, spawn_left_panel};
 pub use right::spawn_right_panel;
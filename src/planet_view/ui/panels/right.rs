@@ -7,6 +7,7 @@ use bevy::ecs::hierarchy::ChildSpawnerCommands;
 use bevy::prelude::*;
 
 use crate::planet_view::types::colors;
+use crate::ui_layout::{LayoutConfig, LayoutPanel};
 
 /// Spawn the right orbital structures panel.
 ///
@@ -22,11 +23,16 @@ use crate::planet_view::types::colors;
 /// ```
 ///
 /// Shows up to 8 slots visually, with a "+N more" indicator
-/// if there are additional slots beyond that.
-pub fn spawn_right_panel(main: &mut ChildSpawnerCommands, orbital_slots: usize) {
+/// if there are additional slots beyond that. The width follows
+/// [`LayoutConfig::panel_width_px`].
+pub fn spawn_right_panel(
+    main: &mut ChildSpawnerCommands,
+    orbital_slots: usize,
+    layout: &LayoutConfig,
+) {
     main.spawn((
         Node {
-            width: Val::Px(180.0),
+            width: Val::Px(layout.panel_width_px),
             height: Val::Percent(100.0),
             flex_direction: FlexDirection::Column,
             padding: UiRect::all(Val::Px(15.0)),
@@ -36,6 +42,7 @@ pub fn spawn_right_panel(main: &mut ChildSpawnerCommands, orbital_slots: usize)
         },
         BackgroundColor(colors::PANEL_BG.with_alpha(0.85)),
         BorderColor::all(colors::BORDER),
+        LayoutPanel,
     ))
     .with_children(|panel| {
         panel.spawn((
@@ -0,0 +1,391 @@
+//! Production queue list with drag-and-drop reordering.
+//!
+//! Every [`ProductionQueueList`] shows the queued projects, one row each,
+//! with a grip handle on the left. Holding the left mouse button on a grip
+//! for [`DRAG_HOLD_SECONDS`] picks the project up: a ghost label follows
+//! the cursor and releasing over another row moves the project there.
+//!
+//! Clicking a row selects it; Ctrl with the pan up/down keys (arrows by
+//! default) then moves the selected project one place.
+
+use bevy::prelude::*;
+use bevy::ui::RelativeCursorPosition;
+use bevy::window::PrimaryWindow;
+
+use crate::keybindings::{KeyBindings, actions};
+use crate::planet_view::logic::move_queued_project;
+use crate::planet_view::types::{PlanetViewRoot, PlanetViewState, ProjectType, TileUpdateEvent};
+use crate::planet_view::ui::panels::ProductionQueueList;
+
+#[cfg(test)]
+mod tests;
+
+/// How long a grip must be held before the drag starts.
+pub const DRAG_HOLD_SECONDS: f32 = 0.2;
+
+/// Row background of the selected project.
+const SELECTED_BG: Color = Color::srgba(0.3, 0.5, 0.8, 0.4);
+/// Row background of the drop target while dragging.
+const DROP_TARGET_BG: Color = Color::srgba(1.0, 1.0, 1.0, 0.2);
+
+/// Progress of a drag in the production queue.
+#[derive(Resource, Debug, Default)]
+pub struct QueueDragState {
+    /// Row whose grip is held, with the seconds held so far.
+    pub pressed: Option<(usize, f32)>,
+    /// Row being dragged.
+    pub dragging: Option<usize>,
+    /// Row the dragged project would be dropped on.
+    pub drop_index: Option<usize>,
+    /// Cursor position in logical pixels, where the ghost is drawn.
+    pub ghost_pos: Vec2,
+    /// Row selected for keyboard reordering.
+    pub selected: Option<usize>,
+    /// Queue length the indices above refer to.
+    pub queue_len: usize,
+}
+
+impl QueueDragState {
+    /// Drop the selection and any drag when the queue length differs from
+    /// the one they were made against, as their indices are stale then.
+    pub fn sync_queue_len(&mut self, len: usize) {
+        if self.queue_len != len {
+            self.queue_len = len;
+            self.selected = None;
+            self.reset_drag();
+        }
+    }
+
+    fn reset_drag(&mut self) {
+        self.pressed = None;
+        self.dragging = None;
+        self.drop_index = None;
+    }
+}
+
+/// Row of the production queue list, with its queue index.
+#[derive(Component)]
+pub struct QueueRow(pub usize);
+
+/// Grip handle of a queue row, with its queue index.
+#[derive(Component)]
+pub struct QueueDragHandle(pub usize);
+
+/// Label following the cursor while a project is dragged.
+#[derive(Component)]
+pub struct QueueDragGhost;
+
+/// Display name of a queued project.
+fn project_label(project_type: &ProjectType) -> String {
+    match project_type {
+        ProjectType::Building(b) => format!("{:?}", b),
+    }
+}
+
+/// Text and color of each row, highlighting the active (first) project
+/// with the production income rate (e.g. "+5").
+fn queue_rows(planet_state: &PlanetViewState) -> Vec<(String, Color)> {
+    planet_state
+        .production_queue
+        .iter()
+        .enumerate()
+        .map(|(i, project)| {
+            let name = project_label(&project.project_type);
+            let progress_text = format!("{} / {}", project.progress, project.total_cost);
+            if i == 0 {
+                (
+                    format!("{name}: {progress_text} (+{})", planet_state.production),
+                    Color::srgb(0.0, 1.0, 0.0),
+                )
+            } else {
+                (format!("{name}: {progress_text}"), Color::WHITE)
+            }
+        })
+        .collect()
+}
+
+/// What the list currently shows; it is rebuilt only when this changes.
+#[derive(Default, PartialEq)]
+pub struct RenderedQueue {
+    rows: Vec<(String, Color)>,
+    selected: Option<usize>,
+    dragging: Option<usize>,
+    drop_index: Option<usize>,
+}
+
+/// Rebuild the production queue list when its contents or the drag
+/// highlight change.
+///
+/// Rows are not rebuilt every frame so that the grip being held keeps its
+/// [`Interaction`].
+pub fn update_production_queue_ui(
+    mut commands: Commands,
+    planet_state: Res<PlanetViewState>,
+    drag: Res<QueueDragState>,
+    queue_query: Query<(Entity, Ref<ProductionQueueList>)>,
+    mut rendered: Local<RenderedQueue>,
+) {
+    let current = RenderedQueue {
+        rows: queue_rows(&planet_state),
+        selected: drag.selected,
+        dragging: drag.dragging,
+        drop_index: drag.drop_index,
+    };
+    let spawned = queue_query.iter().any(|(_, list)| list.is_added());
+    if current == *rendered && !spawned {
+        return;
+    }
+
+    for (entity, _) in &queue_query {
+        commands
+            .entity(entity)
+            .despawn_related::<Children>()
+            .with_children(|parent| {
+                for (i, (text, color)) in current.rows.iter().enumerate() {
+                    let background = if current.dragging.is_some() && current.drop_index == Some(i)
+                    {
+                        DROP_TARGET_BG
+                    } else if current.selected == Some(i) {
+                        SELECTED_BG
+                    } else {
+                        Color::NONE
+                    };
+                    // Dim the dragged project where it currently sits.
+                    let color = if current.dragging == Some(i) {
+                        color.with_alpha(0.4)
+                    } else {
+                        *color
+                    };
+
+                    parent
+                        .spawn((
+                            Button,
+                            Node {
+                                align_items: AlignItems::Center,
+                                column_gap: Val::Px(6.0),
+                                ..default()
+                            },
+                            BackgroundColor(background),
+                            RelativeCursorPosition::default(),
+                            QueueRow(i),
+                        ))
+                        .with_children(|row| {
+                            row.spawn((
+                                Button,
+                                Node {
+                                    padding: UiRect::horizontal(Val::Px(4.0)),
+                                    ..default()
+                                },
+                                QueueDragHandle(i),
+                            ))
+                            .with_children(|handle| {
+                                handle.spawn((
+                                    Text::new("::"),
+                                    TextFont {
+                                        font_size: 14.0,
+                                        ..default()
+                                    },
+                                    TextColor(Color::srgb(0.6, 0.6, 0.6)),
+                                ));
+                            });
+                            row.spawn((
+                                Text::new(text.clone()),
+                                TextFont {
+                                    font_size: 14.0,
+                                    ..default()
+                                },
+                                TextColor(color),
+                            ));
+                        });
+                }
+            });
+    }
+
+    *rendered = current;
+}
+
+/// Forget the selected row when projects are added to or finished from
+/// the queue.
+pub fn queue_selection_system(
+    planet_state: Res<PlanetViewState>,
+    mut drag: ResMut<QueueDragState>,
+) {
+    drag.sync_queue_len(planet_state.production_queue.len());
+}
+
+/// Select rows on click and start a drag once a grip is held long enough.
+pub fn queue_press_system(
+    mouse: Res<ButtonInput<MouseButton>>,
+    time: Res<Time>,
+    handles: Query<(&Interaction, &QueueDragHandle), Changed<Interaction>>,
+    rows: Query<(&Interaction, &QueueRow), Changed<Interaction>>,
+    mut drag: ResMut<QueueDragState>,
+) {
+    for (interaction, row) in &rows {
+        if *interaction == Interaction::Pressed {
+            drag.selected = Some(row.0);
+        }
+    }
+    for (interaction, handle) in &handles {
+        if *interaction == Interaction::Pressed {
+            drag.selected = Some(handle.0);
+            drag.pressed = Some((handle.0, 0.0));
+        }
+    }
+
+    if drag.dragging.is_some() {
+        return;
+    }
+    let Some((index, held)) = drag.pressed else {
+        return;
+    };
+    if !mouse.pressed(MouseButton::Left) {
+        // Released before the hold time: a plain click.
+        drag.pressed = None;
+        return;
+    }
+    let held = held + time.delta_secs();
+    if held >= DRAG_HOLD_SECONDS {
+        drag.dragging = Some(index);
+        drag.drop_index = Some(index);
+    } else {
+        drag.pressed = Some((index, held));
+    }
+}
+
+/// Track the drop target under the cursor and move the project on release.
+pub fn queue_drop_system(
+    mouse: Res<ButtonInput<MouseButton>>,
+    windows: Query<&Window, With<PrimaryWindow>>,
+    rows: Query<(&QueueRow, &RelativeCursorPosition)>,
+    mut drag: ResMut<QueueDragState>,
+    mut planet_state: ResMut<PlanetViewState>,
+    mut update_events: MessageWriter<TileUpdateEvent>,
+) {
+    let Some(from) = drag.dragging else {
+        return;
+    };
+
+    if let Some(cursor) = windows.single().ok().and_then(Window::cursor_position) {
+        drag.ghost_pos = cursor;
+    }
+    // Above or below the list the last row under the cursor stays the target.
+    if let Some((row, _)) = rows.iter().find(|(_, position)| position.cursor_over()) {
+        drag.drop_index = Some(row.0);
+    }
+
+    if mouse.pressed(MouseButton::Left) {
+        return;
+    }
+    let to = drag.drop_index.unwrap_or(from);
+    drag.reset_drag();
+    if from == to || !move_queued_project(&mut planet_state, from, to) {
+        return;
+    }
+    drag.selected = Some(to);
+    send_tile_update(&planet_state, to, &mut update_events);
+}
+
+/// Show a label with the dragged project's name next to the cursor.
+pub fn queue_ghost_system(
+    mut commands: Commands,
+    drag: Res<QueueDragState>,
+    planet_state: Res<PlanetViewState>,
+    ui_scale: Res<UiScale>,
+    mut ghost_query: Query<(Entity, &mut Node), With<QueueDragGhost>>,
+) {
+    let project = drag
+        .dragging
+        .and_then(|index| planet_state.production_queue.get(index));
+    let Some(project) = project else {
+        for (entity, _) in &ghost_query {
+            commands.entity(entity).despawn();
+        }
+        return;
+    };
+
+    // The cursor is in logical pixels; UI values are scaled by `UiScale`.
+    let left = Val::Px(drag.ghost_pos.x / ui_scale.0 + 12.0);
+    let top = Val::Px(drag.ghost_pos.y / ui_scale.0);
+    if let Ok((_, mut node)) = ghost_query.single_mut() {
+        node.left = left;
+        node.top = top;
+        return;
+    }
+
+    commands
+        .spawn((
+            Node {
+                position_type: PositionType::Absolute,
+                left,
+                top,
+                padding: UiRect::axes(Val::Px(8.0), Val::Px(4.0)),
+                ..default()
+            },
+            BackgroundColor(Color::BLACK.with_alpha(0.8)),
+            GlobalZIndex(30),
+            QueueDragGhost,
+            PlanetViewRoot,
+        ))
+        .with_children(|ghost| {
+            ghost.spawn((
+                Text::new(project_label(&project.project_type)),
+                TextFont {
+                    font_size: 14.0,
+                    ..default()
+                },
+                TextColor(Color::WHITE),
+            ));
+        });
+}
+
+/// Move the selected project with Ctrl and the pan up/down keys.
+pub fn queue_keyboard_system(
+    keyboard: Res<ButtonInput<KeyCode>>,
+    bindings: Res<KeyBindings>,
+    mut drag: ResMut<QueueDragState>,
+    mut planet_state: ResMut<PlanetViewState>,
+    mut update_events: MessageWriter<TileUpdateEvent>,
+) {
+    let ctrl_pressed =
+        keyboard.pressed(KeyCode::ControlLeft) || keyboard.pressed(KeyCode::ControlRight);
+    let Some(from) = drag
+        .selected
+        .filter(|_| ctrl_pressed && drag.dragging.is_none())
+    else {
+        return;
+    };
+
+    let to = if bindings.just_pressed(&keyboard, actions::PAN_UP) {
+        from.checked_sub(1)
+    } else if bindings.just_pressed(&keyboard, actions::PAN_DOWN) {
+        Some(from + 1)
+    } else {
+        None
+    };
+    let Some(to) = to else {
+        return;
+    };
+    if move_queued_project(&mut planet_state, from, to) {
+        drag.selected = Some(to);
+        send_tile_update(&planet_state, to, &mut update_events);
+    }
+}
+
+/// Refresh the target tile of the project now at `index`.
+fn send_tile_update(
+    planet_state: &PlanetViewState,
+    index: usize,
+    update_events: &mut MessageWriter<TileUpdateEvent>,
+) {
+    let (Some(project), Some(surface)) = (
+        planet_state.production_queue.get(index),
+        planet_state.surface.as_ref(),
+    ) else {
+        return;
+    };
+    update_events.write(TileUpdateEvent {
+        x: project.target_tile_index % surface.row_width,
+        y: project.target_tile_index / surface.row_width,
+    });
+}
@@ -0,0 +1,29 @@
+use super::QueueDragState;
+
+#[test]
+fn selection_is_cleared_when_the_queue_changes_length() {
+    let mut drag = QueueDragState::default();
+    drag.sync_queue_len(3);
+    drag.selected = Some(2);
+
+    drag.sync_queue_len(3);
+    assert_eq!(drag.selected, Some(2));
+
+    // The first project finished, so row 2 no longer exists.
+    drag.sync_queue_len(2);
+    assert_eq!(drag.selected, None);
+}
+
+#[test]
+fn drag_is_dropped_when_the_queue_changes_length() {
+    let mut drag = QueueDragState::default();
+    drag.sync_queue_len(2);
+    drag.pressed = Some((1, 0.5));
+    drag.dragging = Some(1);
+    drag.drop_index = Some(0);
+
+    drag.sync_queue_len(3);
+    assert_eq!(drag.pressed, None);
+    assert_eq!(drag.dragging, None);
+    assert_eq!(drag.drop_index, None);
+}
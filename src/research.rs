@@ -0,0 +1,300 @@
+//! Research graph and the player's research queue.
+//!
+//! Technologies reference each other through `prerequisites` in
+//! `technologies.ron`. [`ResearchGraph`] turns those references into a
+//! dependency graph that screens can lay out, and [`ResearchQueue`] tracks
+//! which technologies are researched, being researched, or waiting.
+//!
+//! Each turn the colony's science is spent through
+//! [`ResearchQueue::advance_with_science`].
+
+use std::collections::{BTreeMap, BTreeSet, VecDeque};
+
+use bevy::prelude::*;
+use serde::{Deserialize, Serialize};
+use thiserror::Error;
+
+use crate::data_types::{GameData, TechnologyId};
+
+#[cfg(test)]
+mod tests;
+
+/// Technology dependency graph built from game data.
+#[derive(Debug, Clone, Default)]
+pub struct ResearchGraph {
+    /// Prerequisites of every technology (sorted by id).
+    prerequisites: BTreeMap<TechnologyId, Vec<TechnologyId>>,
+}
+
+impl ResearchGraph {
+    /// Build the graph from all loaded technologies.
+    ///
+    /// Prerequisites that reference unknown technologies are ignored.
+    pub fn from_game_data(game_data: &GameData) -> Self {
+        let known: BTreeSet<&str> = game_data
+            .technologies()
+            .iter()
+            .map(|tech| tech.id.as_str())
+            .collect();
+
+        let prerequisites = game_data
+            .technologies()
+            .iter()
+            .map(|tech| {
+                let prereqs = game_data
+                    .tech_prereqs(&tech.id)
+                    .iter()
+                    .filter(|id| known.contains(id.as_str()))
+                    .map(|id| TechnologyId::from(id.as_str()))
+                    .collect();
+                (TechnologyId::from(tech.id.as_str()), prereqs)
+            })
+            .collect();
+
+        Self { prerequisites }
+    }
+
+    /// Prerequisites of a technology (empty for unknown ids).
+    pub fn prerequisites(&self, id: &TechnologyId) -> &[TechnologyId] {
+        self.prerequisites
+            .get(id)
+            .map(Vec::as_slice)
+            .unwrap_or_default()
+    }
+
+    /// Arrange technologies into columns using a topological sort.
+    ///
+    /// Column 0 holds technologies without prerequisites; every other
+    /// technology sits one column to the right of its deepest prerequisite.
+    /// Technologies caught in a dependency cycle cannot be ordered and are
+    /// placed together in a final column.
+    pub fn layers(&self) -> Vec<Vec<TechnologyId>> {
+        let mut remaining: BTreeMap<&TechnologyId, usize> = self
+            .prerequisites
+            .iter()
+            .map(|(id, prereqs)| (id, prereqs.len()))
+            .collect();
+        let mut layer_of: BTreeMap<&TechnologyId, usize> = BTreeMap::new();
+        let mut ready: VecDeque<&TechnologyId> = remaining
+            .iter()
+            .filter(|&(_, &count)| count == 0)
+            .map(|(&id, _)| id)
+            .collect();
+
+        // Kahn's algorithm, tracking the longest path to each node.
+        while let Some(id) = ready.pop_front() {
+            let layer = self
+                .prerequisites(id)
+                .iter()
+                .filter_map(|prereq| layer_of.get(prereq))
+                .map(|&layer| layer + 1)
+                .max()
+                .unwrap_or(0);
+            layer_of.insert(id, layer);
+
+            for (dependent, prereqs) in &self.prerequisites {
+                if prereqs.contains(id) {
+                    let count = remaining.get_mut(dependent).expect("known technology");
+                    *count -= 1;
+                    if *count == 0 {
+                        ready.push_back(dependent);
+                    }
+                }
+            }
+        }
+
+        let depth = layer_of.values().max().map_or(0, |&max| max + 1);
+        let mut layers = vec![Vec::new(); depth];
+        for (id, layer) in &layer_of {
+            layers[*layer].push((*id).clone());
+        }
+
+        let cyclic: Vec<TechnologyId> = self
+            .prerequisites
+            .keys()
+            .filter(|id| !layer_of.contains_key(id))
+            .cloned()
+            .collect();
+        if !cyclic.is_empty() {
+            layers.push(cyclic);
+        }
+
+        layers
+    }
+}
+
+/// Errors returned by [`ResearchQueue::queue_tech`].
+#[derive(Debug, Error, PartialEq, Eq)]
+pub enum QueueError {
+    /// The technology is not defined in the game data.
+    #[error("Unknown technology: {0}")]
+    UnknownTech(String),
+    /// The technology has already been researched.
+    #[error("Technology already researched: {0}")]
+    AlreadyResearched(String),
+    /// The technology is already being researched or waiting in the queue.
+    #[error("Technology already queued: {0}")]
+    AlreadyQueued(String),
+    /// Some prerequisites are neither researched nor queued.
+    #[error("Technology {id} is missing prerequisites: {missing:?}")]
+    MissingPrerequisites {
+        /// Technology that was requested.
+        id: String,
+        /// Prerequisites that are not researched or queued.
+        missing: Vec<String>,
+    },
+}
+
+/// Technologies unlocked by a call to [`ResearchQueue::advance_with_science`].
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct TechUnlockedEvents(pub Vec<TechnologyId>);
+
+/// Research state of one species: finished technologies, the technology
+/// currently being researched, and the technologies queued after it.
+///
+/// Saved alongside the planet by [`crate::save_load`].
+#[derive(Resource, Debug, Clone, Default, Serialize, Deserialize)]
+pub struct ResearchQueue {
+    /// Technologies that have been fully researched.
+    pub researched: BTreeSet<TechnologyId>,
+    /// Technology currently receiving science.
+    pub active: Option<TechnologyId>,
+    /// Technologies waiting to be researched, in order.
+    pub queued: VecDeque<TechnologyId>,
+    /// Science accumulated toward the active technology.
+    ///
+    /// Science earned while nothing is queued is kept here and carried
+    /// over to the next technology.
+    pub progress: i32,
+}
+
+impl ResearchQueue {
+    /// Whether `id` has been researched.
+    pub fn is_researched(&self, id: &TechnologyId) -> bool {
+        self.researched.contains(id)
+    }
+
+    /// Whether `id` is being researched or waiting in the queue.
+    pub fn is_queued(&self, id: &TechnologyId) -> bool {
+        self.active.as_ref() == Some(id) || self.queued.contains(id)
+    }
+
+    /// Whether nothing is being researched or queued.
+    pub fn is_idle(&self) -> bool {
+        self.active.is_none() && self.queued.is_empty()
+    }
+
+    /// Mark `id` as researched without spending science, dropping it from
+    /// the queue.
+    ///
+    /// Science already spent on `id` carries over to the next queued
+    /// technology.
+    pub fn grant(&mut self, id: TechnologyId) {
+        self.queued.retain(|queued| *queued != id);
+        if self.active.as_ref() == Some(&id) {
+            self.active = self.queued.pop_front();
+        }
+        self.researched.insert(id);
+    }
+
+    /// Prerequisites of `id` that are neither researched nor queued ahead of it.
+    pub fn missing_prerequisites(&self, id: &TechnologyId, game_data: &GameData) -> Vec<String> {
+        game_data
+            .tech_prereqs(id.as_str())
+            .iter()
+            .filter(|prereq| {
+                let prereq = TechnologyId::from(prereq.as_str());
+                !self.is_researched(&prereq) && !self.is_queued(&prereq)
+            })
+            .cloned()
+            .collect()
+    }
+
+    /// Whether every prerequisite of `id` is researched or queued ahead of it.
+    pub fn prerequisites_met(&self, id: &TechnologyId, game_data: &GameData) -> bool {
+        self.missing_prerequisites(id, game_data).is_empty()
+    }
+
+    /// Add `id` to the end of the queue.
+    ///
+    /// If nothing is being researched the technology becomes active
+    /// immediately.
+    ///
+    /// # Errors
+    /// Returns a [`QueueError`] (leaving the queue unchanged) if the
+    /// technology is unknown, already researched or queued, or its
+    /// prerequisites are not met.
+    pub fn queue_tech(&mut self, id: TechnologyId, game_data: &GameData) -> Result<(), QueueError> {
+        if !game_data.technologies().iter().any(|t| t.id == id.as_str()) {
+            return Err(QueueError::UnknownTech(id.0));
+        }
+        if self.is_researched(&id) {
+            return Err(QueueError::AlreadyResearched(id.0));
+        }
+        if self.is_queued(&id) {
+            return Err(QueueError::AlreadyQueued(id.0));
+        }
+        let missing = self.missing_prerequisites(&id, game_data);
+        if !missing.is_empty() {
+            return Err(QueueError::MissingPrerequisites { id: id.0, missing });
+        }
+
+        if self.active.is_none() {
+            self.active = Some(id);
+        } else {
+            self.queued.push_back(id);
+        }
+        Ok(())
+    }
+
+    /// Science cost of the active technology, if any.
+    pub fn active_cost(&self, game_data: &GameData) -> Option<i32> {
+        let active = self.active.as_ref()?;
+        game_data
+            .technologies()
+            .iter()
+            .find(|t| t.id == active.as_str())
+            .map(|t| t.science_cost.max(0))
+    }
+
+    /// Spend `science` on the queue.
+    ///
+    /// Completing a technology immediately moves on to the next queued one,
+    /// carrying over any leftover science, so several technologies can be
+    /// unlocked in a single call.
+    pub fn advance_with_science(
+        &mut self,
+        science: i32,
+        game_data: &GameData,
+    ) -> TechUnlockedEvents {
+        let mut unlocked = Vec::new();
+        self.progress += science.max(0);
+
+        loop {
+            if self.active.is_none() {
+                self.active = self.queued.pop_front();
+            }
+            let Some(active) = self.active.clone() else {
+                break;
+            };
+            let Some(cost) = self.active_cost(game_data) else {
+                warn!(
+                    "Dropping unknown technology from research queue: {}",
+                    active.as_str()
+                );
+                self.active = None;
+                continue;
+            };
+            if self.progress < cost {
+                break;
+            }
+
+            self.progress -= cost;
+            self.active = None;
+            self.researched.insert(active.clone());
+            unlocked.push(active);
+        }
+
+        TechUnlockedEvents(unlocked)
+    }
+}
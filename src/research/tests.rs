@@ -0,0 +1,116 @@
+use super::{QueueError, ResearchGraph, ResearchQueue, TechUnlockedEvents};
+use crate::data_types::{GameData, Technology, TechnologyId};
+
+fn tech(id: &str, prerequisites: &[&str]) -> Technology {
+    Technology {
+        id: id.to_string(),
+        name_en: id.to_string(),
+        science_cost: 100,
+        description_en: String::new(),
+        prerequisites: prerequisites.iter().map(|p| p.to_string()).collect(),
+        accident_chance: 0.0,
+    }
+}
+
+fn game_data(technologies: Vec<Technology>) -> GameData {
+    GameData {
+        surface_cell_types: Vec::new(),
+        surface_buildings: Vec::new(),
+        technologies,
+        victory_conditions: Vec::new(),
+        scenarios: Vec::new(),
+        adjacency_bonuses: Vec::new(),
+        planet_events: Vec::new(),
+    }
+}
+
+fn graph(technologies: Vec<Technology>) -> ResearchGraph {
+    ResearchGraph::from_game_data(&game_data(technologies))
+}
+
+fn ids(layer: &[TechnologyId]) -> Vec<&str> {
+    layer.iter().map(TechnologyId::as_str).collect()
+}
+
+#[test]
+fn layers_follow_longest_prerequisite_chain() {
+    let graph = graph(vec![
+        tech("c", &["a", "b"]),
+        tech("b", &["a"]),
+        tech("a", &[]),
+        tech("d", &[]),
+    ]);
+
+    let layers = graph.layers();
+    assert_eq!(layers.len(), 3);
+    assert_eq!(ids(&layers[0]), ["a", "d"]);
+    assert_eq!(ids(&layers[1]), ["b"]);
+    assert_eq!(ids(&layers[2]), ["c"]);
+}
+
+#[test]
+fn cyclic_technologies_share_a_final_layer() {
+    let graph = graph(vec![tech("a", &[]), tech("x", &["y"]), tech("y", &["x"])]);
+
+    let layers = graph.layers();
+    assert_eq!(ids(&layers[0]), ["a"]);
+    assert_eq!(ids(layers.last().unwrap()), ["x", "y"]);
+}
+
+#[test]
+fn queue_tech_validates_prerequisites() {
+    let data = game_data(vec![tech("a", &[]), tech("b", &["a"])]);
+    let mut queue = ResearchQueue::default();
+
+    assert_eq!(
+        queue.queue_tech("b".into(), &data),
+        Err(QueueError::MissingPrerequisites {
+            id: "b".to_string(),
+            missing: vec!["a".to_string()],
+        })
+    );
+    assert_eq!(queue.queue_tech("a".into(), &data), Ok(()));
+    assert_eq!(
+        queue.queue_tech("a".into(), &data),
+        Err(QueueError::AlreadyQueued("a".to_string()))
+    );
+    assert_eq!(
+        queue.queue_tech("b".into(), &data),
+        Ok(()),
+        "a is queued ahead of b"
+    );
+    assert_eq!(
+        queue.queue_tech("z".into(), &data),
+        Err(QueueError::UnknownTech("z".to_string()))
+    );
+
+    assert_eq!(queue.active, Some("a".into()));
+    assert_eq!(ids(queue.queued.make_contiguous()), ["b"]);
+}
+
+#[test]
+fn science_advances_through_the_queue() {
+    let data = game_data(vec![tech("a", &[]), tech("b", &["a"]), tech("c", &[])]);
+    let mut queue = ResearchQueue::default();
+    queue.queue_tech("a".into(), &data).unwrap();
+    queue.queue_tech("b".into(), &data).unwrap();
+    queue.queue_tech("c".into(), &data).unwrap();
+
+    assert_eq!(
+        queue.advance_with_science(60, &data),
+        TechUnlockedEvents::default()
+    );
+    assert_eq!(queue.progress, 60);
+
+    // 60 + 150 completes "a" (100) and "b" (100) with 10 left over for "c".
+    let unlocked = queue.advance_with_science(150, &data);
+    assert_eq!(ids(&unlocked.0), ["a", "b"]);
+    assert_eq!(queue.active, Some("c".into()));
+    assert_eq!(queue.progress, 10);
+    assert!(queue.is_researched(&"b".into()));
+
+    assert_eq!(
+        queue.queue_tech("a".into(), &data),
+        Err(QueueError::AlreadyResearched("a".to_string()))
+    );
+}
@@ -0,0 +1,102 @@
+//! Automatic saving at the end of the player's turn.
+//!
+//! Every [`AutoSaveConfig::frequency_turns`] turns the game is written to
+//! [`AUTOSAVE_PATH`], overwriting the previous autosave. A failed autosave
+//! is logged and announced with a sound but never interrupts play.
+
+use std::path::Path;
+
+use bevy::prelude::*;
+use serde::{Deserialize, Serialize};
+
+use crate::data_types::load_ron_file;
+use crate::planet_view::types::TurnEnded;
+use crate::sfx::PlaySfx;
+
+use super::save_game;
+
+/// Player settings for autosaving.
+pub const AUTOSAVE_CONFIG_PATH: &str = "settings/autosave.ron";
+
+/// Save slot overwritten by every autosave.
+pub const AUTOSAVE_PATH: &str = "saves/autosave.ron";
+
+/// How often the game saves itself.
+///
+/// # RON Example
+/// ```ron
+/// (enabled: true, frequency_turns: 5)
+/// ```
+#[derive(Resource, Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct AutoSaveConfig {
+    /// Whether autosaving is on.
+    pub enabled: bool,
+    /// Save on every turn that is a multiple of this (0 never saves).
+    pub frequency_turns: u32,
+}
+
+impl Default for AutoSaveConfig {
+    fn default() -> Self {
+        Self {
+            enabled: true,
+            frequency_turns: 1,
+        }
+    }
+}
+
+impl AutoSaveConfig {
+    /// Whether the game should be saved when `turn` begins.
+    pub fn is_due(&self, turn: u32) -> bool {
+        self.enabled && self.frequency_turns > 0 && turn % self.frequency_turns == 0
+    }
+}
+
+/// Load the autosave settings, falling back to [`AutoSaveConfig::default`]
+/// when the file is missing or broken.
+pub fn load_autosave_config(path: &Path) -> AutoSaveConfig {
+    if !path.exists() {
+        return AutoSaveConfig::default();
+    }
+    load_ron_file(path).unwrap_or_else(|err| {
+        warn!("Using default autosave settings: {err}");
+        AutoSaveConfig::default()
+    })
+}
+
+/// Plugin that loads [`AutoSaveConfig`] and autosaves after each turn.
+pub struct AutoSavePlugin;
+
+impl Plugin for AutoSavePlugin {
+    fn build(&self, app: &mut App) {
+        app.insert_resource(load_autosave_config(Path::new(AUTOSAVE_CONFIG_PATH)))
+            // After Update so the save includes the AI turns and victory check.
+            .add_systems(PostUpdate, autosave_system);
+    }
+}
+
+/// Write [`AUTOSAVE_PATH`] when a turn the config asks for begins.
+fn autosave_system(
+    mut turn_events: MessageReader<TurnEnded>,
+    config: Res<AutoSaveConfig>,
+    mut commands: Commands,
+) {
+    let Some(event) = turn_events.read().last() else {
+        return;
+    };
+    if !config.is_due(event.turn) {
+        return;
+    }
+
+    // Saving needs read access to every game resource.
+    commands.queue(|world: &mut World| {
+        let path = Path::new(AUTOSAVE_PATH);
+        match save_game(path, world) {
+            Ok(()) => info!("Autosaved to {}", path.display()),
+            Err(err) => {
+                error!("Autosave failed: {err}");
+                world.write_message(PlaySfx::Notification);
+            }
+        }
+    });
+}
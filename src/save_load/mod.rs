@@ -0,0 +1,276 @@
+//! Save/load serialization for game sessions.
+//!
+//! A save game is a single RON file containing a version header and a
+//! snapshot of every resource needed to resume play. Loading is split
+//! into two steps so a forward-incompatible file is rejected before any
+//! Bevy resource is touched:
+//!
+//! 1. [`load_game`] reads and validates the file into a [`SaveGame`]
+//! 2. [`SaveGame::restore`] inserts the resources and re-enters the game screen
+//!
+//! The [`AutoSavePlugin`] additionally writes [`AUTOSAVE_PATH`] at the end
+//! of the player's turn (see [`AutoSaveConfig`]).
+//!
+//! Each save also carries a [`SaveMetadata`] header that [`list_saves`]
+//! reads to fill the main menu's save browser without parsing whole files.
+//!
+//! # Example
+//! ```ignore
+//! save_game(Path::new(DEFAULT_SAVE_PATH), world)?;
+//! let save = load_game(Path::new(DEFAULT_SAVE_PATH))?;
+//! save.restore(world);
+//! ```
+
+mod autosave;
+mod slots;
+
+use std::fs;
+use std::path::Path;
+use std::time::SystemTime;
+
+use bevy::prelude::*;
+use serde::{Deserialize, Serialize};
+use thiserror::Error;
+
+use crate::ai::AiState;
+use crate::defeat_screen::types::GameStatsTracker;
+use crate::diplomacy::{DiplomacyState, PLAYER_SPECIES_NAME};
+use crate::main_menu::GameState;
+use crate::planet_view::types::PlanetViewState;
+use crate::planet_view::ui::notification::NotificationQueue;
+use crate::planet_view::ui::notification_log::NotificationLog;
+use crate::research::ResearchQueue;
+
+pub use autosave::{
+    AUTOSAVE_CONFIG_PATH, AUTOSAVE_PATH, AutoSaveConfig, AutoSavePlugin, load_autosave_config,
+};
+pub use slots::{SaveSlot, delete_save, format_timestamp, list_saves, read_save_metadata};
+
+#[cfg(test)]
+mod tests;
+
+/// Current save file format version.
+///
+/// Bump this whenever the serialized layout changes in a way older
+/// builds cannot read.
+pub const SAVE_VERSION: u32 = 1;
+
+/// Directory holding the save files listed by the save browser.
+pub const SAVES_DIR: &str = "saves";
+
+/// Default location of the quick save slot used by the main menu.
+pub const DEFAULT_SAVE_PATH: &str = "saves/quicksave.ron";
+
+/// Errors that can occur while saving or loading a game.
+#[derive(Debug, Error)]
+pub enum SaveError {
+    /// The requested save file does not exist.
+    #[error("Save file not found: {path}")]
+    NotFound {
+        /// Path that was requested.
+        path: String,
+    },
+    /// File read or write failure.
+    #[error("Failed to access {path}: {source}")]
+    Io {
+        /// Source I/O error.
+        source: std::io::Error,
+        /// Path that failed.
+        path: String,
+    },
+    /// RON parse failure.
+    #[error("Failed to parse save file {path}: {source}")]
+    Parse {
+        /// RON parse error.
+        source: ron::error::SpannedError,
+        /// Path that failed.
+        path: String,
+    },
+    /// RON serialization failure.
+    #[error("Failed to serialize save game: {0}")]
+    Serialize(#[from] ron::Error),
+    /// Save was written by a newer build and cannot be migrated.
+    #[error(
+        "Save file {path} uses version {found}, but this build only supports up to version {current}"
+    )]
+    UnsupportedVersion {
+        /// Version found in the save file.
+        found: u32,
+        /// Latest version supported by this build.
+        current: u32,
+        /// Path of the save file.
+        path: String,
+    },
+    /// There is no game session in progress to save.
+    #[error("No game in progress to save")]
+    NothingToSave,
+}
+
+/// Summary of a save shown in the save browser.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct SaveMetadata {
+    /// Turn the game was saved on.
+    pub turn: u32,
+    /// Name of the player's species.
+    pub species_name: String,
+    /// When the game was saved, as `YYYY-MM-DD HH:MM` (UTC).
+    pub timestamp: String,
+    /// Save format version, copied from the file's `version` field.
+    #[serde(skip)]
+    pub save_version: u32,
+}
+
+/// Snapshot of a game session.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SaveGame {
+    /// Save format version (see [`SAVE_VERSION`]).
+    pub version: u32,
+    /// Summary for the save browser (empty in saves made before it existed).
+    #[serde(default)]
+    pub metadata: SaveMetadata,
+    /// Planet surface, resources, research, and production queue.
+    pub planet: PlanetViewState,
+    /// AI species and their colonies.
+    #[serde(default)]
+    pub ai: AiState,
+    /// Researched and queued technologies.
+    #[serde(default)]
+    pub research: ResearchQueue,
+    /// Stances between the player and AI species.
+    #[serde(default)]
+    pub diplomacy: DiplomacyState,
+    /// History of the notifications shown to the player.
+    #[serde(default)]
+    pub notification_log: NotificationLog,
+    /// Statistics shown on the defeat screen.
+    #[serde(default)]
+    pub game_stats: GameStatsTracker,
+}
+
+/// Minimal view of a save file used to check compatibility and list saves
+/// before deserializing the full payload.
+#[derive(Deserialize)]
+struct SaveHeader {
+    version: u32,
+    #[serde(default)]
+    metadata: SaveMetadata,
+}
+
+impl SaveGame {
+    /// Capture the current session from the world.
+    ///
+    /// # Errors
+    /// Returns [`SaveError::NothingToSave`] if no planet has been generated yet.
+    pub fn capture(world: &World) -> Result<Self, SaveError> {
+        let planet = world
+            .get_resource::<PlanetViewState>()
+            .filter(|state| state.surface.is_some())
+            .ok_or(SaveError::NothingToSave)?;
+
+        Ok(Self {
+            version: SAVE_VERSION,
+            metadata: SaveMetadata {
+                turn: planet.turn,
+                species_name: PLAYER_SPECIES_NAME.to_string(),
+                timestamp: format_timestamp(SystemTime::now()),
+                save_version: SAVE_VERSION,
+            },
+            planet: planet.clone(),
+            ai: world.get_resource::<AiState>().cloned().unwrap_or_default(),
+            research: world
+                .get_resource::<ResearchQueue>()
+                .cloned()
+                .unwrap_or_default(),
+            diplomacy: world
+                .get_resource::<DiplomacyState>()
+                .cloned()
+                .unwrap_or_default(),
+            notification_log: world
+                .get_resource::<NotificationLog>()
+                .cloned()
+                .unwrap_or_default(),
+            game_stats: world
+                .get_resource::<GameStatsTracker>()
+                .cloned()
+                .unwrap_or_default(),
+        })
+    }
+
+    /// Insert the saved resources into the world and enter the planet view.
+    ///
+    /// Notifications left over from the previous session are discarded;
+    /// the notification log is restored from the save.
+    pub fn restore(self, world: &mut World) {
+        world.insert_resource(self.planet);
+        world.insert_resource(self.ai);
+        world.insert_resource(self.research);
+        world.insert_resource(self.diplomacy);
+        world.insert_resource(NotificationQueue::default());
+        world.insert_resource(self.notification_log);
+        world.insert_resource(self.game_stats);
+        world
+            .resource_mut::<NextState<GameState>>()
+            .set(GameState::PlanetView);
+    }
+}
+
+/// Serialize the current session to a RON file at `path`.
+///
+/// Parent directories are created as needed.
+pub fn save_game(path: &Path, world: &World) -> Result<(), SaveError> {
+    let save = SaveGame::capture(world)?;
+    let content = ron::ser::to_string_pretty(&save, ron::ser::PrettyConfig::default())?;
+
+    if let Some(parent) = path.parent().filter(|p| !p.as_os_str().is_empty()) {
+        fs::create_dir_all(parent).map_err(|source| SaveError::Io {
+            source,
+            path: parent.display().to_string(),
+        })?;
+    }
+
+    fs::write(path, content).map_err(|source| SaveError::Io {
+        source,
+        path: path.display().to_string(),
+    })
+}
+
+/// Read a save file from `path`.
+///
+/// The version header is checked before the full payload is parsed, so
+/// saves from newer builds report [`SaveError::UnsupportedVersion`]
+/// instead of a confusing parse error.
+pub fn load_game(path: &Path) -> Result<SaveGame, SaveError> {
+    let display = path.display().to_string();
+    let content = read_save_file(path)?;
+
+    let header: SaveHeader = ron::from_str(&content).map_err(|source| SaveError::Parse {
+        source,
+        path: display.clone(),
+    })?;
+    if header.version > SAVE_VERSION {
+        return Err(SaveError::UnsupportedVersion {
+            found: header.version,
+            current: SAVE_VERSION,
+            path: display,
+        });
+    }
+
+    let mut save: SaveGame = ron::from_str(&content).map_err(|source| SaveError::Parse {
+        source,
+        path: display,
+    })?;
+    save.metadata.save_version = save.version;
+    Ok(save)
+}
+
+/// Read a save file, reporting a missing file as [`SaveError::NotFound`].
+fn read_save_file(path: &Path) -> Result<String, SaveError> {
+    fs::read_to_string(path).map_err(|source| {
+        let path = path.display().to_string();
+        if source.kind() == std::io::ErrorKind::NotFound {
+            SaveError::NotFound { path }
+        } else {
+            SaveError::Io { source, path }
+        }
+    })
+}
@@ -0,0 +1,123 @@
+//! Listing, inspecting, and deleting the save files in [`SAVES_DIR`].
+//!
+//! [`SAVES_DIR`]: super::SAVES_DIR
+
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::time::{SystemTime, UNIX_EPOCH};
+
+use bevy::log::warn;
+
+use super::{AUTOSAVE_PATH, SaveError, SaveHeader, SaveMetadata, read_save_file};
+
+/// A save file found by [`list_saves`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SaveSlot {
+    /// Path of the save file.
+    pub path: PathBuf,
+    /// Summary read from the file's header.
+    pub metadata: SaveMetadata,
+}
+
+impl SaveSlot {
+    /// File name without the `.ron` extension, e.g. `quicksave`.
+    pub fn name(&self) -> String {
+        self.path
+            .file_stem()
+            .map_or_else(String::new, |stem| stem.to_string_lossy().into_owned())
+    }
+
+    /// Whether this is the slot written by autosaving.
+    pub fn is_autosave(&self) -> bool {
+        self.path.file_name() == Path::new(AUTOSAVE_PATH).file_name()
+    }
+}
+
+/// Read only the [`SaveMetadata`] of the save file at `path`.
+///
+/// # Errors
+/// Returns an error if the file cannot be read or has no valid header.
+pub fn read_save_metadata(path: &Path) -> Result<SaveMetadata, SaveError> {
+    let content = read_save_file(path)?;
+    let header: SaveHeader = ron::from_str(&content).map_err(|source| SaveError::Parse {
+        source,
+        path: path.display().to_string(),
+    })?;
+
+    let mut metadata = header.metadata;
+    metadata.save_version = header.version;
+    Ok(metadata)
+}
+
+/// Every readable `.ron` save in `dir`, newest first.
+///
+/// A missing directory means there are no saves; files whose header cannot
+/// be read are skipped with a warning.
+pub fn list_saves(dir: &Path) -> Vec<SaveSlot> {
+    let Ok(entries) = fs::read_dir(dir) else {
+        return Vec::new();
+    };
+
+    let mut slots: Vec<SaveSlot> = entries
+        .filter_map(|entry| entry.ok().map(|entry| entry.path()))
+        .filter(|path| path.extension().is_some_and(|ext| ext == "ron"))
+        .filter_map(|path| match read_save_metadata(&path) {
+            Ok(metadata) => Some(SaveSlot { path, metadata }),
+            Err(err) => {
+                warn!("Skipping save file: {err}");
+                None
+            }
+        })
+        .collect();
+
+    // Timestamps sort chronologically as text; break ties by file name.
+    slots.sort_by(|a, b| {
+        b.metadata
+            .timestamp
+            .cmp(&a.metadata.timestamp)
+            .then_with(|| a.path.cmp(&b.path))
+    });
+    slots
+}
+
+/// Delete the save file at `path`.
+///
+/// # Errors
+/// Returns an error if the file cannot be removed.
+pub fn delete_save(path: &Path) -> Result<(), SaveError> {
+    fs::remove_file(path).map_err(|source| SaveError::Io {
+        source,
+        path: path.display().to_string(),
+    })
+}
+
+/// Format `time` as `YYYY-MM-DD HH:MM` in UTC.
+pub fn format_timestamp(time: SystemTime) -> String {
+    let seconds = time
+        .duration_since(UNIX_EPOCH)
+        .map_or(0, |duration| duration.as_secs());
+    let (days, seconds_of_day) = (seconds / 86_400, seconds % 86_400);
+    let (year, month, day) = civil_from_days(days as i64);
+    format!(
+        "{year:04}-{month:02}-{day:02} {:02}:{:02}",
+        seconds_of_day / 3600,
+        seconds_of_day % 3600 / 60
+    )
+}
+
+/// Convert days since 1970-01-01 to a (year, month, day) date.
+///
+/// Howard Hinnant's `civil_from_days` algorithm.
+fn civil_from_days(days: i64) -> (i64, u32, u32) {
+    let z = days + 719_468;
+    let era = z.div_euclid(146_097);
+    let day_of_era = z.rem_euclid(146_097);
+    let year_of_era =
+        (day_of_era - day_of_era / 1460 + day_of_era / 36_524 - day_of_era / 146_096) / 365;
+    let day_of_year = day_of_era - (365 * year_of_era + year_of_era / 4 - year_of_era / 100);
+    let mp = (5 * day_of_year + 2) / 153;
+    let day = (day_of_year - (153 * mp + 2) / 5 + 1) as u32;
+    let month = if mp < 10 { mp + 3 } else { mp - 9 } as u32;
+    let year = year_of_era + era * 400 + i64::from(month <= 2);
+    (year, month, day)
+}
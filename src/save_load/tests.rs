@@ -0,0 +1,200 @@
+use std::path::PathBuf;
+
+use bevy::prelude::World;
+
+use super::{
+    AutoSaveConfig, SAVE_VERSION, SaveError, SaveSlot, delete_save, format_timestamp, list_saves,
+    load_autosave_config, load_game, read_save_metadata, save_game,
+};
+use crate::planet_data::generate_planet;
+use crate::planet_view::types::PlanetViewState;
+use crate::planet_view::ui::notification::{Notification, NotificationKind};
+use crate::planet_view::ui::notification_log::NotificationLog;
+
+fn temp_save_path(name: &str) -> PathBuf {
+    std::env::temp_dir()
+        .join(format!("ascenoria_save_{}", std::process::id()))
+        .join(name)
+}
+
+#[test]
+fn round_trips_planet_state() {
+    let mut world = World::new();
+    world.insert_resource(PlanetViewState {
+        surface: Some(generate_planet(7, false)),
+        turn: 12,
+        food: 40,
+        production: 9,
+        ..Default::default()
+    });
+
+    let path = temp_save_path("round_trip.ron");
+    save_game(&path, &world).expect("save should succeed");
+    let save = load_game(&path).expect("load should succeed");
+
+    assert_eq!(save.version, SAVE_VERSION);
+    assert_eq!(save.planet.turn, 12);
+    assert_eq!(save.planet.food, 40);
+    assert_eq!(save.planet.surface, Some(generate_planet(7, false)));
+}
+
+#[test]
+fn round_trips_notification_log() {
+    let mut world = World::new();
+    world.insert_resource(PlanetViewState {
+        surface: Some(generate_planet(7, false)),
+        ..Default::default()
+    });
+    let mut log = NotificationLog::default();
+    log.record(
+        4,
+        &Notification {
+            kind: NotificationKind::Research,
+            title: "Terraforming".to_string(),
+            lines: vec!["New buildings: Terraformer".to_string()],
+        },
+    );
+    world.insert_resource(log.clone());
+
+    let path = temp_save_path("notification_log.ron");
+    save_game(&path, &world).expect("save should succeed");
+    let save = load_game(&path).expect("load should succeed");
+
+    assert_eq!(save.notification_log, log);
+}
+
+#[test]
+fn refuses_to_save_without_a_session() {
+    let mut world = World::new();
+    world.insert_resource(PlanetViewState::default());
+
+    let error = save_game(&temp_save_path("empty.ron"), &world).expect_err("nothing to save");
+    assert!(matches!(error, SaveError::NothingToSave));
+}
+
+#[test]
+fn reports_missing_save_file() {
+    let error = load_game(&temp_save_path("does_not_exist.ron")).expect_err("file is missing");
+    assert!(matches!(error, SaveError::NotFound { .. }));
+}
+
+#[test]
+fn rejects_saves_from_newer_versions() {
+    let path = temp_save_path("future.ron");
+    std::fs::create_dir_all(path.parent().unwrap()).unwrap();
+    std::fs::write(
+        &path,
+        format!("(version: {}, planet: ())", SAVE_VERSION + 1),
+    )
+    .unwrap();
+
+    match load_game(&path).expect_err("future version should be rejected") {
+        SaveError::UnsupportedVersion { found, current, .. } => {
+            assert_eq!(found, SAVE_VERSION + 1);
+            assert_eq!(current, SAVE_VERSION);
+        }
+        other => panic!("Unexpected error: {other:?}"),
+    }
+}
+
+#[test]
+fn records_metadata_for_the_save_browser() {
+    let mut world = World::new();
+    world.insert_resource(PlanetViewState {
+        surface: Some(generate_planet(3, false)),
+        turn: 27,
+        ..Default::default()
+    });
+
+    let path = temp_save_path("metadata.ron");
+    save_game(&path, &world).expect("save should succeed");
+    let metadata = read_save_metadata(&path).expect("header should parse");
+
+    assert_eq!(metadata.turn, 27);
+    assert_eq!(metadata.species_name, "Player");
+    assert_eq!(metadata.save_version, SAVE_VERSION);
+    assert_eq!(metadata.timestamp.len(), "2024-01-01 00:00".len());
+}
+
+#[test]
+fn lists_saves_newest_first_and_deletes_them() {
+    let dir = temp_save_path("browser");
+    std::fs::create_dir_all(&dir).unwrap();
+    for (name, timestamp) in [("old", "2024-01-01 10:00"), ("new", "2024-03-05 08:30")] {
+        std::fs::write(
+            dir.join(format!("{name}.ron")),
+            format!(
+                "(version: {SAVE_VERSION}, metadata: (turn: 1, species_name: \"Player\", timestamp: \"{timestamp}\"), planet: ())"
+            ),
+        )
+        .unwrap();
+    }
+    std::fs::write(dir.join("broken.ron"), "not a save").unwrap();
+    std::fs::write(dir.join("notes.txt"), "ignored").unwrap();
+
+    let names: Vec<String> = list_saves(&dir).iter().map(|slot| slot.name()).collect();
+    assert_eq!(names, ["new", "old"]);
+
+    delete_save(&dir.join("old.ron")).expect("delete should succeed");
+    let names: Vec<String> = list_saves(&dir).iter().map(|slot| slot.name()).collect();
+    assert_eq!(names, ["new"]);
+}
+
+#[test]
+fn missing_save_directory_has_no_saves() {
+    assert!(list_saves(&temp_save_path("no_such_dir")).is_empty());
+}
+
+#[test]
+fn formats_timestamps_as_utc_dates() {
+    let time = std::time::UNIX_EPOCH + std::time::Duration::from_secs(1_709_627_400);
+    assert_eq!(format_timestamp(time), "2024-03-05 08:30");
+}
+
+#[test]
+fn autosaves_on_multiples_of_the_frequency() {
+    let config = AutoSaveConfig {
+        enabled: true,
+        frequency_turns: 5,
+    };
+    assert!(!config.is_due(4));
+    assert!(config.is_due(5));
+    assert!(config.is_due(10));
+
+    let disabled = AutoSaveConfig {
+        enabled: false,
+        ..config
+    };
+    assert!(!disabled.is_due(5));
+
+    let never = AutoSaveConfig {
+        enabled: true,
+        frequency_turns: 0,
+    };
+    assert!(!never.is_due(5));
+}
+
+#[test]
+fn loads_autosave_config_with_defaults() {
+    assert_eq!(
+        load_autosave_config(&temp_save_path("no_autosave_config.ron")),
+        AutoSaveConfig::default()
+    );
+
+    let path = temp_save_path("autosave_config.ron");
+    std::fs::create_dir_all(path.parent().unwrap()).unwrap();
+    std::fs::write(&path, "(frequency_turns: 3)").unwrap();
+    let config = load_autosave_config(&path);
+    assert!(config.enabled);
+    assert_eq!(config.frequency_turns, 3);
+}
+
+#[test]
+fn recognizes_the_autosave_slot() {
+    let slot = |path: &str| SaveSlot {
+        path: PathBuf::from(path),
+        metadata: Default::default(),
+    };
+    assert!(slot("saves/autosave.ron").is_autosave());
+    assert!(!slot("saves/quicksave.ron").is_autosave());
+}
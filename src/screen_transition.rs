@@ -0,0 +1,159 @@
+//! Fade to black and back when switching screens.
+//!
+//! Screens request a switch by writing [`TransitionToState`] instead of
+//! setting [`NextState`] directly. The screen fades out over
+//! [`FADE_SECONDS`], the state changes while it is black, and the new
+//! screen fades in. Clicks are blocked while a fade is running.
+//!
+//! # Example
+//! ```ignore
+//! fn on_back(mut transitions: MessageWriter<TransitionToState>) {
+//!     transitions.write(TransitionToState(GameState::MainMenu));
+//! }
+//! ```
+
+use bevy::prelude::*;
+use bevy::ui::FocusPolicy;
+
+use crate::main_menu::GameState;
+
+#[cfg(test)]
+mod tests;
+
+/// Duration of each half of a transition (fade out, then fade in).
+pub const FADE_SECONDS: f32 = 0.3;
+
+/// Request to switch to another screen with a fade.
+#[derive(Message, Debug, Clone, Copy, PartialEq, Eq)]
+pub struct TransitionToState(pub GameState);
+
+/// Which way the fade overlay is moving.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum FadeDirection {
+    /// No transition running; the overlay is transparent.
+    #[default]
+    Idle,
+    /// Darkening towards black before the state change.
+    FadeOut,
+    /// Clearing after the state change.
+    FadeIn,
+}
+
+/// Progress of the current screen transition.
+#[derive(Resource, Debug, Default)]
+pub struct FadeState {
+    /// Opacity of the black overlay, from 0.0 to 1.0.
+    pub alpha: f32,
+    /// Current phase of the transition.
+    pub direction: FadeDirection,
+    /// State to switch to once the screen is black.
+    pub queued_state: Option<GameState>,
+}
+
+impl FadeState {
+    /// Start fading out towards `state`.
+    ///
+    /// A request during a running fade takes over from the current
+    /// opacity, and the latest requested state wins.
+    pub fn start(&mut self, state: GameState) {
+        self.queued_state = Some(state);
+        self.direction = FadeDirection::FadeOut;
+    }
+
+    /// Whether a transition is running.
+    pub fn is_active(&self) -> bool {
+        self.direction != FadeDirection::Idle
+    }
+
+    /// Advance the fade by `delta` seconds.
+    ///
+    /// Returns the queued state on the frame the screen turns black; the
+    /// fade-in starts immediately after.
+    pub fn advance(&mut self, delta: f32) -> Option<GameState> {
+        let step = delta / FADE_SECONDS;
+        match self.direction {
+            FadeDirection::Idle => None,
+            FadeDirection::FadeOut => {
+                self.alpha = (self.alpha + step).min(1.0);
+                if self.alpha < 1.0 {
+                    return None;
+                }
+                self.direction = FadeDirection::FadeIn;
+                self.queued_state.take()
+            }
+            FadeDirection::FadeIn => {
+                self.alpha = (self.alpha - step).max(0.0);
+                if self.alpha <= 0.0 {
+                    self.direction = FadeDirection::Idle;
+                }
+                None
+            }
+        }
+    }
+}
+
+/// Marker for the fullscreen fade overlay.
+#[derive(Component)]
+pub struct FadeOverlay;
+
+/// Plugin that fades between screens on [`TransitionToState`].
+pub struct ScreenTransitionPlugin;
+
+impl Plugin for ScreenTransitionPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<FadeState>()
+            .add_message::<TransitionToState>()
+            .add_systems(Startup, spawn_fade_overlay)
+            .add_systems(Update, (start_transition_system, fade_system).chain());
+    }
+}
+
+/// Spawn the overlay; it lives for the whole session, above every screen.
+fn spawn_fade_overlay(mut commands: Commands) {
+    commands.spawn((
+        Node {
+            width: Val::Percent(100.0),
+            height: Val::Percent(100.0),
+            position_type: PositionType::Absolute,
+            ..default()
+        },
+        BackgroundColor(Color::BLACK.with_alpha(0.0)),
+        GlobalZIndex(999),
+        FocusPolicy::Pass,
+        FadeOverlay,
+    ));
+}
+
+/// Begin a fade for each requested transition.
+pub fn start_transition_system(
+    mut requests: MessageReader<TransitionToState>,
+    mut fade: ResMut<FadeState>,
+) {
+    if let Some(TransitionToState(state)) = requests.read().last() {
+        fade.start(*state);
+    }
+}
+
+/// Animate the overlay and switch state when the screen is black.
+pub fn fade_system(
+    time: Res<Time>,
+    mut fade: ResMut<FadeState>,
+    mut next_state: ResMut<NextState<GameState>>,
+    mut overlay_query: Query<(&mut BackgroundColor, &mut FocusPolicy), With<FadeOverlay>>,
+) {
+    if !fade.is_active() {
+        return;
+    }
+    if let Some(state) = fade.advance(time.delta_secs()) {
+        next_state.set(state);
+    }
+
+    for (mut color, mut focus) in &mut overlay_query {
+        *color = BackgroundColor(Color::BLACK.with_alpha(fade.alpha));
+        *focus = if fade.is_active() {
+            FocusPolicy::Block
+        } else {
+            FocusPolicy::Pass
+        };
+    }
+}
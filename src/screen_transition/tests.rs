@@ -0,0 +1,54 @@
+use crate::main_menu::GameState;
+
+use super::{FADE_SECONDS, FadeDirection, FadeState};
+
+#[test]
+fn idle_fade_does_nothing() {
+    let mut fade = FadeState::default();
+
+    assert_eq!(fade.advance(1.0), None);
+    assert_eq!(fade.alpha, 0.0);
+    assert!(!fade.is_active());
+}
+
+#[test]
+fn state_changes_once_the_screen_is_black() {
+    let mut fade = FadeState::default();
+    fade.start(GameState::TechTree);
+
+    assert_eq!(fade.advance(FADE_SECONDS / 2.0), None);
+    assert!(fade.alpha > 0.0 && fade.alpha < 1.0);
+
+    assert_eq!(fade.advance(FADE_SECONDS), Some(GameState::TechTree));
+    assert_eq!(fade.alpha, 1.0);
+    assert_eq!(fade.direction, FadeDirection::FadeIn);
+}
+
+#[test]
+fn fade_in_ends_transparent_and_idle() {
+    let mut fade = FadeState::default();
+    fade.start(GameState::PlanetView);
+    fade.advance(FADE_SECONDS);
+
+    assert_eq!(fade.advance(FADE_SECONDS), None);
+
+    assert_eq!(fade.alpha, 0.0);
+    assert!(!fade.is_active());
+    assert_eq!(fade.queued_state, None);
+}
+
+#[test]
+fn request_during_fade_in_fades_out_from_current_alpha() {
+    let mut fade = FadeState::default();
+    fade.start(GameState::PlanetView);
+    fade.advance(FADE_SECONDS);
+    fade.advance(FADE_SECONDS / 2.0);
+    let alpha = fade.alpha;
+
+    fade.start(GameState::MainMenu);
+
+    assert_eq!(fade.direction, FadeDirection::FadeOut);
+    assert_eq!(fade.advance(0.0), None);
+    assert_eq!(fade.alpha, alpha);
+    assert_eq!(fade.advance(FADE_SECONDS), Some(GameState::MainMenu));
+}
@@ -0,0 +1,137 @@
+//! Player settings stored in [`SETTINGS_PATH`].
+//!
+//! [`GameSettings`] is loaded at startup and applied to the matching Bevy
+//! resources ([`UiScale`] and [`GlobalVolume`]). Every change, e.g. from the
+//! main menu's Settings panel, is applied right away and written back to
+//! disk. A missing or broken file is replaced with the defaults.
+//!
+//! # RON Example
+//! ```ron
+//! (
+//!     font_size_multiplier: 1.25,
+//!     audio_volume: 0.8,
+//! )
+//! ```
+
+use std::fs;
+use std::path::Path;
+
+use bevy::audio::Volume;
+use bevy::prelude::*;
+use serde::{Deserialize, Serialize};
+
+use crate::data_types::{DataLoadError, load_ron_file};
+
+#[cfg(test)]
+mod tests;
+
+/// Where the player's settings are stored.
+pub const SETTINGS_PATH: &str = "settings/settings.ron";
+
+/// Smallest allowed [`GameSettings::font_size_multiplier`].
+pub const MIN_FONT_SIZE_MULTIPLIER: f32 = 0.75;
+
+/// Largest allowed [`GameSettings::font_size_multiplier`].
+pub const MAX_FONT_SIZE_MULTIPLIER: f32 = 1.5;
+
+/// Player-adjustable settings.
+#[derive(Resource, Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct GameSettings {
+    /// Scale of the whole UI, text included (1.0 is normal size).
+    pub font_size_multiplier: f32,
+    /// Volume of all sounds, from 0.0 (muted) to 1.0.
+    pub audio_volume: f32,
+}
+
+impl Default for GameSettings {
+    fn default() -> Self {
+        Self {
+            font_size_multiplier: 1.0,
+            audio_volume: 1.0,
+        }
+    }
+}
+
+impl GameSettings {
+    /// These settings with every value moved into its allowed range.
+    pub fn clamped(self) -> Self {
+        Self {
+            font_size_multiplier: self
+                .font_size_multiplier
+                .clamp(MIN_FONT_SIZE_MULTIPLIER, MAX_FONT_SIZE_MULTIPLIER),
+            audio_volume: self.audio_volume.clamp(0.0, 1.0),
+        }
+    }
+}
+
+/// Load the settings at `path`.
+///
+/// A missing or broken file is replaced with [`GameSettings::default`] so
+/// the player has a valid file to edit. Out-of-range values are clamped.
+pub fn load_settings(path: &Path) -> GameSettings {
+    if path.exists() {
+        match load_ron_file::<GameSettings>(path) {
+            Ok(settings) => return settings.clamped(),
+            Err(err) => warn!("Resetting settings: {err}"),
+        }
+    }
+
+    let settings = GameSettings::default();
+    if let Err(err) = save_settings(path, &settings) {
+        warn!("Failed to write default settings: {err}");
+    }
+    settings
+}
+
+/// Write `settings` to `path`, creating parent directories as needed.
+///
+/// # Errors
+/// Returns an error if the file cannot be written.
+pub fn save_settings(path: &Path, settings: &GameSettings) -> Result<(), DataLoadError> {
+    let io_error = |source| DataLoadError::io(source, path.display().to_string());
+
+    let content = ron::ser::to_string_pretty(settings, ron::ser::PrettyConfig::default())
+        .map_err(|err| io_error(std::io::Error::other(err)))?;
+
+    if let Some(parent) = path.parent().filter(|p| !p.as_os_str().is_empty()) {
+        fs::create_dir_all(parent).map_err(io_error)?;
+    }
+    fs::write(path, content).map_err(io_error)
+}
+
+/// Plugin that loads [`GameSettings`], applies them, and saves changes.
+pub struct SettingsPlugin;
+
+impl Plugin for SettingsPlugin {
+    fn build(&self, app: &mut App) {
+        app.insert_resource(load_settings(Path::new(SETTINGS_PATH)))
+            .add_systems(Update, (apply_settings_system, settings_write_system));
+    }
+}
+
+/// Push changed settings into the Bevy resources they control.
+pub fn apply_settings_system(
+    settings: Res<GameSettings>,
+    mut ui_scale: ResMut<UiScale>,
+    mut volume: ResMut<GlobalVolume>,
+) {
+    if !settings.is_changed() {
+        return;
+    }
+    ui_scale.0 = settings.font_size_multiplier;
+    // Affects sounds started from now on; effects are short enough.
+    *volume = GlobalVolume::new(Volume::Linear(settings.audio_volume));
+}
+
+/// Write the settings to [`SETTINGS_PATH`] after the player changes them.
+fn settings_write_system(settings: Res<GameSettings>) {
+    // The freshly loaded settings are already on disk.
+    if !settings.is_changed() || settings.is_added() {
+        return;
+    }
+    match save_settings(Path::new(SETTINGS_PATH), &settings) {
+        Ok(()) => info!("Saved settings to {SETTINGS_PATH}"),
+        Err(err) => warn!("Failed to save settings: {err}"),
+    }
+}
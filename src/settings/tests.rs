@@ -0,0 +1,73 @@
+use std::path::PathBuf;
+
+use bevy::prelude::*;
+
+use super::{
+    GameSettings, MAX_FONT_SIZE_MULTIPLIER, apply_settings_system, load_settings, save_settings,
+};
+
+fn temp_path(name: &str) -> PathBuf {
+    std::env::temp_dir()
+        .join(format!("ascenoria_settings_{}", std::process::id()))
+        .join(name)
+}
+
+#[test]
+fn round_trips_through_disk() {
+    let settings = GameSettings {
+        font_size_multiplier: 1.25,
+        audio_volume: 0.5,
+    };
+    let path = temp_path("round_trip.ron");
+
+    save_settings(&path, &settings).expect("save settings");
+
+    assert_eq!(load_settings(&path), settings);
+}
+
+#[test]
+fn missing_file_is_created_with_defaults() {
+    let path = temp_path("missing.ron");
+    let _ = std::fs::remove_file(&path);
+
+    assert_eq!(load_settings(&path), GameSettings::default());
+    assert!(path.exists());
+}
+
+#[test]
+fn corrupt_file_is_replaced_with_defaults() {
+    let path = temp_path("corrupt.ron");
+    std::fs::create_dir_all(path.parent().unwrap()).unwrap();
+    std::fs::write(&path, "(font_size_multiplier: \"big\"").unwrap();
+
+    assert_eq!(load_settings(&path), GameSettings::default());
+    assert_eq!(load_settings(&path), GameSettings::default());
+}
+
+#[test]
+fn out_of_range_values_are_clamped() {
+    let path = temp_path("clamped.ron");
+    std::fs::create_dir_all(path.parent().unwrap()).unwrap();
+    std::fs::write(&path, "(font_size_multiplier: 4.0, audio_volume: -1.0)").unwrap();
+
+    let settings = load_settings(&path);
+
+    assert_eq!(settings.font_size_multiplier, MAX_FONT_SIZE_MULTIPLIER);
+    assert_eq!(settings.audio_volume, 0.0);
+}
+
+#[test]
+fn settings_are_applied_to_ui_scale() {
+    let mut app = App::new();
+    app.init_resource::<UiScale>()
+        .init_resource::<GlobalVolume>()
+        .insert_resource(GameSettings {
+            font_size_multiplier: 1.5,
+            audio_volume: 1.0,
+        })
+        .add_systems(Update, apply_settings_system);
+
+    app.update();
+
+    assert_eq!(app.world().resource::<UiScale>().0, 1.5);
+}
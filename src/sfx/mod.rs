@@ -0,0 +1,140 @@
+//! Sound effect hooks for UI events and game notifications.
+//!
+//! Gameplay code requests a sound by writing a [`PlaySfx`] message; the
+//! [`SfxPlugin`] maps it to an audio file in `assets/audio/` and plays it.
+//!
+//! Every [`Button`] clicks when pressed and ticks when the cursor first
+//! moves onto it, in any screen, without gameplay code asking for it.
+//! Playback volume follows [`GameSettings::audio_volume`].
+//!
+//! Audio files are optional. Any file missing at startup is reported once
+//! with a warning and its sound is skipped, so the game runs without audio
+//! assets.
+//!
+//! [`GameSettings::audio_volume`]: crate::settings::GameSettings::audio_volume
+//!
+//! # Example
+//! ```ignore
+//! fn on_research_done(mut sfx: MessageWriter<PlaySfx>) {
+//!     sfx.write(PlaySfx::TechUnlocked);
+//! }
+//! ```
+
+use std::collections::HashMap;
+use std::path::Path;
+
+use bevy::prelude::*;
+
+#[cfg(test)]
+mod tests;
+
+/// Directory (relative to the asset root) holding sound effects.
+pub const AUDIO_DIR: &str = "audio";
+
+/// Sound effects the game can request.
+#[derive(Message, Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum PlaySfx {
+    /// A UI button was pressed.
+    ButtonClick,
+    /// The cursor moved onto a UI button.
+    ButtonHover,
+    /// Construction of a building finished.
+    BuildingPlaced,
+    /// A technology was researched.
+    TechUnlocked,
+    /// Something needs the player's attention.
+    Notification,
+}
+
+impl PlaySfx {
+    /// Every sound effect, used to preload their files.
+    pub const ALL: [PlaySfx; 5] = [
+        PlaySfx::ButtonClick,
+        PlaySfx::ButtonHover,
+        PlaySfx::BuildingPlaced,
+        PlaySfx::TechUnlocked,
+        PlaySfx::Notification,
+    ];
+
+    /// File name of this sound inside [`AUDIO_DIR`].
+    pub fn file_name(self) -> &'static str {
+        match self {
+            PlaySfx::ButtonClick => "button_click.ogg",
+            PlaySfx::ButtonHover => "button_hover.ogg",
+            PlaySfx::BuildingPlaced => "building_placed.ogg",
+            PlaySfx::TechUnlocked => "tech_unlocked.ogg",
+            PlaySfx::Notification => "notification.ogg",
+        }
+    }
+}
+
+/// Loaded audio handles for every sound effect whose file exists.
+#[derive(Resource, Default)]
+pub struct SfxHandles(HashMap<PlaySfx, Handle<AudioSource>>);
+
+/// Plugin that loads sound effects and plays them on [`PlaySfx`] messages.
+pub struct SfxPlugin;
+
+impl Plugin for SfxPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<SfxHandles>()
+            .add_message::<PlaySfx>()
+            .add_systems(Startup, load_sfx)
+            // Before playback so button sounds start in the same frame.
+            .add_systems(Update, (button_audio_system, sfx_system).chain());
+    }
+}
+
+/// Load every sound effect file that is present on disk.
+fn load_sfx(asset_server: Res<AssetServer>, mut handles: ResMut<SfxHandles>) {
+    for sfx in PlaySfx::ALL {
+        let asset_path = format!("{AUDIO_DIR}/{}", sfx.file_name());
+        if Path::new("assets").join(&asset_path).exists() {
+            handles.0.insert(sfx, asset_server.load(asset_path));
+        } else {
+            warn!("Sound effect assets/{asset_path} not found; {sfx:?} will be silent");
+        }
+    }
+}
+
+/// Play requested sound effects.
+fn sfx_system(
+    mut commands: Commands,
+    mut requests: MessageReader<PlaySfx>,
+    handles: Res<SfxHandles>,
+) {
+    for sfx in requests.read() {
+        if let Some(handle) = handles.0.get(sfx) {
+            commands.spawn((AudioPlayer::new(handle.clone()), PlaybackSettings::DESPAWN));
+        }
+    }
+}
+
+/// Sound for a button whose interaction went from `previous` to `current`.
+///
+/// Hovering plays only when the cursor arrives, not when a click is
+/// released over the button, so holding the cursor still stays quiet.
+pub fn button_sfx(previous: Option<Interaction>, current: Interaction) -> Option<PlaySfx> {
+    match (previous, current) {
+        (_, Interaction::Pressed) => Some(PlaySfx::ButtonClick),
+        (None | Some(Interaction::None), Interaction::Hovered) => Some(PlaySfx::ButtonHover),
+        _ => None,
+    }
+}
+
+/// Request click and hover sounds for every button.
+fn button_audio_system(
+    buttons: Query<(Entity, &Interaction), (Changed<Interaction>, With<Button>)>,
+    mut removed: RemovedComponents<Interaction>,
+    mut previous: Local<HashMap<Entity, Interaction>>,
+    mut sfx: MessageWriter<PlaySfx>,
+) {
+    for entity in removed.read() {
+        previous.remove(&entity);
+    }
+    for (entity, interaction) in &buttons {
+        if let Some(sound) = button_sfx(previous.insert(entity, *interaction), *interaction) {
+            sfx.write(sound);
+        }
+    }
+}
@@ -0,0 +1,39 @@
+use bevy::prelude::Interaction;
+
+use super::{PlaySfx, button_sfx};
+
+#[test]
+fn pressing_a_button_clicks() {
+    assert_eq!(
+        button_sfx(Some(Interaction::Hovered), Interaction::Pressed),
+        Some(PlaySfx::ButtonClick)
+    );
+}
+
+#[test]
+fn hover_plays_once_when_the_cursor_arrives() {
+    assert_eq!(
+        button_sfx(None, Interaction::Hovered),
+        Some(PlaySfx::ButtonHover)
+    );
+    assert_eq!(
+        button_sfx(Some(Interaction::None), Interaction::Hovered),
+        Some(PlaySfx::ButtonHover)
+    );
+    assert_eq!(
+        button_sfx(Some(Interaction::Hovered), Interaction::Hovered),
+        None
+    );
+}
+
+#[test]
+fn releasing_a_click_or_leaving_is_silent() {
+    assert_eq!(
+        button_sfx(Some(Interaction::Pressed), Interaction::Hovered),
+        None
+    );
+    assert_eq!(
+        button_sfx(Some(Interaction::Hovered), Interaction::None),
+        None
+    );
+}
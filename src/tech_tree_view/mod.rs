@@ -0,0 +1,53 @@
+//! Technology tree screen.
+//!
+//! Shows every technology as a node laid out in columns by prerequisite
+//! depth, with connectors drawn from each prerequisite. Selecting a node
+//! shows its description; technologies whose prerequisites are met can be
+//! queued in the [`ResearchQueue`](crate::research::ResearchQueue).
+//!
+//! # Module Structure
+//!
+//! - [`setup`] - Graph layout and UI spawning/cleanup
+//! - [`systems`] - Selection, styling, queueing, and panning
+//! - [`types`] - Components and resources
+//!
+//! # Controls
+//!
+//! - Click a node to select it
+//! - Drag with the left mouse button, scroll, or use the arrow keys to pan
+//! - ESC or "Back" returns to the planet view
+
+mod setup;
+mod systems;
+pub mod types;
+
+use bevy::prelude::*;
+
+use crate::main_menu::GameState;
+use crate::tech_tree_view::types::{TechTreePan, TechTreeSelection};
+
+/// Plugin that manages the tech tree screen.
+///
+/// Systems only run when the game is in `GameState::TechTree`.
+pub struct TechTreePlugin;
+
+impl Plugin for TechTreePlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<TechTreeSelection>()
+            .init_resource::<TechTreePan>()
+            .add_systems(OnEnter(GameState::TechTree), setup::setup_tech_tree)
+            .add_systems(OnExit(GameState::TechTree), setup::cleanup_tech_tree)
+            .add_systems(
+                Update,
+                (
+                    systems::tech_node_click_system,
+                    systems::tech_node_style_system,
+                    systems::tech_details_system,
+                    systems::tech_tree_action_system,
+                    systems::tech_tree_pan_system,
+                    systems::tech_tree_keyboard_system,
+                )
+                    .run_if(in_state(GameState::TechTree)),
+            );
+    }
+}
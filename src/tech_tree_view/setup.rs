@@ -0,0 +1,290 @@
+//! Spawning and despawning of the tech tree screen.
+
+use std::collections::HashMap;
+
+use bevy::prelude::*;
+
+use crate::data_types::{GameData, TechnologyId};
+use crate::research::ResearchGraph;
+use crate::tech_tree_view::types::{
+    TechDetailsText, TechNode, TechTreeAction, TechTreeCanvas, TechTreePan, TechTreeRoot,
+    TechTreeSelection,
+};
+use crate::tooltip::Tooltip;
+
+/// Width of a technology node.
+const NODE_WIDTH: f32 = 180.0;
+/// Height of a technology node.
+const NODE_HEIGHT: f32 = 60.0;
+/// Horizontal space between columns (where edges are routed).
+const COLUMN_GAP: f32 = 80.0;
+/// Vertical space between nodes in the same column.
+const ROW_GAP: f32 = 30.0;
+/// Empty space around the graph.
+const MARGIN: f32 = 40.0;
+/// Thickness of prerequisite edges.
+const EDGE_THICKNESS: f32 = 2.0;
+
+/// Top-left corner of the node in `column`/`row`, in canvas coordinates.
+fn node_position(column: usize, row: usize) -> Vec2 {
+    Vec2::new(
+        MARGIN + column as f32 * (NODE_WIDTH + COLUMN_GAP),
+        MARGIN + row as f32 * (NODE_HEIGHT + ROW_GAP),
+    )
+}
+
+/// Spawn the tech tree screen.
+///
+/// # Layout
+/// ```text
+/// ┌──────────────────────────────────────┬───────────────┐
+/// │  [Tech A] ──┬── [Tech C]              │ Details       │
+/// │  [Tech B] ──┘                         │ [Queue]       │
+/// │        (drag / arrow keys to pan)     │ [Back]        │
+/// └──────────────────────────────────────┴───────────────┘
+/// ```
+///
+/// Columns come from [`ResearchGraph::layers`]; each prerequisite is drawn
+/// as an L-shaped connector made of thin absolutely positioned nodes.
+pub fn setup_tech_tree(
+    mut commands: Commands,
+    game_data: Res<GameData>,
+    mut selection: ResMut<TechTreeSelection>,
+    mut pan: ResMut<TechTreePan>,
+) {
+    selection.0 = None;
+    pan.offset = Vec2::ZERO;
+
+    let graph = ResearchGraph::from_game_data(&game_data);
+    let layers = graph.layers();
+
+    let mut positions = HashMap::new();
+    for (column, layer) in layers.iter().enumerate() {
+        for (row, id) in layer.iter().enumerate() {
+            positions.insert(id.clone(), node_position(column, row));
+        }
+    }
+
+    let rows = layers.iter().map(Vec::len).max().unwrap_or(0);
+    let far_corner = node_position(layers.len(), rows);
+    let canvas_size = Vec2::new(far_corner.x - COLUMN_GAP, far_corner.y - ROW_GAP) + MARGIN;
+
+    commands.spawn((Camera2d::default(), TechTreeRoot));
+
+    commands
+        .spawn((
+            Node {
+                width: Val::Percent(100.0),
+                height: Val::Percent(100.0),
+                flex_direction: FlexDirection::Row,
+                ..default()
+            },
+            BackgroundColor(Color::srgb(0.05, 0.06, 0.1)),
+            TechTreeRoot,
+        ))
+        .with_children(|root| {
+            // Viewport: clips the pannable canvas
+            root.spawn(Node {
+                flex_grow: 1.0,
+                height: Val::Percent(100.0),
+                overflow: Overflow::clip(),
+                ..default()
+            })
+            .with_children(|viewport| {
+                viewport
+                    .spawn((
+                        Node {
+                            position_type: PositionType::Absolute,
+                            left: Val::Px(0.0),
+                            top: Val::Px(0.0),
+                            width: Val::Px(canvas_size.x),
+                            height: Val::Px(canvas_size.y),
+                            ..default()
+                        },
+                        TechTreeCanvas,
+                    ))
+                    .with_children(|canvas| {
+                        // Edges first so nodes are drawn on top of them
+                        for tech in game_data.technologies() {
+                            let id = TechnologyId::from(tech.id.as_str());
+                            let Some(&to) = positions.get(&id) else {
+                                continue;
+                            };
+                            for prereq in graph.prerequisites(&id) {
+                                if let Some(&from) = positions.get(prereq) {
+                                    spawn_edge(canvas, from, to);
+                                }
+                            }
+                        }
+
+                        for tech in game_data.technologies() {
+                            let id = TechnologyId::from(tech.id.as_str());
+                            let Some(&pos) = positions.get(&id) else {
+                                continue;
+                            };
+                            let tooltip =
+                                Tooltip::new(format!("{}\n{}", tech.name_en, tech.description_en))
+                                    .with_stat("Cost", format!("{} RP", tech.science_cost))
+                                    .with_stat("Prerequisites", graph.prerequisites(&id).len());
+                            spawn_node(
+                                canvas,
+                                TechNode(id),
+                                tooltip,
+                                pos,
+                                &tech.name_en,
+                                tech.science_cost,
+                            );
+                        }
+                    });
+            });
+
+            // Details panel
+            root.spawn((
+                Node {
+                    width: Val::Px(320.0),
+                    height: Val::Percent(100.0),
+                    flex_direction: FlexDirection::Column,
+                    padding: UiRect::all(Val::Px(20.0)),
+                    row_gap: Val::Px(16.0),
+                    ..default()
+                },
+                BackgroundColor(Color::BLACK.with_alpha(0.8)),
+            ))
+            .with_children(|panel| {
+                panel.spawn((
+                    Text::new("Research"),
+                    TextFont {
+                        font_size: 28.0,
+                        ..default()
+                    },
+                    TextColor(Color::WHITE),
+                ));
+                panel.spawn((
+                    Text::new("Select a technology."),
+                    TextFont {
+                        font_size: 16.0,
+                        ..default()
+                    },
+                    TextColor(Color::srgb(0.8, 0.8, 0.8)),
+                    TechDetailsText,
+                ));
+                spawn_action_button(panel, TechTreeAction::QueueSelected, "Queue Research");
+                spawn_action_button(panel, TechTreeAction::Back, "Back");
+            });
+        });
+}
+
+/// Despawn every tech tree entity.
+pub fn cleanup_tech_tree(mut commands: Commands, query: Query<Entity, With<TechTreeRoot>>) {
+    for entity in &query {
+        commands.entity(entity).despawn();
+    }
+}
+
+fn spawn_node(
+    canvas: &mut ChildSpawnerCommands,
+    node: TechNode,
+    tooltip: Tooltip,
+    pos: Vec2,
+    name: &str,
+    cost: i32,
+) {
+    canvas
+        .spawn((
+            Button,
+            Node {
+                position_type: PositionType::Absolute,
+                left: Val::Px(pos.x),
+                top: Val::Px(pos.y),
+                width: Val::Px(NODE_WIDTH),
+                height: Val::Px(NODE_HEIGHT),
+                flex_direction: FlexDirection::Column,
+                justify_content: JustifyContent::Center,
+                align_items: AlignItems::Center,
+                border: UiRect::all(Val::Px(2.0)),
+                ..default()
+            },
+            BackgroundColor(Color::srgb(0.2, 0.2, 0.25)),
+            BorderColor::all(Color::srgb(0.5, 0.5, 0.6)),
+            node,
+            tooltip,
+        ))
+        .with_children(|btn| {
+            btn.spawn((
+                Text::new(name),
+                TextFont {
+                    font_size: 16.0,
+                    ..default()
+                },
+                TextColor(Color::WHITE),
+            ));
+            btn.spawn((
+                Text::new(format!("{cost} RP")),
+                TextFont {
+                    font_size: 13.0,
+                    ..default()
+                },
+                TextColor(Color::srgb(0.6, 0.8, 1.0)),
+            ));
+        });
+}
+
+/// Draw an L-shaped connector from the right edge of `from` to the left edge of `to`.
+fn spawn_edge(canvas: &mut ChildSpawnerCommands, from: Vec2, to: Vec2) {
+    let start = Vec2::new(from.x + NODE_WIDTH, from.y + NODE_HEIGHT / 2.0);
+    let end = Vec2::new(to.x, to.y + NODE_HEIGHT / 2.0);
+    let bend_x = end.x - COLUMN_GAP / 2.0;
+
+    spawn_segment(
+        canvas,
+        start.x.min(bend_x),
+        start.y,
+        (bend_x - start.x).abs(),
+        EDGE_THICKNESS,
+    );
+    spawn_segment(
+        canvas,
+        bend_x,
+        start.y.min(end.y),
+        EDGE_THICKNESS,
+        (end.y - start.y).abs() + EDGE_THICKNESS,
+    );
+    spawn_segment(
+        canvas,
+        bend_x.min(end.x),
+        end.y,
+        (end.x - bend_x).abs(),
+        EDGE_THICKNESS,
+    );
+}
+
+fn spawn_segment(canvas: &mut ChildSpawnerCommands, x: f32, y: f32, width: f32, height: f32) {
+    canvas.spawn((
+        Node {
+            position_type: PositionType::Absolute,
+            left: Val::Px(x),
+            top: Val::Px(y),
+            width: Val::Px(width),
+            height: Val::Px(height),
+            ..default()
+        },
+        BackgroundColor(Color::srgb(0.5, 0.5, 0.6)),
+    ));
+}
+
+fn spawn_action_button(panel: &mut ChildSpawnerCommands, action: TechTreeAction, label: &str) {
+    panel
+        .spawn((
+            Button,
+            Node {
+                padding: UiRect::all(Val::Px(10.0)),
+                justify_content: JustifyContent::Center,
+                ..default()
+            },
+            BackgroundColor(Color::srgb(0.3, 0.3, 0.3)),
+            action,
+        ))
+        .with_children(|btn| {
+            btn.spawn((Text::new(label), TextColor(Color::WHITE)));
+        });
+}
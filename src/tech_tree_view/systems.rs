@@ -0,0 +1,233 @@
+//! Interaction, styling, and navigation systems for the tech tree screen.
+
+use bevy::input::mouse::{AccumulatedMouseMotion, AccumulatedMouseScroll, MouseScrollUnit};
+use bevy::prelude::*;
+
+use crate::data_types::{GameData, TechnologyId};
+use crate::keybindings::{KeyBindings, actions};
+use crate::main_menu::GameState;
+use crate::research::ResearchQueue;
+use crate::screen_transition::TransitionToState;
+use crate::tech_tree_view::types::{
+    TechDetailsText, TechNode, TechTreeAction, TechTreeCanvas, TechTreePan, TechTreeSelection,
+};
+
+/// Keyboard pan speed in logical pixels per second.
+const KEY_PAN_SPEED: f32 = 600.0;
+/// Pixels scrolled per mouse wheel line.
+const SCROLL_LINE_PIXELS: f32 = 40.0;
+
+/// Select a technology when its node is clicked.
+pub fn tech_node_click_system(
+    query: Query<(&Interaction, &TechNode), Changed<Interaction>>,
+    mut selection: ResMut<TechTreeSelection>,
+) {
+    for (interaction, node) in &query {
+        if *interaction == Interaction::Pressed {
+            selection.0 = Some(node.0.clone());
+        }
+    }
+}
+
+/// Color nodes by research status, selection, and hover.
+///
+/// - Green: researched
+/// - Blue: being researched or queued
+/// - Grey: available to queue
+/// - Dark: prerequisites missing
+pub fn tech_node_style_system(
+    mut query: Query<(
+        &TechNode,
+        &Interaction,
+        &mut BackgroundColor,
+        &mut BorderColor,
+    )>,
+    selection: Res<TechTreeSelection>,
+    research: Res<ResearchQueue>,
+    game_data: Res<GameData>,
+) {
+    for (node, interaction, mut bg_color, mut border_color) in &mut query {
+        let base = if research.is_researched(&node.0) {
+            Color::srgb(0.15, 0.45, 0.2)
+        } else if research.is_queued(&node.0) {
+            Color::srgb(0.15, 0.3, 0.55)
+        } else if research.prerequisites_met(&node.0, &game_data) {
+            Color::srgb(0.25, 0.25, 0.3)
+        } else {
+            Color::srgb(0.1, 0.1, 0.12)
+        };
+        *bg_color = BackgroundColor(match interaction {
+            Interaction::None => base,
+            _ => base.lighter(0.1),
+        });
+
+        let selected = selection.0.as_ref() == Some(&node.0);
+        *border_color = BorderColor::all(if selected {
+            Color::srgb(1.0, 0.85, 0.3)
+        } else {
+            Color::srgb(0.5, 0.5, 0.6)
+        });
+    }
+}
+
+/// Describe the selected technology in the details panel.
+pub fn tech_details_system(
+    selection: Res<TechTreeSelection>,
+    research: Res<ResearchQueue>,
+    game_data: Res<GameData>,
+    mut query: Query<&mut Text, With<TechDetailsText>>,
+) {
+    if !selection.is_changed() && !research.is_changed() {
+        return;
+    }
+    let Ok(mut text) = query.single_mut() else {
+        return;
+    };
+
+    let Some(tech) = selection.0.as_ref().and_then(|id| {
+        game_data
+            .technologies()
+            .iter()
+            .find(|t| t.id == id.as_str())
+    }) else {
+        text.0 = "Select a technology.".to_string();
+        return;
+    };
+
+    let id = TechnologyId::from(tech.id.as_str());
+    let status = if research.is_researched(&id) {
+        "Researched"
+    } else if research.active.as_ref() == Some(&id) {
+        "Researching"
+    } else if research.is_queued(&id) {
+        "Queued"
+    } else if research.prerequisites_met(&id, &game_data) {
+        "Available"
+    } else {
+        "Locked"
+    };
+
+    let name_of = |id: &str| {
+        game_data
+            .technologies()
+            .iter()
+            .find(|t| t.id == id)
+            .map_or(id.to_string(), |t| t.name_en.clone())
+    };
+    let requires: Vec<String> = game_data
+        .tech_prereqs(&tech.id)
+        .iter()
+        .map(|id| name_of(id))
+        .collect();
+    let unlocks: Vec<&str> = game_data
+        .tech_unlocks(&tech.id)
+        .map(|building| building.name_en.as_str())
+        .collect();
+
+    let list = |items: &[&str]| {
+        if items.is_empty() {
+            "None".to_string()
+        } else {
+            items.join(", ")
+        }
+    };
+    let requires: Vec<&str> = requires.iter().map(String::as_str).collect();
+
+    text.0 = format!(
+        "{}\nCost: {} RP\nStatus: {}\n\n{}\n\nRequires: {}\nUnlocks: {}",
+        tech.name_en,
+        tech.science_cost,
+        status,
+        tech.description_en,
+        list(&requires),
+        list(&unlocks),
+    );
+}
+
+/// Handle the Queue Research and Back buttons.
+pub fn tech_tree_action_system(
+    query: Query<(&Interaction, &TechTreeAction), (Changed<Interaction>, With<Button>)>,
+    selection: Res<TechTreeSelection>,
+    mut research: ResMut<ResearchQueue>,
+    game_data: Res<GameData>,
+    mut transitions: MessageWriter<TransitionToState>,
+) {
+    for (interaction, action) in &query {
+        if *interaction != Interaction::Pressed {
+            continue;
+        }
+        match action {
+            TechTreeAction::QueueSelected => {
+                let Some(id) = selection.0.clone() else {
+                    continue;
+                };
+                match research.queue_tech(id.clone(), &game_data) {
+                    Ok(()) => info!("Queued research: {}", id.as_str()),
+                    Err(err) => info!("Cannot queue research: {err}"),
+                }
+            }
+            TechTreeAction::Back => {
+                transitions.write(TransitionToState(GameState::PlanetView));
+            }
+        }
+    }
+}
+
+/// Pan the canvas with left-mouse drag, the mouse wheel, or the arrow keys.
+pub fn tech_tree_pan_system(
+    keyboard: Res<ButtonInput<KeyCode>>,
+    bindings: Res<KeyBindings>,
+    mouse_buttons: Res<ButtonInput<MouseButton>>,
+    mouse_motion: Res<AccumulatedMouseMotion>,
+    mouse_scroll: Res<AccumulatedMouseScroll>,
+    time: Res<Time>,
+    mut pan: ResMut<TechTreePan>,
+    mut canvas_query: Query<&mut Node, With<TechTreeCanvas>>,
+) {
+    let mut delta = Vec2::ZERO;
+
+    let mut direction = Vec2::ZERO;
+    if bindings.pressed(&keyboard, actions::PAN_LEFT) {
+        direction.x += 1.0;
+    }
+    if bindings.pressed(&keyboard, actions::PAN_RIGHT) {
+        direction.x -= 1.0;
+    }
+    if bindings.pressed(&keyboard, actions::PAN_UP) {
+        direction.y += 1.0;
+    }
+    if bindings.pressed(&keyboard, actions::PAN_DOWN) {
+        direction.y -= 1.0;
+    }
+    delta += direction * KEY_PAN_SPEED * time.delta_secs();
+
+    if mouse_buttons.pressed(MouseButton::Left) {
+        delta += mouse_motion.delta;
+    }
+
+    delta += match mouse_scroll.unit {
+        MouseScrollUnit::Line => mouse_scroll.delta * SCROLL_LINE_PIXELS,
+        MouseScrollUnit::Pixel => mouse_scroll.delta,
+    };
+
+    if delta == Vec2::ZERO {
+        return;
+    }
+    pan.offset += delta;
+
+    for mut node in &mut canvas_query {
+        node.left = Val::Px(pan.offset.x);
+        node.top = Val::Px(pan.offset.y);
+    }
+}
+
+/// Return to the planet view when the Back key (ESC by default) is pressed.
+pub fn tech_tree_keyboard_system(
+    keyboard: Res<ButtonInput<KeyCode>>,
+    bindings: Res<KeyBindings>,
+    mut transitions: MessageWriter<TransitionToState>,
+) {
+    if bindings.just_pressed(&keyboard, actions::BACK) {
+        transitions.write(TransitionToState(GameState::PlanetView));
+    }
+}
@@ -0,0 +1,43 @@
+//! Components and resources for the tech tree screen.
+
+use bevy::prelude::*;
+
+use crate::data_types::TechnologyId;
+
+/// Marker component for all entities belonging to the tech tree screen.
+///
+/// Used for cleanup when leaving the screen.
+#[derive(Component)]
+pub struct TechTreeRoot;
+
+/// The pannable container holding tech nodes and edges.
+#[derive(Component)]
+pub struct TechTreeCanvas;
+
+/// A clickable technology node.
+#[derive(Component, Debug, Clone)]
+pub struct TechNode(pub TechnologyId);
+
+/// Text in the details panel describing the selected technology.
+#[derive(Component)]
+pub struct TechDetailsText;
+
+/// Buttons outside the graph itself.
+#[derive(Component, Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TechTreeAction {
+    /// Queue the selected technology for research.
+    QueueSelected,
+    /// Return to the planet view.
+    Back,
+}
+
+/// Currently selected technology, if any.
+#[derive(Resource, Debug, Default)]
+pub struct TechTreeSelection(pub Option<TechnologyId>);
+
+/// Pan offset of the canvas in logical pixels.
+#[derive(Resource, Debug, Default)]
+pub struct TechTreePan {
+    /// Offset applied to the canvas' `left`/`top`.
+    pub offset: Vec2,
+}
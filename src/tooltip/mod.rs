@@ -0,0 +1,169 @@
+//! Hover tooltips for UI entities.
+//!
+//! Attach a [`Tooltip`] to any entity with an [`Interaction`] (usually a
+//! [`Button`]). After the pointer rests on it for
+//! [`TooltipSettings::tooltip_delay_secs`], a floating panel with the text
+//! and stats appears next to the cursor. The panel is removed as soon as
+//! the pointer leaves the entity or the entity is despawned.
+//!
+//! # Example
+//! ```ignore
+//! commands.spawn((
+//!     Button,
+//!     Tooltip::new("Farm\nGrows food for the colony.").with_stat("Cost", "50"),
+//! ));
+//! ```
+
+use bevy::prelude::*;
+use bevy::window::PrimaryWindow;
+
+/// Offset of the panel from the cursor, in logical pixels.
+const CURSOR_OFFSET: Vec2 = Vec2::new(16.0, 16.0);
+
+/// Tooltip content attached to a hoverable UI entity.
+#[derive(Component, Debug, Clone, Default, PartialEq)]
+pub struct Tooltip {
+    /// Name and description (may span several lines).
+    pub text: String,
+    /// Key stats shown as `label: value` rows below the text.
+    pub stats: Vec<(String, String)>,
+}
+
+impl Tooltip {
+    /// Create a tooltip without stats.
+    pub fn new(text: impl Into<String>) -> Self {
+        Self {
+            text: text.into(),
+            stats: Vec::new(),
+        }
+    }
+
+    /// Append a `label: value` stat row.
+    pub fn with_stat(mut self, label: impl Into<String>, value: impl ToString) -> Self {
+        self.stats.push((label.into(), value.to_string()));
+        self
+    }
+}
+
+/// Tooltip behavior settings.
+#[derive(Resource, Debug, Clone)]
+pub struct TooltipSettings {
+    /// Seconds the pointer must rest on an entity before its tooltip shows.
+    pub tooltip_delay_secs: f32,
+}
+
+impl Default for TooltipSettings {
+    fn default() -> Self {
+        Self {
+            tooltip_delay_secs: 0.5,
+        }
+    }
+}
+
+/// Which entity is hovered and for how long.
+#[derive(Resource, Debug, Default)]
+struct TooltipHover {
+    target: Option<Entity>,
+    hover_timer: f32,
+}
+
+/// Marker for the floating tooltip panel.
+#[derive(Component)]
+struct TooltipPanel;
+
+/// Plugin that shows [`Tooltip`]s on hover.
+pub struct TooltipPlugin;
+
+impl Plugin for TooltipPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<TooltipSettings>()
+            .init_resource::<TooltipHover>()
+            .add_systems(Update, tooltip_system);
+    }
+}
+
+/// Track the hovered entity, then spawn, move, or despawn the panel.
+fn tooltip_system(
+    mut commands: Commands,
+    time: Res<Time>,
+    settings: Res<TooltipSettings>,
+    mut hover: ResMut<TooltipHover>,
+    hoverables: Query<(Entity, &Interaction, &Tooltip)>,
+    mut panels: Query<(Entity, &mut Node), With<TooltipPanel>>,
+    windows: Query<&Window, With<PrimaryWindow>>,
+) {
+    let hovered = hoverables
+        .iter()
+        .find(|(_, interaction, _)| **interaction == Interaction::Hovered)
+        .map(|(entity, _, tooltip)| (entity, tooltip));
+
+    if hovered.map(|(entity, _)| entity) != hover.target {
+        for (panel, _) in &panels {
+            commands.entity(panel).despawn();
+        }
+        hover.target = hovered.map(|(entity, _)| entity);
+        hover.hover_timer = 0.0;
+        return;
+    }
+
+    let Some((_, tooltip)) = hovered else {
+        return;
+    };
+    let Some(cursor) = windows.single().ok().and_then(Window::cursor_position) else {
+        return;
+    };
+    let position = cursor + CURSOR_OFFSET;
+
+    if let Some((_, mut node)) = panels.iter_mut().next() {
+        node.left = Val::Px(position.x);
+        node.top = Val::Px(position.y);
+        return;
+    }
+
+    hover.hover_timer += time.delta_secs();
+    if hover.hover_timer >= settings.tooltip_delay_secs {
+        spawn_tooltip_panel(&mut commands, tooltip, position);
+    }
+}
+
+fn spawn_tooltip_panel(commands: &mut Commands, tooltip: &Tooltip, position: Vec2) {
+    commands
+        .spawn((
+            Node {
+                position_type: PositionType::Absolute,
+                left: Val::Px(position.x),
+                top: Val::Px(position.y),
+                max_width: Val::Px(320.0),
+                flex_direction: FlexDirection::Column,
+                padding: UiRect::all(Val::Px(8.0)),
+                row_gap: Val::Px(4.0),
+                border: UiRect::all(Val::Px(1.0)),
+                ..default()
+            },
+            BackgroundColor(Color::srgb(0.08, 0.08, 0.12).with_alpha(0.95)),
+            BorderColor::all(Color::srgb(0.5, 0.5, 0.7)),
+            GlobalZIndex(200),
+            Pickable::IGNORE,
+            TooltipPanel,
+        ))
+        .with_children(|panel| {
+            panel.spawn((
+                Text::new(tooltip.text.clone()),
+                TextFont {
+                    font_size: 14.0,
+                    ..default()
+                },
+                TextColor(Color::WHITE),
+            ));
+            for (label, value) in &tooltip.stats {
+                panel.spawn((
+                    Text::new(format!("{label}: {value}")),
+                    TextFont {
+                        font_size: 13.0,
+                        ..default()
+                    },
+                    TextColor(Color::srgb(0.6, 0.8, 1.0)),
+                ));
+            }
+        });
+}
@@ -0,0 +1,212 @@
+//! Step-by-step tutorial shown over the game screens.
+//!
+//! Steps are loaded from [`TUTORIAL_DATA_PATH`]. Each step belongs to one
+//! [`GameState`] and is only shown while that screen is active, so the
+//! tutorial waits for the player to navigate there; reaching a screen that
+//! a later step is for skips ahead to it. The screen is dimmed
+//! except for the step's highlight rectangle, and a panel shows the step
+//! text with "Next" and "Skip Tutorial" buttons (Space also advances).
+//!
+//! The tutorial starts automatically on the first run, detected by the
+//! absence of [`TUTORIAL_PROGRESS_PATH`]. That file is written once the
+//! tutorial is finished or skipped.
+
+mod overlay;
+
+use std::fs;
+use std::path::Path;
+
+use bevy::prelude::*;
+use serde::{Deserialize, Serialize};
+
+use crate::data_types::{DataLoadError, load_ron_file};
+use crate::main_menu::GameState;
+
+use self::overlay::{
+    tutorial_button_system, tutorial_follow_state_system, tutorial_keyboard_system,
+    update_tutorial_overlay,
+};
+
+#[cfg(test)]
+mod tests;
+
+/// Location of the tutorial steps.
+pub const TUTORIAL_DATA_PATH: &str = "assets/data/tutorial.ron";
+
+/// File recording that the player has finished or skipped the tutorial.
+pub const TUTORIAL_PROGRESS_PATH: &str = "settings/tutorial.ron";
+
+/// Area of the screen left undimmed, in percent of the window size.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Deserialize)]
+pub struct HighlightRect {
+    /// Distance from the left edge.
+    pub left: f32,
+    /// Distance from the top edge.
+    pub top: f32,
+    /// Width of the highlighted area.
+    pub width: f32,
+    /// Height of the highlighted area.
+    pub height: f32,
+}
+
+/// A single tutorial step.
+///
+/// # RON Example
+/// ```ron
+/// (
+///     target_state: PlanetView,
+///     highlight: (left: 0.0, top: 0.0, width: 100.0, height: 8.0),
+///     text_en: "The top bar shows your colony's stockpiles.",
+/// )
+/// ```
+#[derive(Debug, Clone, PartialEq, Deserialize)]
+pub struct TutorialStep {
+    /// Screen the step is shown on.
+    pub target_state: GameState,
+    /// Part of the screen the step points at.
+    pub highlight: HighlightRect,
+    /// English step text.
+    pub text_en: String,
+}
+
+/// Contents of `tutorial.ron`.
+#[derive(Debug, Deserialize)]
+struct TutorialData {
+    step: Vec<TutorialStep>,
+}
+
+/// Progress marker saved to [`TUTORIAL_PROGRESS_PATH`].
+#[derive(Debug, Serialize, Deserialize)]
+struct TutorialProgress {
+    completed: bool,
+}
+
+/// Tutorial steps and how far the player has got.
+#[derive(Resource, Debug, Clone, Default)]
+pub struct TutorialState {
+    /// Whether the tutorial is running.
+    pub active: bool,
+    /// Index of the current step in `steps`.
+    pub step: usize,
+    /// All tutorial steps, in order.
+    pub steps: Vec<TutorialStep>,
+}
+
+impl TutorialState {
+    /// Create a tutorial that starts at the first step when `active`.
+    pub fn new(steps: Vec<TutorialStep>, active: bool) -> Self {
+        Self {
+            active: active && !steps.is_empty(),
+            step: 0,
+            steps,
+        }
+    }
+
+    /// Step to show, if the tutorial is running.
+    pub fn current_step(&self) -> Option<&TutorialStep> {
+        self.steps.get(self.step).filter(|_| self.active)
+    }
+
+    /// Move to the next step.
+    ///
+    /// Returns `true` when this finished the tutorial.
+    pub fn advance(&mut self) -> bool {
+        if !self.active {
+            return false;
+        }
+        self.step += 1;
+        if self.step >= self.steps.len() {
+            self.active = false;
+            return true;
+        }
+        false
+    }
+
+    /// Jump ahead to the next step shown on `state` when the player has
+    /// moved on from the current step's screen (e.g. pressed New Game).
+    ///
+    /// Earlier steps are never revisited.
+    pub fn follow_state(&mut self, state: GameState) {
+        let Some(current) = self.current_step() else {
+            return;
+        };
+        if current.target_state == state {
+            return;
+        }
+        if let Some(offset) = self.steps[self.step..]
+            .iter()
+            .position(|step| step.target_state == state)
+        {
+            self.step += offset;
+        }
+    }
+
+    /// Stop the tutorial.
+    pub fn skip(&mut self) {
+        self.active = false;
+    }
+}
+
+/// Load the tutorial steps from a RON file.
+///
+/// # Errors
+/// Returns an error if the file cannot be read or parsed.
+pub fn load_tutorial_steps(path: &Path) -> Result<Vec<TutorialStep>, DataLoadError> {
+    let data: TutorialData = load_ron_file(path)?;
+    Ok(data.step)
+}
+
+/// Whether the tutorial has never been finished or skipped.
+pub fn is_first_run(progress_path: &Path) -> bool {
+    !progress_path.exists()
+}
+
+/// Record that the tutorial is done so it does not start again.
+///
+/// Write errors are logged; the tutorial simply shows again next launch.
+pub fn mark_tutorial_complete(progress_path: &Path) {
+    let content = match ron::ser::to_string_pretty(
+        &TutorialProgress { completed: true },
+        ron::ser::PrettyConfig::default(),
+    ) {
+        Ok(content) => content,
+        Err(err) => {
+            warn!("Failed to serialize tutorial progress: {err}");
+            return;
+        }
+    };
+
+    let parent = progress_path.parent().filter(|p| !p.as_os_str().is_empty());
+    if let Err(err) = parent.map_or(Ok(()), fs::create_dir_all) {
+        warn!("Failed to create settings directory: {err}");
+        return;
+    }
+    if let Err(err) = fs::write(progress_path, content) {
+        warn!("Failed to write {}: {err}", progress_path.display());
+    }
+}
+
+/// Plugin that loads the tutorial and shows its overlay.
+pub struct TutorialPlugin;
+
+impl Plugin for TutorialPlugin {
+    fn build(&self, app: &mut App) {
+        let steps = load_tutorial_steps(Path::new(TUTORIAL_DATA_PATH)).unwrap_or_else(|err| {
+            warn!("Tutorial disabled: {err}");
+            Vec::new()
+        });
+        let first_run = is_first_run(Path::new(TUTORIAL_PROGRESS_PATH));
+
+        app.insert_resource(TutorialState::new(steps, first_run))
+            .add_systems(
+                Update,
+                (
+                    tutorial_follow_state_system,
+                    tutorial_button_system,
+                    tutorial_keyboard_system,
+                    update_tutorial_overlay,
+                )
+                    .chain(),
+            );
+    }
+}
@@ -0,0 +1,234 @@
+//! Dimmed overlay, highlight cutout, and step panel for the tutorial.
+
+use std::path::Path;
+
+use bevy::{ecs::hierarchy::ChildSpawnerCommands, prelude::*};
+
+use crate::keybindings::{KeyBindings, actions};
+use crate::main_menu::GameState;
+
+use super::{HighlightRect, TUTORIAL_PROGRESS_PATH, TutorialState, mark_tutorial_complete};
+
+/// Color of the dimmed screen area outside the highlight.
+const DIM_COLOR: Color = Color::srgba(0.0, 0.0, 0.0, 0.6);
+/// Border drawn around the highlighted area.
+const HIGHLIGHT_BORDER: Color = Color::srgb(1.0, 0.85, 0.3);
+
+/// Marker component for the tutorial overlay root entity.
+#[derive(Component)]
+pub struct TutorialOverlayRoot;
+
+/// Action performed by a tutorial panel button.
+#[derive(Component, Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TutorialButton {
+    /// Go to the next step.
+    Next,
+    /// End the tutorial.
+    Skip,
+}
+
+/// Show the current step over the matching screen, or remove the overlay
+/// when the tutorial is inactive or waiting for another screen.
+pub fn update_tutorial_overlay(
+    mut commands: Commands,
+    tutorial: Res<TutorialState>,
+    game_state: Res<State<GameState>>,
+    overlay_query: Query<Entity, With<TutorialOverlayRoot>>,
+) {
+    if !tutorial.is_changed() && !game_state.is_changed() {
+        return;
+    }
+
+    for entity in &overlay_query {
+        commands.entity(entity).despawn();
+    }
+
+    let Some(step) = tutorial
+        .current_step()
+        .filter(|step| step.target_state == *game_state.get())
+    else {
+        return;
+    };
+
+    let is_last = tutorial.step + 1 == tutorial.steps.len();
+    let rect = step.highlight;
+
+    commands
+        .spawn((
+            Node {
+                width: Val::Percent(100.0),
+                height: Val::Percent(100.0),
+                position_type: PositionType::Absolute,
+                ..default()
+            },
+            GlobalZIndex(150),
+            TutorialOverlayRoot,
+        ))
+        .with_children(|overlay| {
+            // Four dimmed bands around the highlight leave it uncovered.
+            for (left, top, width, height) in dim_bands(rect) {
+                overlay.spawn((
+                    Node {
+                        position_type: PositionType::Absolute,
+                        left: Val::Percent(left),
+                        top: Val::Percent(top),
+                        width: Val::Percent(width),
+                        height: Val::Percent(height),
+                        ..default()
+                    },
+                    BackgroundColor(DIM_COLOR),
+                ));
+            }
+
+            overlay.spawn((
+                Node {
+                    position_type: PositionType::Absolute,
+                    left: Val::Percent(rect.left),
+                    top: Val::Percent(rect.top),
+                    width: Val::Percent(rect.width),
+                    height: Val::Percent(rect.height),
+                    border: UiRect::all(Val::Px(2.0)),
+                    ..default()
+                },
+                BorderColor::all(HIGHLIGHT_BORDER),
+            ));
+
+            // Put the panel in whichever half the highlight leaves free.
+            let panel_top = if rect.top + rect.height / 2.0 > 50.0 {
+                Val::Percent(15.0)
+            } else {
+                Val::Percent(60.0)
+            };
+
+            overlay
+                .spawn((
+                    Node {
+                        position_type: PositionType::Absolute,
+                        left: Val::Percent(30.0),
+                        top: panel_top,
+                        width: Val::Percent(40.0),
+                        flex_direction: FlexDirection::Column,
+                        padding: UiRect::all(Val::Px(16.0)),
+                        border: UiRect::all(Val::Px(2.0)),
+                        row_gap: Val::Px(12.0),
+                        ..default()
+                    },
+                    BackgroundColor(Color::srgba(0.08, 0.1, 0.18, 0.95)),
+                    BorderColor::all(HIGHLIGHT_BORDER),
+                ))
+                .with_children(|panel| {
+                    panel.spawn((
+                        Text::new(format!(
+                            "Tutorial {}/{}",
+                            tutorial.step + 1,
+                            tutorial.steps.len()
+                        )),
+                        TextFont {
+                            font_size: 14.0,
+                            ..default()
+                        },
+                        TextColor(HIGHLIGHT_BORDER),
+                    ));
+                    panel.spawn((
+                        Text::new(step.text_en.clone()),
+                        TextFont {
+                            font_size: 18.0,
+                            ..default()
+                        },
+                        TextColor(Color::WHITE),
+                    ));
+
+                    panel
+                        .spawn(Node {
+                            justify_content: JustifyContent::SpaceBetween,
+                            ..default()
+                        })
+                        .with_children(|row| {
+                            spawn_button(row, "Skip Tutorial", TutorialButton::Skip);
+                            spawn_button(
+                                row,
+                                if is_last { "Finish" } else { "Next" },
+                                TutorialButton::Next,
+                            );
+                        });
+                });
+        });
+}
+
+/// Rectangles (left, top, width, height) covering everything but `rect`.
+fn dim_bands(rect: HighlightRect) -> [(f32, f32, f32, f32); 4] {
+    let bottom = rect.top + rect.height;
+    let right = rect.left + rect.width;
+    [
+        (0.0, 0.0, 100.0, rect.top),
+        (0.0, bottom, 100.0, (100.0 - bottom).max(0.0)),
+        (0.0, rect.top, rect.left, rect.height),
+        (right, rect.top, (100.0 - right).max(0.0), rect.height),
+    ]
+}
+
+fn spawn_button(parent: &mut ChildSpawnerCommands, label: &str, action: TutorialButton) {
+    parent
+        .spawn((
+            Button,
+            Node {
+                padding: UiRect::axes(Val::Px(14.0), Val::Px(6.0)),
+                ..default()
+            },
+            BackgroundColor(Color::srgb(0.2, 0.2, 0.3)),
+            action,
+        ))
+        .with_children(|btn| {
+            btn.spawn((Text::new(label), TextColor(Color::WHITE)));
+        });
+}
+
+/// Handle the "Next" and "Skip Tutorial" buttons.
+pub fn tutorial_button_system(
+    interaction_query: Query<(&Interaction, &TutorialButton), Changed<Interaction>>,
+    mut tutorial: ResMut<TutorialState>,
+) {
+    for (interaction, button) in &interaction_query {
+        if *interaction != Interaction::Pressed {
+            continue;
+        }
+        match button {
+            TutorialButton::Next => advance(&mut tutorial),
+            TutorialButton::Skip => {
+                tutorial.skip();
+                mark_tutorial_complete(Path::new(TUTORIAL_PROGRESS_PATH));
+            }
+        }
+    }
+}
+
+/// Skip ahead when the player switches to a screen a later step is for.
+pub fn tutorial_follow_state_system(
+    game_state: Res<State<GameState>>,
+    mut tutorial: ResMut<TutorialState>,
+) {
+    if game_state.is_changed() {
+        tutorial.follow_state(*game_state.get());
+    }
+}
+
+/// Advance the tutorial with its key (Space by default) while a step is shown.
+pub fn tutorial_keyboard_system(
+    keyboard: Res<ButtonInput<KeyCode>>,
+    bindings: Res<KeyBindings>,
+    game_state: Res<State<GameState>>,
+    mut tutorial: ResMut<TutorialState>,
+) {
+    let showing = tutorial
+        .current_step()
+        .is_some_and(|step| step.target_state == *game_state.get());
+    if showing && bindings.just_pressed(&keyboard, actions::TUTORIAL_NEXT) {
+        advance(&mut tutorial);
+    }
+}
+
+fn advance(tutorial: &mut TutorialState) {
+    if tutorial.advance() {
+        mark_tutorial_complete(Path::new(TUTORIAL_PROGRESS_PATH));
+    }
+}
@@ -0,0 +1,92 @@
+use std::path::{Path, PathBuf};
+
+use super::{
+    HighlightRect, TUTORIAL_DATA_PATH, TutorialState, TutorialStep, is_first_run,
+    load_tutorial_steps, mark_tutorial_complete,
+};
+use crate::main_menu::GameState;
+
+fn step(target_state: GameState) -> TutorialStep {
+    TutorialStep {
+        target_state,
+        highlight: HighlightRect::default(),
+        text_en: "Step".to_string(),
+    }
+}
+
+fn temp_progress_path(name: &str) -> PathBuf {
+    std::env::temp_dir()
+        .join(format!("ascenoria_tutorial_{}", std::process::id()))
+        .join(name)
+}
+
+#[test]
+fn loads_bundled_tutorial() {
+    let steps = load_tutorial_steps(Path::new(TUTORIAL_DATA_PATH)).expect("tutorial loads");
+
+    assert!(!steps.is_empty());
+    assert_eq!(steps[0].target_state, GameState::MainMenu);
+    assert!(steps.iter().all(|step| !step.text_en.trim().is_empty()));
+}
+
+#[test]
+fn advancing_past_the_last_step_finishes() {
+    let mut tutorial = TutorialState::new(
+        vec![step(GameState::MainMenu), step(GameState::PlanetView)],
+        true,
+    );
+
+    assert_eq!(tutorial.current_step(), Some(&step(GameState::MainMenu)));
+    assert!(!tutorial.advance());
+    assert_eq!(tutorial.current_step(), Some(&step(GameState::PlanetView)));
+    assert!(tutorial.advance());
+    assert!(!tutorial.active);
+    assert_eq!(tutorial.current_step(), None);
+}
+
+#[test]
+fn switching_screens_jumps_to_the_next_matching_step() {
+    let mut tutorial = TutorialState::new(
+        vec![
+            step(GameState::MainMenu),
+            step(GameState::PlanetView),
+            step(GameState::TechTree),
+            step(GameState::MainMenu),
+        ],
+        true,
+    );
+
+    tutorial.follow_state(GameState::TechTree);
+    assert_eq!(tutorial.step, 2);
+
+    // Going back never rewinds, and the current screen keeps its step.
+    tutorial.follow_state(GameState::PlanetView);
+    assert_eq!(tutorial.step, 2);
+    tutorial.follow_state(GameState::MainMenu);
+    assert_eq!(tutorial.step, 3);
+}
+
+#[test]
+fn skipping_hides_the_tutorial() {
+    let mut tutorial = TutorialState::new(vec![step(GameState::MainMenu)], true);
+    tutorial.skip();
+
+    assert_eq!(tutorial.current_step(), None);
+    assert!(!tutorial.advance());
+}
+
+#[test]
+fn tutorial_without_steps_never_starts() {
+    assert!(!TutorialState::new(Vec::new(), true).active);
+}
+
+#[test]
+fn completing_the_tutorial_ends_first_run() {
+    let path = temp_progress_path("tutorial.ron");
+    let _ = std::fs::remove_file(&path);
+    assert!(is_first_run(&path));
+
+    mark_tutorial_complete(&path);
+
+    assert!(!is_first_run(&path));
+}
@@ -0,0 +1,147 @@
+//! UI sizes that scale with the window resolution.
+//!
+//! [`LayoutConfig`] is recomputed from the window size whenever the window
+//! is resized. UI entities opt in with a marker component and are resized
+//! by [`apply_layout_config`]:
+//!
+//! - [`LayoutPanel`] - side panel width
+//! - [`LayoutButton`] - minimum button height
+//! - [`LayoutText`] - HUD font size
+//!
+//! Sizes are tuned for the minimum supported resolution of
+//! [`MIN_WINDOW_WIDTH`]×[`MIN_WINDOW_HEIGHT`] and grow proportionally above
+//! it, so a 4K window gets panels three times as wide.
+
+use bevy::prelude::*;
+use bevy::ui::UiSystems;
+use bevy::window::{PrimaryWindow, WindowResized};
+
+#[cfg(test)]
+mod tests;
+
+/// Smallest supported window width in logical pixels.
+pub const MIN_WINDOW_WIDTH: f32 = 1280.0;
+
+/// Smallest supported window height in logical pixels.
+pub const MIN_WINDOW_HEIGHT: f32 = 720.0;
+
+/// Buttons are never shorter than this, so they stay easy to hit.
+pub const MIN_BUTTON_HEIGHT: f32 = 44.0;
+
+/// Side panel width at the minimum resolution.
+const BASE_PANEL_WIDTH: f32 = 220.0;
+/// HUD font size at the minimum resolution.
+const BASE_FONT_SIZE: f32 = 20.0;
+/// Button height at the minimum resolution.
+const BASE_BUTTON_HEIGHT: f32 = 44.0;
+
+/// UI sizes for the current window resolution.
+#[derive(Resource, Debug, Clone, Copy, PartialEq)]
+pub struct LayoutConfig {
+    /// Width of the planet view side panels.
+    pub panel_width_px: f32,
+    /// Font size of HUD text.
+    pub font_size_base: f32,
+    /// Minimum height of buttons.
+    pub button_min_height: f32,
+}
+
+impl Default for LayoutConfig {
+    fn default() -> Self {
+        Self::for_window(MIN_WINDOW_WIDTH, MIN_WINDOW_HEIGHT)
+    }
+}
+
+impl LayoutConfig {
+    /// Sizes for a window of `width`×`height` logical pixels.
+    ///
+    /// Windows smaller than the minimum resolution get the minimum sizes.
+    pub fn for_window(width: f32, height: f32) -> Self {
+        let scale = (width / MIN_WINDOW_WIDTH)
+            .min(height / MIN_WINDOW_HEIGHT)
+            .max(1.0);
+        Self {
+            panel_width_px: BASE_PANEL_WIDTH * scale,
+            font_size_base: BASE_FONT_SIZE * scale,
+            button_min_height: BASE_BUTTON_HEIGHT * scale,
+        }
+    }
+
+    /// Height to give buttons, never below [`MIN_BUTTON_HEIGHT`].
+    pub fn button_height(&self) -> f32 {
+        self.button_min_height.max(MIN_BUTTON_HEIGHT)
+    }
+}
+
+/// Side panel whose width follows [`LayoutConfig::panel_width_px`].
+#[derive(Component)]
+pub struct LayoutPanel;
+
+/// Button whose minimum height follows [`LayoutConfig::button_height`].
+#[derive(Component)]
+pub struct LayoutButton;
+
+/// Text whose size follows [`LayoutConfig::font_size_base`].
+#[derive(Component)]
+pub struct LayoutText;
+
+/// Plugin that keeps [`LayoutConfig`] in sync with the window size.
+pub struct LayoutPlugin;
+
+impl Plugin for LayoutPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<LayoutConfig>()
+            .add_systems(Startup, init_layout_config)
+            .add_systems(Update, update_layout_config)
+            // After the screens spawn their UI, before Bevy lays it out.
+            .add_systems(PostUpdate, apply_layout_config.before(UiSystems::Layout));
+    }
+}
+
+/// Size the UI for the primary window as it opens.
+fn init_layout_config(
+    windows: Query<&Window, With<PrimaryWindow>>,
+    mut layout: ResMut<LayoutConfig>,
+) {
+    if let Ok(window) = windows.single() {
+        *layout = LayoutConfig::for_window(window.width(), window.height());
+    }
+}
+
+/// Recompute [`LayoutConfig`] when the window is resized.
+pub fn update_layout_config(
+    mut resize_events: MessageReader<WindowResized>,
+    mut layout: ResMut<LayoutConfig>,
+) {
+    let Some(event) = resize_events.read().last() else {
+        return;
+    };
+    let resized = LayoutConfig::for_window(event.width, event.height);
+    // Only a real change should trigger a pass over the UI.
+    layout.set_if_neq(resized);
+}
+
+/// Resize marked UI entities when they spawn or the layout changes.
+pub fn apply_layout_config(
+    layout: Res<LayoutConfig>,
+    mut panels: Query<(Ref<LayoutPanel>, &mut Node), Without<LayoutButton>>,
+    mut buttons: Query<(Ref<LayoutButton>, &mut Node), Without<LayoutPanel>>,
+    mut texts: Query<(Ref<LayoutText>, &mut TextFont)>,
+) {
+    let all = layout.is_changed();
+    for (marker, mut node) in &mut panels {
+        if all || marker.is_added() {
+            node.width = Val::Px(layout.panel_width_px);
+        }
+    }
+    for (marker, mut node) in &mut buttons {
+        if all || marker.is_added() {
+            node.min_height = Val::Px(layout.button_height());
+        }
+    }
+    for (marker, mut font) in &mut texts {
+        if all || marker.is_added() {
+            font.font_size = layout.font_size_base;
+        }
+    }
+}
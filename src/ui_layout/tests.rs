@@ -0,0 +1,89 @@
+use bevy::prelude::*;
+use bevy::window::WindowResized;
+
+use super::{
+    LayoutButton, LayoutConfig, LayoutPanel, MIN_BUTTON_HEIGHT, apply_layout_config,
+    update_layout_config,
+};
+
+fn layout_app() -> App {
+    let mut app = App::new();
+    app.add_message::<WindowResized>()
+        .init_resource::<LayoutConfig>()
+        .add_systems(Update, (update_layout_config, apply_layout_config).chain());
+    app
+}
+
+fn resize(app: &mut App, width: f32, height: f32) {
+    app.world_mut().write_message(WindowResized {
+        window: Entity::PLACEHOLDER,
+        width,
+        height,
+    });
+    app.update();
+}
+
+#[test]
+fn minimum_resolution_uses_base_sizes() {
+    let layout = LayoutConfig::for_window(1280.0, 720.0);
+
+    assert_eq!(layout, LayoutConfig::default());
+    assert_eq!(layout.panel_width_px, 220.0);
+    assert!(layout.button_height() >= MIN_BUTTON_HEIGHT);
+}
+
+#[test]
+fn sizes_scale_with_the_smaller_axis() {
+    let base = LayoutConfig::default();
+
+    let uhd = LayoutConfig::for_window(3840.0, 2160.0);
+    assert_eq!(uhd.panel_width_px, base.panel_width_px * 3.0);
+    assert_eq!(uhd.font_size_base, base.font_size_base * 3.0);
+
+    // An ultrawide window is limited by its height.
+    let ultrawide = LayoutConfig::for_window(3440.0, 1440.0);
+    assert_eq!(ultrawide.panel_width_px, base.panel_width_px * 2.0);
+}
+
+#[test]
+fn small_windows_keep_the_minimum_sizes() {
+    let layout = LayoutConfig::for_window(800.0, 600.0);
+
+    assert_eq!(layout, LayoutConfig::default());
+    assert_eq!(layout.button_height(), MIN_BUTTON_HEIGHT);
+}
+
+#[test]
+fn resizing_the_window_updates_the_layout() {
+    let mut app = layout_app();
+
+    resize(&mut app, 2560.0, 1440.0);
+
+    assert_eq!(
+        *app.world().resource::<LayoutConfig>(),
+        LayoutConfig::for_window(2560.0, 1440.0)
+    );
+}
+
+#[test]
+fn marked_nodes_follow_the_layout() {
+    let mut app = layout_app();
+    let panel = app.world_mut().spawn((Node::default(), LayoutPanel)).id();
+    let button = app.world_mut().spawn((Node::default(), LayoutButton)).id();
+    app.update();
+    assert_eq!(
+        app.world().get::<Node>(panel).unwrap().width,
+        Val::Px(220.0)
+    );
+
+    resize(&mut app, 3840.0, 2160.0);
+
+    assert_eq!(
+        app.world().get::<Node>(panel).unwrap().width,
+        Val::Px(660.0)
+    );
+    assert_eq!(
+        app.world().get::<Node>(button).unwrap().min_height,
+        Val::Px(132.0)
+    );
+}
@@ -0,0 +1,493 @@
+//! Victory and defeat condition checking.
+//!
+//! After every turn the [`VictoryPlugin`] evaluates each condition in
+//! `victory_conditions.ron`, dispatching on its [`VictoryType`]. Progress
+//! toward every condition is kept in [`VictoryTracker`] for the UI; once a
+//! condition reaches 100% its id is stored in `PlanetViewState::victory`,
+//! which shows the victory overlay. AI species are scored the same way so
+//! the planet view can show a rival pulling ahead.
+//!
+//! The alliance condition is shared: a species and its allies count the
+//! buildings on all of their planets together (see [`alliance_share`]).
+//! The economic condition needs [`prosperity`] to stay above a threshold
+//! for several turns; the streak is kept in
+//! `PlanetViewState::prosperity_streak` and advanced once per turn by
+//! [`update_prosperity_streak`].
+//!
+//! # Defeat
+//!
+//! A game with a turn limit (the scenario's `max_turns`, or
+//! [`VictoryRules::max_turns`]) is lost once the turn counter passes the
+//! limit without a victory: a [`DefeatEvent`] is sent and its reason stored
+//! in `PlanetViewState::defeat`, which shows the defeat overlay. A warning
+//! notification arrives [`TURN_LIMIT_WARNING_TURNS`] turns before the end.
+//!
+//! The [`VictoryRules`] are loaded from [`VICTORY_RULES_PATH`] at startup.
+//!
+//! # RON Example
+//! ```ron
+//! (
+//!     alliance_victory_threshold: 0.75,
+//!     prosperity_victory_threshold: 1000,
+//!     prosperity_streak_turns: 3,
+//!     max_turns: Some(200),
+//! )
+//! ```
+
+use std::collections::{BTreeMap, HashMap};
+use std::path::Path;
+
+use bevy::prelude::*;
+use serde::{Deserialize, Serialize};
+
+use crate::ai::AiState;
+use crate::data_types::{
+    GameData, GameRegistry, Scenario, SpeciesId, TechnologyId, VictoryCondition,
+    VictoryConditionId, VictoryType, load_ron_file,
+};
+use crate::diplomacy::{DiplomacyState, DiplomaticStance, player_species_id};
+use crate::main_menu::GameState;
+use crate::planet_data::TileColor;
+use crate::planet_view::DEFAULT_SCENARIO_ID;
+use crate::planet_view::types::{PlanetViewState, TurnEnded};
+use crate::planet_view::ui::notification::{Notification, NotificationKind, NotificationQueue};
+use crate::planet_view::ui::notification_log::NotificationLog;
+use crate::research::ResearchQueue;
+
+#[cfg(test)]
+mod tests;
+
+/// Turns remaining when the turn limit warning is shown.
+pub const TURN_LIMIT_WARNING_TURNS: u32 = 10;
+
+/// Where the [`VictoryRules`] of the shipped game are stored.
+pub const VICTORY_RULES_PATH: &str = "assets/data/victory_rules.ron";
+
+/// Tunable thresholds for victory conditions.
+///
+/// Missing fields take their [`Default`] values.
+#[derive(Resource, Debug, Clone, PartialEq, Deserialize)]
+#[serde(default)]
+pub struct VictoryRules {
+    /// Share of the white tiles on every planet that a species and its
+    /// allies must cover together to win an alliance victory.
+    pub alliance_victory_threshold: f32,
+    /// Prosperity a colony must reach for a turn to count toward an
+    /// economic victory.
+    pub prosperity_victory_threshold: u32,
+    /// Consecutive turns at or above the prosperity threshold needed for an
+    /// economic victory.
+    pub prosperity_streak_turns: u32,
+    /// Last turn that can be played, unless the scenario sets its own
+    /// limit; `None` for no limit.
+    pub max_turns: Option<u32>,
+}
+
+impl Default for VictoryRules {
+    fn default() -> Self {
+        Self {
+            alliance_victory_threshold: 0.75,
+            prosperity_victory_threshold: 1000,
+            prosperity_streak_turns: 3,
+            max_turns: None,
+        }
+    }
+}
+
+impl VictoryRules {
+    /// Load the rules at `path`, falling back to [`VictoryRules::default`]
+    /// if the file is missing or broken.
+    pub fn load(path: &Path) -> Self {
+        load_ron_file(path).unwrap_or_else(|err| {
+            warn!("Using default victory rules: {err}");
+            Self::default()
+        })
+    }
+
+    /// Turn limit of a game of `scenario`, which overrides
+    /// [`VictoryRules::max_turns`] when it sets one.
+    pub fn turn_limit(&self, scenario: Option<&Scenario>) -> Option<u32> {
+        scenario
+            .and_then(|scenario| scenario.max_turns)
+            .or(self.max_turns)
+    }
+}
+
+/// Why a game was lost.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum DefeatReason {
+    /// The turn limit passed without a victory.
+    TimeLimitExceeded,
+}
+
+impl DefeatReason {
+    /// Text shown on the defeat overlay.
+    pub fn label(self) -> &'static str {
+        match self {
+            DefeatReason::TimeLimitExceeded => "Time limit exceeded",
+        }
+    }
+}
+
+/// Event sent when the player loses the game.
+#[derive(Message, Debug, Clone, Copy)]
+pub struct DefeatEvent {
+    /// Why the game was lost.
+    pub reason: DefeatReason,
+}
+
+/// Turns left to play on `turn` (counting `turn` itself) under `limit`.
+pub fn turns_remaining(turn: u32, limit: u32) -> u32 {
+    (limit + 1).saturating_sub(turn)
+}
+
+/// Progress toward every victory condition, from 0.0 to 1.0.
+#[derive(Resource, Debug, Clone, Default)]
+pub struct VictoryTracker {
+    /// Progress keyed by victory condition id.
+    pub progress: HashMap<VictoryConditionId, f32>,
+    /// Progress of each AI species, keyed by species and condition id.
+    pub rivals: BTreeMap<SpeciesId, HashMap<VictoryConditionId, f32>>,
+    /// Player progress shown instead of a percentage, keyed by condition id,
+    /// e.g. "850/1000 prosperity (streak: 1/3)".
+    pub details: HashMap<VictoryConditionId, String>,
+}
+
+/// The condition one side is closest to meeting.
+#[derive(Debug, Clone, PartialEq)]
+pub struct LeadingCondition {
+    /// Victory condition id.
+    pub condition: VictoryConditionId,
+    /// Progress toward it, from 0.0 to 1.0.
+    pub progress: f32,
+}
+
+impl VictoryTracker {
+    /// Condition the player is closest to meeting.
+    pub fn leading(&self) -> Option<LeadingCondition> {
+        leading_condition(&self.progress)
+    }
+
+    /// AI species whose best condition is further along than the player's
+    /// progress toward that same condition, picking the one closest to
+    /// winning.
+    pub fn rival_ahead(&self) -> Option<(&SpeciesId, LeadingCondition)> {
+        self.rivals
+            .iter()
+            .filter_map(|(species, progress)| Some((species, leading_condition(progress)?)))
+            .filter(|(_, lead)| {
+                lead.progress > self.progress.get(&lead.condition).copied().unwrap_or(0.0)
+            })
+            .max_by(|a, b| a.1.progress.total_cmp(&b.1.progress).then(b.0.cmp(a.0)))
+    }
+}
+
+/// Entry with the highest progress (ties go to the smallest id).
+fn leading_condition(progress: &HashMap<VictoryConditionId, f32>) -> Option<LeadingCondition> {
+    progress
+        .iter()
+        .max_by(|a, b| a.1.total_cmp(b.1).then(b.0.cmp(a.0)))
+        .map(|(condition, progress)| LeadingCondition {
+            condition: condition.clone(),
+            progress: *progress,
+        })
+}
+
+/// Plugin that checks victory conditions after every turn.
+pub struct VictoryPlugin;
+
+impl Plugin for VictoryPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<VictoryTracker>()
+            .insert_resource(VictoryRules::load(Path::new(VICTORY_RULES_PATH)))
+            .add_message::<DefeatEvent>()
+            .add_systems(
+                Update,
+                (victory_check_system, turn_limit_system, defeat_system)
+                    .chain()
+                    .run_if(in_state(GameState::PlanetView)),
+            );
+    }
+}
+
+/// Progress toward a single condition, from 0.0 to 1.0.
+///
+/// `alliance_share` is the side's [`alliance_share`], used by the alliance
+/// condition only.
+pub fn condition_progress(
+    condition: &VictoryCondition,
+    planet: &PlanetViewState,
+    research: &ResearchQueue,
+    alliance_share: f32,
+    rules: &VictoryRules,
+    game_data: &GameData,
+) -> f32 {
+    match condition.condition_type {
+        VictoryType::CoverAllTiles => cover_all_tiles_progress(planet),
+        VictoryType::ResearchAllTechnologies => research_progress(research, game_data),
+        VictoryType::Alliance => {
+            if rules.alliance_victory_threshold <= 0.0 {
+                return 0.0;
+            }
+            (alliance_share / rules.alliance_victory_threshold).min(1.0)
+        }
+        VictoryType::Economic => {
+            if rules.prosperity_streak_turns == 0 {
+                return 1.0;
+            }
+            (planet.prosperity_streak as f32 / rules.prosperity_streak_turns as f32).min(1.0)
+        }
+    }
+}
+
+/// Progress shown for a condition instead of a percentage, if any.
+fn condition_detail(
+    condition: &VictoryCondition,
+    planet: &PlanetViewState,
+    rules: &VictoryRules,
+) -> Option<String> {
+    match condition.condition_type {
+        VictoryType::Economic => Some(format!(
+            "{}/{} prosperity (streak: {}/{})",
+            prosperity(planet),
+            rules.prosperity_victory_threshold,
+            planet.prosperity_streak.min(rules.prosperity_streak_turns),
+            rules.prosperity_streak_turns
+        )),
+        _ => None,
+    }
+}
+
+/// Prosperity of a colony: its food, production and science stockpiles
+/// added together.
+pub fn prosperity(planet: &PlanetViewState) -> u32 {
+    planet
+        .food
+        .saturating_add(planet.production)
+        .saturating_add(planet.science)
+}
+
+/// Count one more turn at or above the prosperity threshold, or reset the
+/// streak if prosperity has dropped below it.
+pub fn update_prosperity_streak(planet: &mut PlanetViewState, rules: &VictoryRules) {
+    planet.prosperity_streak = if prosperity(planet) >= rules.prosperity_victory_threshold {
+        planet.prosperity_streak.saturating_add(1)
+    } else {
+        0
+    };
+}
+
+/// Share of white tiles that hold a building.
+fn cover_all_tiles_progress(planet: &PlanetViewState) -> f32 {
+    let (covered, total) = tile_coverage(planet);
+    if total == 0 {
+        return 0.0;
+    }
+    covered as f32 / total as f32
+}
+
+/// Number of built-up white tiles and of all white tiles on `planet`.
+fn tile_coverage(planet: &PlanetViewState) -> (usize, usize) {
+    let Some(surface) = &planet.surface else {
+        return (0, 0);
+    };
+    surface
+        .tiles
+        .iter()
+        .filter(|tile| tile.color == TileColor::White)
+        .fold((0, 0), |(covered, total), tile| {
+            (covered + usize::from(tile.building.is_some()), total + 1)
+        })
+}
+
+/// Share of the white tiles on every planet in `planets` that are covered
+/// by `species` and the species it is allied with.
+///
+/// Returns 0.0 while `species` has no ally.
+pub fn alliance_share(
+    species: &SpeciesId,
+    planets: &[(SpeciesId, &PlanetViewState)],
+    diplomacy: &DiplomacyState,
+) -> f32 {
+    let in_alliance = |other: &SpeciesId| {
+        other == species || diplomacy.stance(species, other) == DiplomaticStance::Alliance
+    };
+    if !planets
+        .iter()
+        .any(|(other, _)| other != species && in_alliance(other))
+    {
+        return 0.0;
+    }
+
+    let (covered, total) = planets
+        .iter()
+        .fold((0, 0), |(covered, total), (owner, planet)| {
+            let (planet_covered, planet_total) = tile_coverage(planet);
+            let planet_covered = if in_alliance(owner) {
+                planet_covered
+            } else {
+                0
+            };
+            (covered + planet_covered, total + planet_total)
+        });
+    if total == 0 {
+        return 0.0;
+    }
+    covered as f32 / total as f32
+}
+
+/// Share of all technologies that have been researched.
+fn research_progress(research: &ResearchQueue, game_data: &GameData) -> f32 {
+    let technologies = game_data.technologies();
+    if technologies.is_empty() {
+        return 0.0;
+    }
+    let researched = technologies
+        .iter()
+        .filter(|tech| research.is_researched(&TechnologyId::from(tech.id.as_str())))
+        .count();
+    researched as f32 / technologies.len() as f32
+}
+
+/// Evaluate every victory condition.
+///
+/// Returns the progress of each condition and the id of the first one that
+/// has been met, if any.
+pub fn check_victory(
+    planet: &PlanetViewState,
+    research: &ResearchQueue,
+    alliance_share: f32,
+    rules: &VictoryRules,
+    game_data: &GameData,
+) -> (VictoryTracker, Option<VictoryConditionId>) {
+    let mut tracker = VictoryTracker::default();
+    let mut winner = None;
+
+    for condition in game_data.victory_conditions() {
+        let id = VictoryConditionId::from(condition.id.as_str());
+        let progress = condition_progress(
+            condition,
+            planet,
+            research,
+            alliance_share,
+            rules,
+            game_data,
+        );
+        if progress >= 1.0 && winner.is_none() {
+            winner = Some(id.clone());
+        }
+        if let Some(detail) = condition_detail(condition, planet, rules) {
+            tracker.details.insert(id.clone(), detail);
+        }
+        tracker.progress.insert(id, progress);
+    }
+
+    (tracker, winner)
+}
+
+/// Re-check victory conditions whenever a turn ends.
+fn victory_check_system(
+    mut turn_events: MessageReader<TurnEnded>,
+    mut planet_state: ResMut<PlanetViewState>,
+    research: Res<ResearchQueue>,
+    mut ai_state: ResMut<AiState>,
+    diplomacy: Res<DiplomacyState>,
+    rules: Res<VictoryRules>,
+    game_data: Res<GameData>,
+    mut tracker: ResMut<VictoryTracker>,
+) {
+    if turn_events.read().last().is_none() {
+        return;
+    }
+
+    update_prosperity_streak(&mut planet_state, &rules);
+    for species in ai_state.species.values_mut() {
+        update_prosperity_streak(&mut species.planet, &rules);
+    }
+
+    let player = player_species_id();
+    let planets: Vec<(SpeciesId, &PlanetViewState)> =
+        std::iter::once((player.clone(), &*planet_state))
+            .chain(
+                ai_state
+                    .species
+                    .iter()
+                    .map(|(id, species)| (id.clone(), &species.planet)),
+            )
+            .collect();
+    let share = alliance_share(&player, &planets, &diplomacy);
+    let (mut progress, winner) = check_victory(&planet_state, &research, share, &rules, &game_data);
+    for (id, species) in &ai_state.species {
+        let share = alliance_share(id, &planets, &diplomacy);
+        let (rival, _) = check_victory(
+            &species.planet,
+            &species.research,
+            share,
+            &rules,
+            &game_data,
+        );
+        progress.rivals.insert(id.clone(), rival.progress);
+    }
+    *tracker = progress;
+
+    if planet_state.victory.is_none() && planet_state.defeat.is_none() {
+        if let Some(id) = winner {
+            info!("Victory achieved: {}", id.as_str());
+            planet_state.victory = Some(id);
+        }
+    }
+}
+
+/// Warn as the turn limit approaches and send a [`DefeatEvent`] once it has
+/// passed without a victory.
+fn turn_limit_system(
+    mut turn_events: MessageReader<TurnEnded>,
+    planet_state: Res<PlanetViewState>,
+    rules: Res<VictoryRules>,
+    game_data: Res<GameData>,
+    registry: Res<GameRegistry>,
+    mut queue: ResMut<NotificationQueue>,
+    mut log: ResMut<NotificationLog>,
+    mut defeat_events: MessageWriter<DefeatEvent>,
+) {
+    let Some(event) = turn_events.read().last() else {
+        return;
+    };
+    let scenario = registry.scenario(&game_data, DEFAULT_SCENARIO_ID);
+    let Some(limit) = rules.turn_limit(scenario) else {
+        return;
+    };
+    if planet_state.victory.is_some() || planet_state.defeat.is_some() {
+        return;
+    }
+
+    if event.turn > limit {
+        defeat_events.write(DefeatEvent {
+            reason: DefeatReason::TimeLimitExceeded,
+        });
+    } else if turns_remaining(event.turn, limit) == TURN_LIMIT_WARNING_TURNS {
+        let notification = Notification {
+            kind: NotificationKind::Warning,
+            title: format!("{TURN_LIMIT_WARNING_TURNS} turns remaining"),
+            lines: vec![format!(
+                "The game is lost if no victory is achieved by the end of turn {limit}."
+            )],
+        };
+        log.record(event.turn, &notification);
+        queue.push(notification);
+    }
+}
+
+/// Record the first defeat, which shows the defeat overlay.
+fn defeat_system(
+    mut defeat_events: MessageReader<DefeatEvent>,
+    mut planet_state: ResMut<PlanetViewState>,
+) {
+    let Some(event) = defeat_events.read().next() else {
+        return;
+    };
+    if planet_state.victory.is_none() && planet_state.defeat.is_none() {
+        info!("Defeat: {}", event.reason.label());
+        planet_state.defeat = Some(event.reason);
+    }
+}
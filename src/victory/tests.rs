@@ -0,0 +1,296 @@
+use std::path::{Path, PathBuf};
+
+use bevy::prelude::*;
+
+use super::{
+    DefeatEvent, DefeatReason, TURN_LIMIT_WARNING_TURNS, VICTORY_RULES_PATH, VictoryRules,
+    VictoryTracker, alliance_share, check_victory, defeat_system, turn_limit_system,
+    turns_remaining, update_prosperity_streak,
+};
+use crate::data_types::{GameData, SpeciesId, TechnologyId, VictoryConditionId, load_game_data};
+use crate::diplomacy::DiplomacyState;
+use crate::planet_data::{BuildingType, TileColor, generate_planet};
+use crate::planet_view::types::{PlanetViewState, TurnEnded};
+use crate::planet_view::ui::notification::{NotificationKind, NotificationQueue};
+use crate::planet_view::ui::notification_log::NotificationLog;
+use crate::research::ResearchQueue;
+
+#[test]
+fn covering_every_white_tile_wins() {
+    let (data, _) = load_game_data(PathBuf::from("assets/data")).expect("data loads");
+    let mut planet = PlanetViewState {
+        surface: Some(generate_planet(12345, false)),
+        ..Default::default()
+    };
+    let research = ResearchQueue::default();
+    let rules = VictoryRules::default();
+    let id = VictoryConditionId::from("victory_cover_planet");
+
+    let (tracker, winner) = check_victory(&planet, &research, 0.0, &rules, &data);
+    assert!(winner.is_none());
+    let start = tracker.progress[&id];
+    assert!(
+        start > 0.0 && start < 1.0,
+        "only the Base is built: {start}"
+    );
+
+    for tile in &mut planet.surface.as_mut().unwrap().tiles {
+        if tile.color == TileColor::White && tile.building.is_none() {
+            tile.building = Some(BuildingType::Farm);
+        }
+    }
+
+    let (tracker, winner) = check_victory(&planet, &research, 0.0, &rules, &data);
+    assert_eq!(tracker.progress[&id], 1.0);
+    assert_eq!(winner, Some(id));
+}
+
+#[test]
+fn research_victory_requires_every_technology() {
+    let (mut data, _) = load_game_data(PathBuf::from("assets/data")).expect("data loads");
+    data.victory_conditions = ron::from_str(
+        r#"[(id: "victory_science", name_en: "Science", type: research_all_technologies)]"#,
+    )
+    .expect("condition parses");
+    let planet = PlanetViewState::default();
+    let mut research = ResearchQueue::default();
+    let rules = VictoryRules::default();
+
+    let (_, winner) = check_victory(&planet, &research, 0.0, &rules, &data);
+    assert!(winner.is_none());
+
+    for tech in data.technologies() {
+        research
+            .researched
+            .insert(TechnologyId::from(tech.id.as_str()));
+    }
+    let (_, winner) = check_victory(&planet, &research, 0.0, &rules, &data);
+    assert_eq!(winner, Some(VictoryConditionId::from("victory_science")));
+}
+
+#[test]
+fn rival_is_shown_only_while_ahead_of_the_player() {
+    let cover = VictoryConditionId::from("victory_cover_planet");
+    let science = VictoryConditionId::from("victory_science");
+    let mut tracker = VictoryTracker::default();
+    tracker.progress.insert(cover.clone(), 0.4);
+    tracker.progress.insert(science.clone(), 0.1);
+    tracker.rivals.insert(
+        SpeciesId::from("ai_1"),
+        [(cover.clone(), 0.3), (science.clone(), 0.35)].into(),
+    );
+
+    let lead = tracker.leading().expect("player has progress");
+    assert_eq!((lead.condition, lead.progress), (cover.clone(), 0.4));
+
+    // The rival's best condition (science) is ahead of the player's science,
+    // even though the player leads overall.
+    let (species, rival) = tracker.rival_ahead().expect("rival leads in science");
+    assert_eq!(species.as_str(), "ai_1");
+    assert_eq!((rival.condition, rival.progress), (science.clone(), 0.35));
+
+    tracker.progress.insert(science, 0.5);
+    assert!(tracker.rival_ahead().is_none());
+}
+
+/// A colony on a fresh planet with `built` extra white tiles covered.
+fn colony(seed: u64, built: usize) -> PlanetViewState {
+    let mut surface = generate_planet(seed, false);
+    surface
+        .tiles
+        .iter_mut()
+        .filter(|tile| tile.color == TileColor::White && tile.building.is_none())
+        .take(built)
+        .for_each(|tile| tile.building = Some(BuildingType::Farm));
+    PlanetViewState {
+        surface: Some(surface),
+        ..Default::default()
+    }
+}
+
+#[test]
+fn alliance_share_needs_an_ally() {
+    let player = SpeciesId::from("player");
+    let ally = SpeciesId::from("ai_1");
+    let other = SpeciesId::from("ai_2");
+    let (a, b, c) = (colony(1, 10), colony(2, 10), colony(3, 10));
+    let planets = [
+        (player.clone(), &a),
+        (ally.clone(), &b),
+        (other.clone(), &c),
+    ];
+    let mut diplomacy = DiplomacyState::new(&[player.clone(), ally.clone(), other.clone()]);
+    diplomacy.make_contact(&player, &ally);
+
+    assert_eq!(alliance_share(&player, &planets, &diplomacy), 0.0);
+
+    diplomacy.propose_treaty(&player, &ally).expect("treaty");
+    diplomacy.propose_treaty(&player, &ally).expect("alliance");
+    let share = alliance_share(&player, &planets, &diplomacy);
+    let single = alliance_share(&player, &planets[..2], &diplomacy);
+
+    assert!(share > 0.0 && share < single, "{share} vs {single}");
+    assert_eq!(alliance_share(&ally, &planets, &diplomacy), share);
+    assert_eq!(alliance_share(&other, &planets, &diplomacy), 0.0);
+}
+
+#[test]
+fn alliance_victory_triggers_at_the_threshold() {
+    let (mut data, _) = load_game_data(PathBuf::from("assets/data")).expect("data loads");
+    data.victory_conditions =
+        ron::from_str(r#"[(id: "victory_alliance", name_en: "Alliance Victory", type: alliance)]"#)
+            .expect("condition parses");
+    let planet = PlanetViewState::default();
+    let research = ResearchQueue::default();
+    let rules = VictoryRules::default();
+    let id = VictoryConditionId::from("victory_alliance");
+
+    let (tracker, winner) = check_victory(&planet, &research, 0.375, &rules, &data);
+    assert_eq!(tracker.progress[&id], 0.5);
+    assert!(winner.is_none());
+
+    let (_, winner) = check_victory(&planet, &research, 0.75, &rules, &data);
+    assert_eq!(winner, Some(id));
+}
+
+/// Game data with only the economic victory condition.
+fn economic_data() -> GameData {
+    let (mut data, _) = load_game_data(PathBuf::from("assets/data")).expect("data loads");
+    data.victory_conditions =
+        ron::from_str(r#"[(id: "victory_economic", name_en: "Economic Victory", type: economic)]"#)
+            .expect("condition parses");
+    data
+}
+
+/// End a turn with the given stockpiles and check for victory.
+fn end_turn(
+    planet: &mut PlanetViewState,
+    stockpile: u32,
+    rules: &VictoryRules,
+    data: &GameData,
+) -> (VictoryTracker, Option<VictoryConditionId>) {
+    (planet.food, planet.production, planet.science) = (stockpile, stockpile, stockpile);
+    update_prosperity_streak(planet, rules);
+    check_victory(planet, &ResearchQueue::default(), 0.0, rules, data)
+}
+
+#[test]
+fn sustained_prosperity_wins_an_economic_victory() {
+    let data = economic_data();
+    let rules = VictoryRules::default();
+    let mut planet = PlanetViewState::default();
+    let id = VictoryConditionId::from("victory_economic");
+
+    assert!(end_turn(&mut planet, 400, &rules, &data).1.is_none());
+    let (tracker, winner) = end_turn(&mut planet, 400, &rules, &data);
+    assert!(winner.is_none());
+    assert_eq!(tracker.details[&id], "1200/1000 prosperity (streak: 2/3)");
+
+    let (_, winner) = end_turn(&mut planet, 400, &rules, &data);
+    assert_eq!(winner, Some(id));
+}
+
+#[test]
+fn a_dip_in_prosperity_resets_the_streak() {
+    let data = economic_data();
+    let rules = VictoryRules::default();
+    let mut planet = PlanetViewState::default();
+    let id = VictoryConditionId::from("victory_economic");
+
+    end_turn(&mut planet, 400, &rules, &data);
+    let (tracker, _) = end_turn(&mut planet, 300, &rules, &data);
+    assert_eq!(planet.prosperity_streak, 0);
+    assert_eq!(tracker.details[&id], "900/1000 prosperity (streak: 0/3)");
+
+    end_turn(&mut planet, 400, &rules, &data);
+    let (_, winner) = end_turn(&mut planet, 400, &rules, &data);
+    assert!(winner.is_none());
+    assert_eq!(planet.prosperity_streak, 2);
+}
+
+#[test]
+fn scenario_turn_limit_overrides_the_rules() {
+    let (data, _) = load_game_data(PathBuf::from("assets/data")).expect("data loads");
+    let mut scenario = data.scenarios()[0].clone();
+    let rules = VictoryRules {
+        max_turns: Some(100),
+        ..Default::default()
+    };
+
+    scenario.max_turns = None;
+    assert_eq!(rules.turn_limit(Some(&scenario)), Some(100));
+    assert_eq!(rules.turn_limit(None), Some(100));
+    scenario.max_turns = Some(40);
+    assert_eq!(rules.turn_limit(Some(&scenario)), Some(40));
+    assert_eq!(VictoryRules::default().turn_limit(None), None);
+
+    assert_eq!(turns_remaining(40, 40), 1);
+    assert_eq!(turns_remaining(41, 40), 0);
+}
+
+#[test]
+fn shipped_rules_set_a_turn_limit() {
+    let rules = VictoryRules::load(Path::new(VICTORY_RULES_PATH));
+    assert_eq!(rules.max_turns, Some(200));
+
+    let missing = VictoryRules::load(Path::new("assets/data/missing_rules.ron"));
+    assert_eq!(missing, VictoryRules::default());
+}
+
+/// App running the turn limit checks with a limit of `max_turns`.
+fn turn_limit_app(max_turns: u32) -> App {
+    let (data, registry) = load_game_data(PathBuf::from("assets/data")).expect("data loads");
+    let mut app = App::new();
+    app.add_message::<TurnEnded>()
+        .add_message::<DefeatEvent>()
+        .insert_resource(data)
+        .insert_resource(registry)
+        .insert_resource(VictoryRules {
+            max_turns: Some(max_turns),
+            ..Default::default()
+        })
+        .init_resource::<PlanetViewState>()
+        .init_resource::<NotificationQueue>()
+        .init_resource::<NotificationLog>()
+        .add_systems(Update, (turn_limit_system, defeat_system).chain());
+    app
+}
+
+fn begin_turn(app: &mut App, turn: u32) {
+    app.world_mut().resource_mut::<PlanetViewState>().turn = turn;
+    app.world_mut().write_message(TurnEnded { turn });
+    app.update();
+}
+
+#[test]
+fn passing_the_turn_limit_loses_the_game() {
+    let mut app = turn_limit_app(20);
+
+    begin_turn(&mut app, 20);
+    assert_eq!(app.world().resource::<PlanetViewState>().defeat, None);
+
+    begin_turn(&mut app, 21);
+    assert_eq!(
+        app.world().resource::<PlanetViewState>().defeat,
+        Some(DefeatReason::TimeLimitExceeded)
+    );
+}
+
+#[test]
+fn a_warning_arrives_before_the_turn_limit() {
+    let mut app = turn_limit_app(20);
+
+    begin_turn(&mut app, 20 - TURN_LIMIT_WARNING_TURNS);
+    assert!(
+        app.world()
+            .resource::<NotificationQueue>()
+            .current()
+            .is_none()
+    );
+
+    begin_turn(&mut app, 21 - TURN_LIMIT_WARNING_TURNS);
+    let queue = app.world().resource::<NotificationQueue>();
+    let warning = queue.current().expect("warning queued");
+    assert_eq!(warning.kind, NotificationKind::Warning);
+    assert_eq!(app.world().resource::<NotificationLog>().entries.len(), 1);
+}
@@ -67,7 +67,7 @@ async fn then_registry_resolves_ids(world: &mut AscenoriaWorld) {
 #[given("a deterministic planet seed")]
 async fn given_deterministic_planet_seed(world: &mut AscenoriaWorld) {
     world.data_path = PathBuf::from("assets/data");
-    world.surface = Some(generate_planet(42));
+    world.surface = Some(generate_planet(42, false));
 }
 
 #[then("the planet surface is a 10 by 10 grid")]